@@ -1,33 +1,33 @@
 use crate::{child::Child, position::Position, WIDTH};
-use macroquad::rand;
+use macroquad::rand::RandGenerator;
 
 pub trait ActiveRow: Sized {
-    fn build_children(dx: i32) -> Vec<Child> {
+    fn build_children(dx: i32, difficulty: f32, rng: &RandGenerator) -> Vec<Child> {
         let mut children = Vec::new();
         let mut x = -WIDTH / 2 - 70;
         while x < WIDTH / 2 + 70 {
-            x += rand::gen_range::<i32>(240, 481);
+            x += rng.gen_range::<i32>(240, 481);
             let position = if dx > 0 {
                 Position::new(WIDTH / 2 + x, 0)
             } else {
                 Position::new(WIDTH / 2 - x, 0)
             };
-            children.push(Self::build_child(dx, position));
+            children.push(Self::build_child(dx, position, difficulty, rng));
         }
         children
     }
 
-    fn build_child(dx: i32, position: Position) -> Child;
+    fn build_child(dx: i32, position: Position, difficulty: f32, rng: &RandGenerator) -> Child;
 
-    fn create_random_child(&self, dx: i32) -> Child {
+    fn create_random_child(&self, dx: i32, difficulty: f32, rng: &RandGenerator) -> Child {
         let pos = Position::new(if dx < 0 { WIDTH + 70 } else { -70 }, 0);
-        Self::build_child(dx, pos)
+        Self::build_child(dx, pos, difficulty, rng)
     }
 
-    fn random_interval(&self, dx: i32) -> f32 {
+    fn random_interval(&self, dx: i32, rng: &RandGenerator) -> f32 {
         // 240 is minimum distance between the start of one child object and the start of the next, assuming its
         // speed is 1. If the speed is 2, they can occur twice as frequently without risk of overlapping with
         // each other. The maximum distance is double the minimum distance (1 + random value of 1)
-        (1. + rand::gen_range::<f32>(0.0, 1.0)) * (240 / dx.abs()) as f32
+        (1. + rng.gen_range::<f32>(0.0, 1.0)) * (240 / dx.abs()) as f32
     }
 }
@@ -0,0 +1,91 @@
+use crate::{actor::Actor, mover::Mover, position::Position, resources::Resources};
+use macroquad::{
+    color::WHITE,
+    math::vec2,
+    prelude::collections::storage,
+    texture::{draw_texture_ex, DrawTextureParams, Texture2D},
+};
+
+// Birds are small versions of the eagle, crossing grass rows while swooping up and down; they can
+// only catch the bunner when close to the ground.
+const SCALE: f32 = 0.35;
+const SWOOP_HEIGHT: f32 = 60.;
+const SWOOP_PERIOD: f32 = 90.;
+const CATCH_HEIGHT: f32 = 12.;
+
+#[derive(Clone)]
+pub struct Bird {
+    dx: i32,
+    position: Position,
+    timer: i32,
+}
+
+impl Mover for Bird {
+    fn dx(&self) -> i32 {
+        self.dx
+    }
+}
+
+impl Actor for Bird {
+    fn update(&mut self) {
+        self.position.x += self.dx;
+        self.timer += 1;
+    }
+
+    fn draw(&self, offset_x: i32, offset_y: i32) {
+        let x = (self.position.x + offset_x) as f32;
+        let y = (self.position.y + offset_y) as f32;
+
+        let shadow_image = storage::get::<Resources>().eagles_texture;
+        Self::draw_scaled(shadow_image, x, y);
+
+        let bird_image = storage::get::<Resources>().eagle_texture;
+        Self::draw_scaled(bird_image, x, y - self.height());
+    }
+
+    fn x(&self) -> i32 {
+        self.position.x
+    }
+
+    fn y(&self) -> i32 {
+        self.position.y
+    }
+
+    fn width(&self) -> i32 {
+        40
+    }
+}
+
+impl Bird {
+    pub fn new(dx: i32, position: Position) -> Self {
+        Self {
+            dx,
+            position,
+            timer: 0,
+        }
+    }
+
+    pub fn swooping(&self) -> bool {
+        self.height() < CATCH_HEIGHT
+    }
+
+    // Starts at the top of the swoop.
+    fn height(&self) -> f32 {
+        let phase = self.timer as f32 / SWOOP_PERIOD * std::f32::consts::TAU;
+        SWOOP_HEIGHT * (phase.cos() + 1.) / 2.
+    }
+
+    fn draw_scaled(image: Texture2D, x: f32, y: f32) {
+        let size = vec2(image.width(), image.height()) * SCALE;
+        draw_texture_ex(
+            image,
+            x - size.x / 2.,
+            y - size.y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(size),
+                ..Default::default()
+            },
+        );
+    }
+}
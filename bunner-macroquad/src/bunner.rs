@@ -1,12 +1,16 @@
 use crate::{
-    child::Child, player_direction::PlayerDirection, player_state::PlayerState, position::Position,
-    resources::Resources, row::Row, splat::Splat, HEIGHT, WIDTH,
+    child::Child,
+    player_direction::PlayerDirection,
+    player_state::PlayerState,
+    position::Position,
+    resources::{play_sound_once, Resources},
+    row::Row,
+    splat::Splat,
+    HEIGHT, WIDTH,
 };
 use macroquad::{
-    audio::play_sound_once,
-    color::colors::WHITE,
+    color::{colors::WHITE, Color},
     experimental::collections::storage,
-    input::KeyCode,
     texture::{draw_texture, Texture2D},
 };
 use std::collections::VecDeque;
@@ -18,7 +22,8 @@ pub struct Bunner {
     pub min_y: i32,
     direction: PlayerDirection,
     input_queue: VecDeque<PlayerDirection>,
-    image: Texture2D,
+    // Distinguishes the players in versus mode.
+    tint: Color,
 }
 
 impl Bunner {
@@ -32,28 +37,21 @@ impl Bunner {
             min_y: position.y,
             direction: PlayerDirection::Down,
             input_queue: VecDeque::new(),
-            image: storage::get::<Resources>().blank_texture,
+            tint: WHITE,
         }
     }
 
+    pub fn tinted(self, tint: Color) -> Self {
+        Self { tint, ..self }
+    }
+
     pub fn update(
         &mut self,
         scroll_pos: i32,
         rows: &mut [Box<dyn Row>],
-        input_queue: VecDeque<KeyCode>,
+        input_queue: VecDeque<PlayerDirection>,
     ) {
-        self.input_queue.append(
-            &mut input_queue
-                .iter()
-                .filter_map(|key_code| match key_code {
-                    KeyCode::Up => Some(PlayerDirection::Up),
-                    KeyCode::Right => Some(PlayerDirection::Right),
-                    KeyCode::Down => Some(PlayerDirection::Down),
-                    KeyCode::Left => Some(PlayerDirection::Left),
-                    _ => None,
-                })
-                .collect::<VecDeque<PlayerDirection>>(),
-        );
+        self.input_queue.extend(input_queue);
 
         match self.state {
             PlayerState::Alive => {
@@ -85,6 +83,10 @@ impl Bunner {
                             self.position.x += current_row.push();
                             if land {
                                 current_row.play_sound();
+                                if current_row.slippery() {
+                                    // Keep sliding; the slide happens before any queued input.
+                                    self.input_queue.push_front(self.direction);
+                                }
                             }
                         }
                         PlayerState::Splat(y_offset) => {
@@ -97,18 +99,21 @@ impl Bunner {
                                     Position::new(self.position.x, y_offset),
                                 )),
                             );
-                            play_sound_once(storage::get::<Resources>().splat_sound);
+                            play_sound_once(|resources| resources.splat_sound);
                         }
                         PlayerState::Splash => {
-                            play_sound_once(storage::get::<Resources>().splash_sound);
+                            play_sound_once(|resources| resources.splash_sound);
                             self.timer = 100;
                         }
-                        _ => self.timer = 100,
+                        PlayerState::Eagle(_) => {
+                            self.timer = 150;
+                            play_sound_once(|resources| resources.eagle_sound);
+                        }
                     }
                 } else if self.position.y > scroll_pos + HEIGHT + 80 {
                     self.state = PlayerState::Eagle(self.position.x);
                     self.timer = 150;
-                    play_sound_once(storage::get::<Resources>().eagle_sound);
+                    play_sound_once(|resources| resources.eagle_sound);
                 }
 
                 // Limit x position
@@ -122,9 +127,19 @@ impl Bunner {
 
         // Keep track of the furthest we've got in the level
         self.min_y = self.min_y.min(self.position.y);
+    }
+
+    pub fn draw(&self, offset_x: i32, offset_y: i32) {
+        let image = self.image();
+        let x = (self.position.x + offset_x) as f32 - image.width() / 2.;
+        let y = (self.position.y + offset_y) as f32 - image.height();
+        draw_texture(image, x, y, self.tint);
+    }
 
-        // Choose sprite image
-        self.image = match self.state {
+    // Rust: The sprite is chosen when drawing, rather than at the end of the update, so that the
+    // simulation doesn't depend on the resources, and can run headless.
+    fn image(&self) -> Texture2D {
+        match self.state {
             PlayerState::Alive => {
                 if self.timer > 0 {
                     *storage::get::<Resources>()
@@ -151,13 +166,7 @@ impl Bunner {
                     .unwrap()
             }
             _ => storage::get::<Resources>().blank_texture,
-        };
-    }
-
-    pub fn draw(&self, offset_x: i32, offset_y: i32) {
-        let x = (self.position.x + offset_x) as f32 - self.image.width() / 2.;
-        let y = (self.position.y + offset_y) as f32 - self.image.height();
-        draw_texture(self.image, x, y, WHITE);
+        }
     }
 
     pub fn handle_input(&mut self, direction: Option<PlayerDirection>, rows: &[Box<dyn Row>]) {
@@ -169,7 +178,7 @@ impl Bunner {
                     ) {
                         self.direction = direction;
                         self.timer = Bunner::MOVE_DISTANCE;
-                        play_sound_once(storage::get::<Resources>().jump_sound);
+                        play_sound_once(|resources| resources.jump_sound);
                     }
                     break;
                 }
@@ -1,8 +1,12 @@
-use crate::{actor::Actor, mover::Mover, position::Position, resources::Resources};
+use crate::{
+    actor::Actor,
+    mover::Mover,
+    position::Position,
+    resources::{play_sound_once, Resources},
+};
 use macroquad::{
-    audio::play_sound_once,
     prelude::{collections::storage, draw_texture, WHITE},
-    rand::{self, ChooseRandom},
+    rand::{self, ChooseRandom, RandGenerator},
 };
 use std::collections::HashSet;
 
@@ -52,11 +56,11 @@ impl Actor for Car {
 }
 
 impl Car {
-    pub fn new(dx: i32, position: Position) -> Self {
+    pub fn new(dx: i32, position: Position, rng: &RandGenerator) -> Self {
         let image_index = if dx < 0 {
-            *vec![0, 2, 4].choose().unwrap()
+            *[0, 2, 4].choose_with_state(rng).unwrap()
         } else {
-            *vec![1, 3, 5].choose().unwrap()
+            *[1, 3, 5].choose_with_state(rng).unwrap()
         };
         Self {
             dx,
@@ -71,11 +75,11 @@ impl Car {
             match sound {
                 CarSound::Zoom => {
                     let rnd = rand::gen_range::<usize>(0, 6);
-                    play_sound_once(storage::get::<Resources>().zoom_sounds[rnd]);
+                    play_sound_once(|resources| resources.zoom_sounds[rnd]);
                 }
                 CarSound::Honk => {
                     let rnd = rand::gen_range::<usize>(0, 4);
-                    play_sound_once(storage::get::<Resources>().honk_sounds[rnd]);
+                    play_sound_once(|resources| resources.honk_sounds[rnd]);
                 }
             }
         }
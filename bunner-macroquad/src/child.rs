@@ -1,4 +1,6 @@
-use crate::{actor::Actor, car::Car, hedge::Hedge, log::Log, splat::Splat, train::Train};
+use crate::{
+    actor::Actor, bird::Bird, car::Car, hedge::Hedge, log::Log, splat::Splat, train::Train,
+};
 
 #[derive(Clone)]
 pub enum Child {
@@ -7,6 +9,7 @@ pub enum Child {
     Splat(Splat),
     Car(Car),
     Log(Log),
+    Bird(Bird),
 }
 
 impl Child {
@@ -17,6 +20,7 @@ impl Child {
             Child::Splat(splat) => splat.update(),
             Child::Car(car) => car.update(),
             Child::Log(log) => log.update(),
+            Child::Bird(bird) => bird.update(),
         }
     }
 
@@ -27,6 +31,7 @@ impl Child {
             Child::Splat(splat) => splat.draw(x, y),
             Child::Car(car) => car.draw(x, y),
             Child::Log(log) => log.draw(x, y),
+            Child::Bird(bird) => bird.draw(x, y),
         }
     }
 
@@ -37,6 +42,7 @@ impl Child {
             Child::Splat(splat) => splat.x(),
             Child::Car(car) => car.x(),
             Child::Log(log) => log.x(),
+            Child::Bird(bird) => bird.x(),
         }
     }
 
@@ -47,6 +53,16 @@ impl Child {
             Child::Splat(splat) => splat.width(),
             Child::Car(car) => car.width(),
             Child::Log(log) => log.width(),
+            Child::Bird(bird) => bird.width(),
+        }
+    }
+
+    // Submerged crocodiles can't be stood on, and high flying birds can't catch the bunner.
+    pub fn tangible(&self) -> bool {
+        match self {
+            Child::Log(log) => !log.submerged(),
+            Child::Bird(bird) => bird.swooping(),
+            _ => true,
         }
     }
 }
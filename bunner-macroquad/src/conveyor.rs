@@ -0,0 +1,94 @@
+use crate::{
+    child::Child,
+    position::Position,
+    resources::{play_sound_once, Resources},
+    road::Road,
+    row::Row,
+    ROW_HEIGHT, TILE_WIDTH, WIDTH,
+};
+use macroquad::{
+    color::Color, prelude::collections::storage, rand::RandGenerator, shapes::draw_rectangle,
+    texture::Texture2D,
+};
+
+// Rust: There are no conveyor images, so a pavement one is tinted, with moving stripes on top.
+const CONVEYOR_TINT: Color = Color::new(0.55, 0.55, 0.6, 1.);
+const STRIPE_COLOR: Color = Color::new(0.2, 0.2, 0.2, 0.6);
+const STRIPE_WIDTH: f32 = 6.;
+
+// A pavement that pushes the bunner sideways.
+#[derive(Clone)]
+pub struct Conveyor {
+    dx: i32,
+    // Horizontal offset of the stripes.
+    offset: i32,
+    y: i32,
+    children: Vec<Child>,
+}
+
+impl Row for Conveyor {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>) {
+        self.offset = (self.offset + self.dx).rem_euclid(TILE_WIDTH);
+    }
+
+    fn image(&self) -> Texture2D {
+        *storage::get::<Resources>().side_textures.get(1).unwrap()
+    }
+
+    fn tint(&self) -> Color {
+        CONVEYOR_TINT
+    }
+
+    fn draw(&self, offset_x: i32, offset_y: i32) {
+        let (x, y) = self.draw_row(offset_x, offset_y);
+        for stripe_x in (self.offset - TILE_WIDTH..WIDTH).step_by(TILE_WIDTH as usize) {
+            draw_rectangle(
+                (x + stripe_x) as f32,
+                (y - ROW_HEIGHT + 4) as f32,
+                STRIPE_WIDTH,
+                (ROW_HEIGHT - 8) as f32,
+                STRIPE_COLOR,
+            );
+        }
+    }
+
+    fn play_sound(&self) {
+        play_sound_once(|resources| resources.sidewalk_sound);
+    }
+
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
+        Box::new(Road::empty(self.y - ROW_HEIGHT, rng))
+    }
+
+    fn push(&self) -> i32 {
+        self.dx
+    }
+}
+
+impl Conveyor {
+    pub fn new(y: i32, rng: &RandGenerator) -> Self {
+        let dx = if rng.gen_range::<u8>(0, 2) == 0 {
+            -1
+        } else {
+            1
+        };
+        Self {
+            dx,
+            offset: 0,
+            y,
+            children: Vec::new(),
+        }
+    }
+}
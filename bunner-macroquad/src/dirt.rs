@@ -1,13 +1,14 @@
 use crate::{
-    child::Child, position::Position, resources::Resources, road::Road, row::Row, water::Water,
+    child::Child,
+    ice::Ice,
+    position::Position,
+    resources::{play_sound_once, Resources},
+    road::Road,
+    row::{chance, difficulty, Row},
+    water::Water,
     ROW_HEIGHT,
 };
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self},
-    texture::Texture2D,
-};
+use macroquad::{prelude::collections::storage, rand::RandGenerator, texture::Texture2D};
 
 #[derive(Clone)]
 pub struct Dirt {
@@ -39,10 +40,10 @@ impl Row for Dirt {
     }
 
     fn play_sound(&self) {
-        play_sound_once(storage::get::<Resources>().dirt_sound);
+        play_sound_once(|resources| resources.dirt_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
         if self.index <= 5 {
             Box::new(Dirt::new(self.index + 8, y))
@@ -52,15 +53,19 @@ impl Row for Dirt {
             Box::new(Dirt::new(15, y))
         } else if self.index >= 8 && self.index <= 14 {
             Box::new(Dirt::new(self.index + 1, y))
-        } else if rand::gen_range::<u8>(0, 2) == 1 {
-            Box::new(Road::empty(y))
+        } else if chance(Dirt::ICE_CHANCE * difficulty(y), rng) {
+            Box::new(Ice::empty(y))
+        } else if rng.gen_range::<u8>(0, 2) == 1 {
+            Box::new(Road::empty(y, rng))
         } else {
-            Box::new(Water::empty(y))
+            Box::new(Water::empty(y, rng))
         }
     }
 }
 
 impl Dirt {
+    const ICE_CHANCE: f32 = 0.5;
+
     pub fn new(index: i32, y: i32) -> Self {
         Self {
             index,
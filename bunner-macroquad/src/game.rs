@@ -1,40 +1,73 @@
 use crate::{
-    actor::Actor, bunner::Bunner, eagle::Eagle, grass::Grass, player_state::PlayerState,
-    position::Position, resources::Resources, row::Row, row::RowSound, HEIGHT, ROW_HEIGHT,
+    actor::Actor,
+    bunner::Bunner,
+    eagle::Eagle,
+    grass::Grass,
+    player_direction::PlayerDirection,
+    player_state::PlayerState,
+    position::Position,
+    resources::{is_headless, Resources},
+    row::Row,
+    row::RowSound,
+    HEIGHT, ROW_HEIGHT,
 };
 use macroquad::{
     audio::{play_sound, set_sound_volume, stop_sound, PlaySoundParams},
-    prelude::{clear_background, collections::storage, KeyCode, BLACK},
-    rand::gen_range,
+    prelude::{clear_background, collections::storage, BLACK},
+    rand::{gen_range, RandGenerator},
 };
 use std::collections::{HashMap, HashSet, VecDeque};
 
-#[derive(Default)]
+// Rows advanced from the start, for the given y; this is the score of a bunner that got there.
+pub fn distance(y: i32) -> u32 {
+    0.max((-320 - y) / ROW_HEIGHT) as u32
+}
+
 pub struct Game {
-    pub bunner: Option<Bunner>,
+    // Empty in attract mode; in versus mode, there is one bunner per player.
+    pub bunners: Vec<Bunner>,
     pub scroll_pos: i32,
-    eagle: Option<Eagle>,
+    // One (optional) eagle per bunner.
+    eagles: Vec<Option<Eagle>>,
     rows: Vec<Box<dyn Row>>,
     looped_sounds: HashSet<RowSound>,
+    // Drives the level generation (rows and their initial children); each row then spawns children
+    // from its own generator (see row_rng()). Presentation-only randomness (e.g. the choice of sounds)
+    // keeps using the global generator, so that it doesn't alter the level.
+    rng: RandGenerator,
+    seed: u64,
 }
 
 impl Game {
-    pub fn new(bunner: Option<Bunner>) -> Self {
+    pub fn new(bunners: Vec<Bunner>, seed: u64) -> Self {
+        let rng = RandGenerator::new();
+        rng.srand(seed);
+        let rows: Vec<Box<dyn Row>> = vec![Box::new(Grass::without_hedge(0, 0, &rng))];
+        let eagles = bunners.iter().map(|_| None).collect();
+
         Self {
-            bunner,
+            bunners,
             scroll_pos: -HEIGHT,
-            eagle: None,
-            rows: vec![Box::new(Grass::without_hedge(0, 0))],
+            eagles,
+            rows,
             looped_sounds: HashSet::new(),
+            rng,
+            seed,
         }
     }
 
-    pub fn update(&mut self, input_queue: VecDeque<KeyCode>) {
-        if let Some(bunner) = &self.bunner {
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    // One input queue per bunner.
+    pub fn update(&mut self, input_queues: Vec<VecDeque<PlayerDirection>>) {
+        if let Some(leader) = self.leader() {
             // Scroll faster if the player is close to the top of the screen. Limit scroll speed to
             // between 1 and 3 pixels per frame.
-            self.scroll_pos -=
-                1.max(3.min(self.scroll_pos + HEIGHT - bunner.position.y) / (HEIGHT / 4));
+            // With multiple players, the screen follows the leader; whoever falls behind is taken by
+            // the eagle.
+            self.scroll_pos -= 1.max(3.min(self.scroll_pos + HEIGHT - leader.y) / (HEIGHT / 4));
         } else {
             self.scroll_pos -= 1;
         }
@@ -47,36 +80,39 @@ impl Game {
         // Add rows
         while let Some(last_row) = self.rows.last() {
             if last_row.y() > self.scroll_pos + ROW_HEIGHT {
-                let new_row = last_row.next();
+                let new_row = last_row.next(&self.rng);
                 self.rows.push(new_row)
             } else {
                 break;
             }
         }
 
+        let leader = self.leader();
         for row in self.rows.iter_mut() {
-            row.update(
-                self.scroll_pos,
-                self.bunner.as_ref().map(|bunner| bunner.position),
-            );
+            row.update(self.scroll_pos, leader);
         }
-        if let Some(bunner) = self.bunner.as_mut() {
+        for ((bunner, eagle), input_queue) in
+            self.bunners.iter_mut().zip(self.eagles.iter_mut()).zip(
+                input_queues
+                    .into_iter()
+                    .chain(std::iter::repeat_with(VecDeque::new)),
+            )
+        {
             bunner.update(self.scroll_pos, &mut self.rows, input_queue);
             if let PlayerState::Eagle(x) = bunner.state {
-                self.eagle
-                    .get_or_insert_with(|| Eagle::new(Position::new(x, self.scroll_pos)));
+                eagle.get_or_insert_with(|| Eagle::new(Position::new(x, self.scroll_pos)));
             };
         }
-        if let Some(eagle) = self.eagle.as_mut() {
+        for eagle in self.eagles.iter_mut().flatten() {
             eagle.update();
         }
 
-        if let Some(bunner) = &self.bunner {
+        if let Some(leader) = leader {
             let mut sounds: HashMap<RowSound, f32> = HashMap::new();
             for row in self.rows.iter() {
                 if let Some(sound_name) = row.sound() {
                     let volume = sounds.get(&sound_name).unwrap_or(&0.)
-                        + 16.0 / 16.0_f32.max((row.y() - bunner.position.y).abs() as f32);
+                        + 16.0 / 16.0_f32.max((row.y() - leader.y).abs() as f32);
                     sounds.insert(sound_name, volume);
                 }
             }
@@ -92,31 +128,60 @@ impl Game {
         for row in self.rows.iter().rev() {
             row.draw(0, -self.scroll_pos);
         }
-        if let Some(bunner) = &self.bunner {
+        for bunner in self.bunners.iter() {
             bunner.draw(0, -self.scroll_pos);
         }
-        if let Some(eagle) = &self.eagle {
+        for eagle in self.eagles.iter().flatten() {
             eagle.draw(0, -self.scroll_pos);
         }
     }
 
     pub fn game_over(&self) -> bool {
-        if let Some(bunner) = &self.bunner {
-            bunner.state != PlayerState::Alive && bunner.timer < 0
-        } else {
-            false
-        }
+        !self.bunners.is_empty()
+            && self
+                .bunners
+                .iter()
+                .all(|bunner| bunner.state != PlayerState::Alive && bunner.timer < 0)
     }
 
+    // Best score, across the bunners.
     pub fn score(&self) -> u32 {
-        if let Some(bunner) = &self.bunner {
-            0.max((-320 - bunner.min_y as i32) / 40) as u32
-        } else {
-            0
+        self.scores().into_iter().max().unwrap_or(0)
+    }
+
+    pub fn scores(&self) -> Vec<u32> {
+        self.bunners
+            .iter()
+            .map(|bunner| distance(bunner.min_y))
+            .collect()
+    }
+
+    // Index of the bunner with the best score; None in case of a draw.
+    pub fn winner(&self) -> Option<usize> {
+        let scores = self.scores();
+        let best_score = *scores.iter().max()?;
+        let mut best = (0..scores.len()).filter(|&i| scores[i] == best_score);
+        match (best.next(), best.next()) {
+            (Some(index), None) => Some(index),
+            _ => None,
         }
     }
 
+    // Position of the furthest bunner, preferring the ones alive.
+    fn leader(&self) -> Option<Position> {
+        self.bunners
+            .iter()
+            .filter(|bunner| bunner.state == PlayerState::Alive)
+            .min_by_key(|bunner| bunner.position.y)
+            .or_else(|| self.bunners.iter().min_by_key(|bunner| bunner.position.y))
+            .map(|bunner| bunner.position)
+    }
+
     fn loop_sound(&mut self, row_sound: RowSound, volume: f32) {
+        if is_headless() {
+            return;
+        }
+
         let sound = match row_sound {
             RowSound::River => *storage::get::<Resources>()
                 .river_sounds
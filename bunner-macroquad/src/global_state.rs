@@ -2,6 +2,10 @@ use crate::{
     bunner::Bunner,
     drawing::{display_number, NumberAlign, NumberColor},
     game::Game,
+    high_scores::{format_date, DeathCause, HighScore, HighScores},
+    initials_screen::InitialsScreen,
+    input::{Action, Gamepads, InputEvent, KeyBindings},
+    options_screen::OptionsScreen,
     position::Position,
     resources::Resources,
     state::State,
@@ -9,23 +13,49 @@ use crate::{
 };
 use macroquad::{
     audio::{play_sound, set_sound_volume, PlaySoundParams, Sound},
-    color::colors::WHITE,
+    color::{colors::WHITE, Color, YELLOW},
     experimental::collections::storage,
     input::KeyCode,
     miniquad::{self},
     rand::{self},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
     texture::draw_texture,
 };
 use std::collections::VecDeque;
-#[cfg(not(target_arch = "wasm32"))]
-use std::fs;
+
+const SECONDS_PER_DAY: f64 = 86_400.;
+const DAILY_HIGH_SCORES_FILE: &str = "daily_high.txt";
+// The title screen alternates with the high scores table; this is the duration of each, in scroll
+// pixels (approximately frames).
+const TITLE_CYCLE: i32 = 360;
+// Tint of the second player, matching the color of their score.
+const PLAYER_TWO_TINT: Color = Color::new(1., 0.85, 0.3, 1.);
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum GameMode {
+    Normal,
+    // The seed of the daily challenge.
+    Daily(u64),
+    // Two players racing on the same rows; the scores are not recorded.
+    Versus,
+}
 
 pub struct GlobalState {
     state: State,
     game: Game,
-    high_score: u32,
+    high_scores: HighScores,
+    mode: GameMode,
+    // The daily challenge high score, with its seed; only the latest day is kept.
+    daily_high_score: Option<(u64, u32)>,
     music: Sound,
-    input_queue: VecDeque<KeyCode>,
+    // Raw inputs; they're mapped to actions on update, so that the options screen can bind them.
+    input_queue: VecDeque<InputEvent>,
+    key_bindings: KeyBindings,
+    gamepads: Gamepads,
+    options_screen: OptionsScreen,
+    initials_screen: InitialsScreen,
+    quit: bool,
 }
 
 impl miniquad::EventHandler for GlobalState {
@@ -41,7 +71,7 @@ impl miniquad::EventHandler for GlobalState {
         repeat: bool,
     ) {
         if !repeat {
-            self.input_queue.push_back(keycode);
+            self.input_queue.push_back(InputEvent::Key(keycode));
         }
     }
 }
@@ -51,15 +81,24 @@ impl GlobalState {
         Self {
             // Set the initial game state
             state: State::Menu,
-            game: Game::new(None),
-            high_score: 0,
+            game: Game::new(vec![], 0),
+            high_scores: HighScores::default(),
+            mode: GameMode::Normal,
+            daily_high_score: None,
             music,
             input_queue: VecDeque::new(),
+            key_bindings: KeyBindings::default(),
+            gamepads: Gamepads::default(),
+            options_screen: OptionsScreen::default(),
+            initials_screen: InitialsScreen::default(),
+            quit: false,
         }
     }
 
     pub fn init(&mut self) {
         rand::srand(macroquad::miniquad::date::now() as u64);
+        self.game = Game::new(vec![], Self::random_seed());
+        self.key_bindings = KeyBindings::load();
         play_sound(
             self.music,
             PlaySoundParams {
@@ -67,52 +106,110 @@ impl GlobalState {
                 volume: 1.,
             },
         );
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            self.high_score = fs::read_to_string("high.txt")
-                .map_or(Ok(0), |i| i.parse::<u32>())
-                .unwrap_or(0);
-        }
+        self.high_scores = HighScores::load();
+
+        // A "<seed> <score>" line.
+        self.daily_high_score = crate::storage::read(DAILY_HIGH_SCORES_FILE).and_then(|score| {
+            let (seed, score) = score.trim().split_once(' ')?;
+            Some((seed.parse().ok()?, score.parse().ok()?))
+        });
     }
 
     pub fn update(&mut self) {
+        self.gamepads.poll(&mut self.input_queue);
+        let events = self.input_queue.drain(..).collect::<Vec<_>>();
+
+        // The options screen maps the inputs by itself, since it may have to bind them.
+        if self.state == State::Options {
+            if self.options_screen.update(&events, &mut self.key_bindings) {
+                self.state = State::Menu;
+            }
+            self.game.update(vec![]);
+            return;
+        }
+
+        if self.state == State::EnterInitials {
+            if let Some(initials) = self.initials_screen.update(&events, &self.key_bindings) {
+                self.record_high_score(initials);
+                self.state = State::GameOver;
+            }
+            return;
+        }
+
+        let actions = events
+            .into_iter()
+            .flat_map(|event| self.key_bindings.actions(event))
+            .collect::<Vec<_>>();
+
+        if actions.contains(&Action::Quit) {
+            self.quit = true;
+            return;
+        }
+
         match self.state {
             State::Menu => {
-                if self.input_queue.contains(&KeyCode::Space) {
-                    // Switch to play state, and create a new Game object, passing it a new Player object to use
-                    self.state = State::Play;
-                    self.game = Game::new(Some(Bunner::new(Position::new(240, -320))));
-                    self.input_queue.clear();
-                    set_sound_volume(self.music, 0.3);
+                if actions.contains(&Action::Start) {
+                    self.start_game(GameMode::Normal);
+                } else if actions.contains(&Action::DailyChallenge) {
+                    // The daily challenge level is the same for everybody playing on the same (UTC) day.
+                    let daily_seed = (miniquad::date::now() / SECONDS_PER_DAY) as u64;
+                    self.start_game(GameMode::Daily(daily_seed));
+                } else if actions.contains(&Action::Versus) {
+                    self.start_game(GameMode::Versus);
+                } else if actions.contains(&Action::Options) {
+                    self.state = State::Options;
+                    self.options_screen = OptionsScreen::default();
                 } else {
-                    self.game.update(self.input_queue.drain(..).collect());
+                    self.game.update(vec![]);
                 }
             }
             State::Play => {
                 if self.game.game_over() {
-                    self.high_score = self.high_score.max(self.game.score());
-                    #[cfg(not(target_arch = "wasm32"))]
-                    {
-                        fs::write("high.txt", self.high_score.to_string()).ok();
+                    if let GameMode::Daily(daily_seed) = self.mode {
+                        let score = self.daily_high_score(daily_seed).max(self.game.score());
+                        self.daily_high_score = Some((daily_seed, score));
+                        crate::storage::write(
+                            DAILY_HIGH_SCORES_FILE,
+                            &format!("{} {}\n", daily_seed, score),
+                        );
                     }
 
-                    self.state = State::GameOver;
+                    if self.mode == GameMode::Normal
+                        && self.high_scores.qualifies(self.game.score())
+                    {
+                        self.state = State::EnterInitials;
+                        self.initials_screen = InitialsScreen::default();
+                    } else {
+                        self.state = State::GameOver;
+                    }
                 } else {
-                    self.game.update(self.input_queue.drain(..).collect());
+                    let mut input_queues = vec![VecDeque::new(); self.game.bunners.len()];
+                    for action in actions {
+                        if let Action::Move(player, direction) = action {
+                            if let Some(input_queue) = input_queues.get_mut(player) {
+                                input_queue.push_back(direction);
+                            }
+                        }
+                    }
+                    self.game.update(input_queues);
                 }
             }
             State::GameOver => {
-                if self.input_queue.contains(&KeyCode::Space) {
+                if actions.contains(&Action::Start) {
                     // Switch to menu state, and create a new game object
                     self.state = State::Menu;
-                    self.game = Game::new(None);
-                    self.input_queue.clear();
+                    self.game = Game::new(vec![], Self::random_seed());
                     set_sound_volume(self.music, 1.0);
                 }
             }
+            State::Options | State::EnterInitials => unreachable!(),
         }
     }
 
+    pub fn quit(&self) -> bool {
+        self.quit
+    }
+
     pub fn draw(&mut self) {
         let resources = storage::get::<Resources>();
 
@@ -120,8 +217,14 @@ impl GlobalState {
 
         match self.state {
             State::Menu => {
-                // Draw title screen
-                draw_texture(resources.title_texture, 0., 0., WHITE);
+                // Draw title screen, alternating with the high scores table
+                if (self.game.scroll_pos / TITLE_CYCLE) % 2 == 0
+                    || self.high_scores.entries().is_empty()
+                {
+                    draw_texture(resources.title_texture, 0., 0., WHITE);
+                } else {
+                    self.draw_high_scores();
+                }
                 let index: usize = ((self.game.scroll_pos.abs() / 6) % 4) as usize;
                 if let Some(start_index) = [0, 1, 2, 1].get(index) {
                     draw_texture(
@@ -131,21 +234,158 @@ impl GlobalState {
                         WHITE,
                     );
                 };
+                let text = format!(
+                    "{}: DAILY  {}: VERSUS  {}: CONTROLS",
+                    self.key_bindings.key_hint(Action::DailyChallenge),
+                    self.key_bindings.key_hint(Action::Versus),
+                    self.key_bindings.key_hint(Action::Options)
+                );
+                let text_width = measure_text(&text, None, 24, 1.).width;
+                draw_text(
+                    &text,
+                    (WIDTH as f32 - text_width) / 2.,
+                    (HEIGHT - 20) as f32,
+                    24.,
+                    WHITE,
+                );
             }
             State::Play => {
-                // Display score and high score
-                display_number(self.game.score(), NumberColor::Blue, 0, NumberAlign::Left);
-                display_number(
-                    self.high_score,
-                    NumberColor::Yellow,
-                    WIDTH - 10,
-                    NumberAlign::Right,
-                );
+                // Display score and high score; in versus mode, the score of each player instead.
+                if self.mode == GameMode::Versus {
+                    self.draw_versus_scores();
+                } else {
+                    display_number(self.game.score(), NumberColor::Blue, 0, NumberAlign::Left);
+                    let high_score = match self.mode {
+                        GameMode::Daily(daily_seed) => self.daily_high_score(daily_seed),
+                        _ => self.high_scores.best(),
+                    };
+                    display_number(
+                        high_score,
+                        NumberColor::Yellow,
+                        WIDTH - 10,
+                        NumberAlign::Right,
+                    );
+                }
             }
             State::GameOver => {
                 // Display "Game Over" image
                 draw_texture(resources.gameover_texture, 0., 0., WHITE);
+                if self.mode == GameMode::Versus {
+                    self.draw_winner();
+                }
+            }
+            State::Options => {
+                draw_texture(resources.title_texture, 0., 0., WHITE);
+                self.options_screen.draw(&self.key_bindings);
+            }
+            State::EnterInitials => {
+                self.initials_screen.draw(&self.key_bindings);
             }
         }
     }
+
+    fn draw_high_scores(&self) {
+        draw_rectangle(
+            0.,
+            0.,
+            WIDTH as f32,
+            HEIGHT as f32,
+            Color::new(0., 0., 0., 0.8),
+        );
+
+        let title = "HIGH SCORES";
+        let title_width = measure_text(title, None, 32, 1.).width;
+        draw_text(title, (WIDTH as f32 - title_width) / 2., 120., 32., YELLOW);
+
+        for (i, entry) in self.high_scores.entries().iter().enumerate() {
+            let y = 180. + i as f32 * 36.;
+            let columns = [
+                (20., format!("{}.", i + 1)),
+                (70., entry.initials.clone()),
+                (140., entry.distance.to_string()),
+                (210., entry.cause.name().to_uppercase()),
+                (330., entry.date.clone()),
+            ];
+            for (x, text) in columns {
+                draw_text(&text, x, y, 24., WHITE);
+            }
+        }
+    }
+
+    fn record_high_score(&mut self, initials: String) {
+        let bunner = &self.game.bunners[0];
+        if let Some(cause) = DeathCause::from_state(bunner.state) {
+            self.high_scores.insert(HighScore {
+                initials,
+                date: format_date(miniquad::date::now()),
+                distance: self.game.score(),
+                cause,
+            });
+            self.high_scores.save();
+        }
+    }
+
+    fn draw_versus_scores(&self) {
+        let scores = self.game.scores();
+        display_number(scores[0], NumberColor::Blue, 0, NumberAlign::Left);
+        display_number(
+            scores[1],
+            NumberColor::Yellow,
+            WIDTH - 10,
+            NumberAlign::Right,
+        );
+    }
+
+    fn draw_winner(&self) {
+        self.draw_versus_scores();
+
+        let text = match self.game.winner() {
+            Some(player) => format!("PLAYER {} WINS!", player + 1),
+            None => "DRAW!".to_string(),
+        };
+        let text_width = measure_text(&text, None, 48, 1.).width;
+        draw_text(
+            &text,
+            (WIDTH as f32 - text_width) / 2.,
+            (HEIGHT - 160) as f32,
+            48.,
+            WHITE,
+        );
+    }
+
+    // Switch to play state, and create a new Game object, passing it new Player objects to use
+    fn start_game(&mut self, mode: GameMode) {
+        self.state = State::Play;
+        self.mode = mode;
+        let (bunners, seed) = match mode {
+            GameMode::Normal => (
+                vec![Bunner::new(Position::new(240, -320))],
+                Self::random_seed(),
+            ),
+            GameMode::Daily(daily_seed) => {
+                (vec![Bunner::new(Position::new(240, -320))], daily_seed)
+            }
+            GameMode::Versus => (
+                vec![
+                    Bunner::new(Position::new(200, -320)),
+                    Bunner::new(Position::new(280, -320)).tinted(PLAYER_TWO_TINT),
+                ],
+                Self::random_seed(),
+            ),
+        };
+        self.game = Game::new(bunners, seed);
+        set_sound_volume(self.music, 0.3);
+    }
+
+    // Zero if there's no score for the seed (i.e. the day) yet.
+    fn daily_high_score(&self, daily_seed: u64) -> u32 {
+        match self.daily_high_score {
+            Some((seed, score)) if seed == daily_seed => score,
+            _ => 0,
+        }
+    }
+
+    fn random_seed() -> u64 {
+        rand::rand() as u64
+    }
 }
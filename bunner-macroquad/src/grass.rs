@@ -1,22 +1,30 @@
 use crate::{
-    child::Child, hedge::Hedge, hedge_mask::HedgeMask, hedge_row::HedgeRow, hedge_tile::HedgeTile,
-    position::Position, resources::Resources, road::Road, row::Row, water::Water, ROW_HEIGHT,
-    WIDTH,
-};
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self},
-    texture::Texture2D,
+    bird::Bird,
+    child::Child,
+    hedge::Hedge,
+    hedge_mask::HedgeMask,
+    hedge_row::HedgeRow,
+    hedge_tile::HedgeTile,
+    player_state::PlayerState,
+    position::Position,
+    resources::{play_sound_once, Resources},
+    road::Road,
+    row::{chance, collide_children, difficulty, row_rng, Row},
+    water::Water,
+    ROW_HEIGHT, WIDTH,
 };
+use macroquad::{prelude::collections::storage, rand::RandGenerator, texture::Texture2D};
 
-#[derive(Clone)]
 pub struct Grass {
     index: i32,
     y: i32,
     hedge_row: HedgeRow,
     hedge_mask: Vec<HedgeMask>,
+    // Whether birds cross the row; if so, the timer is used to spawn them.
+    birds: bool,
+    timer: f32,
     children: Vec<Child>,
+    rng: RandGenerator,
 }
 
 impl Row for Grass {
@@ -32,6 +40,32 @@ impl Row for Grass {
         self.children.as_mut()
     }
 
+    fn update(&mut self, _scroll_pos: i32, _bunner_pos: Option<Position>) {
+        self.update_children();
+        if !self.birds {
+            return;
+        }
+
+        self.children.retain(|c| match c {
+            Child::Bird(_) => c.x() > -70 && c.x() < WIDTH + 70,
+            _ => true,
+        });
+        self.timer -= 1.;
+
+        if self.timer < 0. {
+            let dx = self.rng.gen_range(2, 4);
+            let bird = if self.rng.gen_range::<u8>(0, 2) == 0 {
+                Bird::new(dx, Position::new(-40, 0))
+            } else {
+                Bird::new(-dx, Position::new(WIDTH + 40, 0))
+            };
+            self.children.push(Child::Bird(bird));
+            self.timer = (1. + self.rng.gen_range::<f32>(0., 1.))
+                * Self::BIRD_INTERVAL
+                * (1.5 - difficulty(self.y));
+        }
+    }
+
     fn image(&self) -> Texture2D {
         *storage::get::<Resources>()
             .grass_textures
@@ -40,28 +74,42 @@ impl Row for Grass {
     }
 
     fn play_sound(&self) {
-        play_sound_once(storage::get::<Resources>().grass_sound);
+        play_sound_once(|resources| resources.grass_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
         if self.index <= 5 {
-            self.grass_row(self.index + 8, y)
+            self.grass_row(self.index + 8, y, rng)
         } else if self.index == 6 {
-            self.grass_row(7, y)
+            self.grass_row(7, y, rng)
         } else if self.index == 7 {
-            self.grass_row(15, y)
+            self.grass_row(15, y, rng)
         } else if self.index >= 8 && self.index <= 14 {
-            self.grass_row(self.index + 1, y)
-        } else if rand::gen_range::<u8>(0, 2) == 0 {
-            Box::new(Road::empty(y))
+            self.grass_row(self.index + 1, y, rng)
+        } else if rng.gen_range::<u8>(0, 2) == 0 {
+            Box::new(Road::empty(y, rng))
         } else {
-            Box::new(Water::empty(y))
+            Box::new(Water::empty(y, rng))
         }
     }
 
     fn allow_movement(&self, x: i32) -> bool {
-        (16..=WIDTH - 16).contains(&x) && !self.collide(x, 8)
+        let hedges = self
+            .children
+            .iter()
+            .filter(|c| matches!(c, Child::Hedge(_)));
+        (16..=WIDTH - 16).contains(&x) && !collide_children(hedges, x, 8)
+    }
+
+    // Rust: Being caught by a bird is treated as being taken by the eagle.
+    fn check_collision(&self, x: i32) -> PlayerState {
+        let birds = self.children.iter().filter(|c| matches!(c, Child::Bird(_)));
+        if collide_children(birds, x, 0) {
+            PlayerState::Eagle(x)
+        } else {
+            PlayerState::Alive
+        }
     }
 
     fn push(&self) -> i32 {
@@ -70,17 +118,21 @@ impl Row for Grass {
 }
 
 impl Grass {
+    const BIRDS_CHANCE: f32 = 0.5;
+    const BIRD_INTERVAL: f32 = 200.;
+
     pub fn new(
         previous_hedge_mask: Option<Vec<HedgeMask>>,
         previous_hedge_row: HedgeRow,
         index: i32,
         y: i32,
+        rng: &RandGenerator,
     ) -> Self {
         let (hedge_mask, hedge_row) = match previous_hedge_mask {
-            Some(_) if previous_hedge_row == HedgeRow::None => Self::first_hedge_row(index),
+            Some(_) if previous_hedge_row == HedgeRow::None => Self::first_hedge_row(index, rng),
             Some(mask) if previous_hedge_row == HedgeRow::First => (mask, HedgeRow::Second),
             Some(_) => (Vec::new(), HedgeRow::None),
-            None => Self::first_hedge_row(index),
+            None => Self::first_hedge_row(index, rng),
         };
 
         let mut children: Vec<Child> = Vec::new();
@@ -107,12 +159,15 @@ impl Grass {
             index,
             hedge_row,
             hedge_mask,
+            birds: chance(Self::BIRDS_CHANCE * difficulty(y), rng),
+            timer: 0.,
             children,
+            rng: row_rng(rng),
         }
     }
 
-    pub fn without_hedge(index: i32, y: i32) -> Self {
-        Self::new(None, HedgeRow::None, index, y)
+    pub fn without_hedge(index: i32, y: i32, rng: &RandGenerator) -> Self {
+        Self::new(None, HedgeRow::None, index, y, rng)
     }
 
     pub fn classify_hedge_segment(
@@ -141,25 +196,25 @@ impl Grass {
         }
     }
 
-    pub fn first_hedge_row(index: i32) -> (Vec<HedgeMask>, HedgeRow) {
-        if rand::gen_range::<u8>(0, 1) == 0 && index > 7 && index < 14 {
-            (Self::generate_hedge_mask(), HedgeRow::First)
+    pub fn first_hedge_row(index: i32, rng: &RandGenerator) -> (Vec<HedgeMask>, HedgeRow) {
+        if rng.gen_range::<u8>(0, 1) == 0 && index > 7 && index < 14 {
+            (Self::generate_hedge_mask(rng), HedgeRow::First)
         } else {
             (Vec::new(), HedgeRow::None)
         }
     }
 
-    pub fn generate_hedge_mask() -> Vec<HedgeMask> {
+    pub fn generate_hedge_mask(rng: &RandGenerator) -> Vec<HedgeMask> {
         let mut mask = Vec::new();
         mask.resize_with(12, || {
-            if rand::gen_range::<u8>(0, 100) >= 1 {
+            if rng.gen_range::<u8>(0, 100) >= 1 {
                 HedgeMask::Hedge
             } else {
                 HedgeMask::Empty
             }
         });
         // Ensure there is at least one gap
-        mask[rand::gen_range(0, 11)] = HedgeMask::Empty;
+        mask[rng.gen_range(0, 11)] = HedgeMask::Empty;
 
         let mut new_mask = Vec::with_capacity(12);
         for i in 0..12 {
@@ -181,19 +236,20 @@ impl Grass {
 
         // Duplicate first and last elements
         let mut mask = Vec::new();
-        mask.push(*new_mask.get(0).unwrap());
+        mask.push(*new_mask.first().unwrap());
         mask.extend(new_mask.clone());
         mask.push(new_mask.pop().unwrap());
 
         mask
     }
 
-    fn grass_row(&self, index: i32, y: i32) -> Box<dyn Row> {
+    fn grass_row(&self, index: i32, y: i32, rng: &RandGenerator) -> Box<dyn Row> {
         Box::new(Grass::new(
             Some(self.hedge_mask.clone()),
             self.hedge_row,
             index,
             y,
+            rng,
         ))
     }
 }
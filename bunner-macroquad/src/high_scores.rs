@@ -0,0 +1,146 @@
+use crate::{player_state::PlayerState, storage};
+
+const HIGH_SCORES_FILE: &str = "high_scores.txt";
+const MAX_ENTRIES: usize = 10;
+const SECONDS_PER_DAY: u64 = 86_400;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeathCause {
+    Splat,
+    Splash,
+    Eagle,
+}
+
+impl DeathCause {
+    // None if the player is alive.
+    pub fn from_state(state: PlayerState) -> Option<Self> {
+        match state {
+            PlayerState::Alive => None,
+            PlayerState::Splat(_) => Some(DeathCause::Splat),
+            PlayerState::Splash => Some(DeathCause::Splash),
+            PlayerState::Eagle(_) => Some(DeathCause::Eagle),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeathCause::Splat => "splat",
+            DeathCause::Splash => "splash",
+            DeathCause::Eagle => "eagle",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [DeathCause::Splat, DeathCause::Splash, DeathCause::Eagle]
+            .into_iter()
+            .find(|cause| cause.name() == name)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HighScore {
+    pub initials: String,
+    // Formatted as YYYY-MM-DD.
+    pub date: String,
+    // Rows advanced, i.e. the score.
+    pub distance: u32,
+    pub cause: DeathCause,
+}
+
+#[derive(Default)]
+pub struct HighScores {
+    // Sorted by descending distance.
+    entries: Vec<HighScore>,
+}
+
+impl HighScores {
+    // One entry per line, in the format `<initials> <date> <distance> <cause>`; invalid lines are
+    // skipped.
+    pub fn load() -> Self {
+        let mut high_scores = Self::default();
+
+        if let Some(data) = storage::read(HIGH_SCORES_FILE) {
+            for line in data.lines() {
+                let fields = line.split_whitespace().collect::<Vec<_>>();
+                if let [initials, date, distance, cause] = fields[..] {
+                    if let (Ok(distance), Some(cause)) =
+                        (distance.parse(), DeathCause::from_name(cause))
+                    {
+                        high_scores.insert(HighScore {
+                            initials: initials.to_string(),
+                            date: date.to_string(),
+                            distance,
+                            cause,
+                        });
+                    }
+                }
+            }
+        }
+
+        high_scores
+    }
+
+    pub fn save(&self) {
+        let data = self
+            .entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{} {} {} {}\n",
+                    entry.initials,
+                    entry.date,
+                    entry.distance,
+                    entry.cause.name()
+                )
+            })
+            .collect::<String>();
+        storage::write(HIGH_SCORES_FILE, &data);
+    }
+
+    pub fn entries(&self) -> &[HighScore] {
+        &self.entries
+    }
+
+    pub fn best(&self) -> u32 {
+        self.entries.first().map_or(0, |entry| entry.distance)
+    }
+
+    // Runs without any distance don't make it to the table.
+    pub fn qualifies(&self, distance: u32) -> bool {
+        distance > 0
+            && (self.entries.len() < MAX_ENTRIES
+                || self.entries.iter().any(|entry| entry.distance < distance))
+    }
+
+    // Ties are ranked by age, so a new entry goes after the existing ones with the same distance.
+    pub fn insert(&mut self, high_score: HighScore) {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.distance < high_score.distance)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, high_score);
+        self.entries.truncate(MAX_ENTRIES);
+    }
+}
+
+// Formats a Unix timestamp (in seconds) as a (UTC) YYYY-MM-DD date. Based on the `civil_from_days`
+// algorithm by Howard Hinnant, which avoids pulling a date crate for a single conversion.
+pub fn format_date(timestamp: f64) -> String {
+    let days = (timestamp as u64 / SECONDS_PER_DAY) as i64 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
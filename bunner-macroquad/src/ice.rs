@@ -0,0 +1,79 @@
+use crate::{
+    child::Child,
+    resources::{play_sound_once, Resources},
+    road::Road,
+    row::Row,
+    water::Water,
+    ROW_HEIGHT,
+};
+use macroquad::{
+    color::Color, prelude::collections::storage, rand::RandGenerator, texture::Texture2D,
+};
+
+// Rust: There are no ice images, so the pavement ones are tinted.
+const ICE_TINT: Color = Color::new(0.7, 0.9, 1., 1.);
+
+#[derive(Clone)]
+pub struct Ice {
+    index: i32,
+    y: i32,
+    children: Vec<Child>,
+}
+
+impl Row for Ice {
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    fn children(&self) -> &[Child] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut Vec<Child> {
+        self.children.as_mut()
+    }
+
+    fn image(&self) -> Texture2D {
+        *storage::get::<Resources>()
+            .side_textures
+            .get(self.index as usize)
+            .unwrap()
+    }
+
+    fn tint(&self) -> Color {
+        ICE_TINT
+    }
+
+    fn play_sound(&self) {
+        play_sound_once(|resources| resources.sidewalk_sound);
+    }
+
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
+        let y = self.y - ROW_HEIGHT;
+        if self.index < 2 {
+            Box::new(Ice::new(self.index + 1, y))
+        } else if rng.gen_range::<u8>(0, 2) == 1 {
+            Box::new(Road::empty(y, rng))
+        } else {
+            Box::new(Water::empty(y, rng))
+        }
+    }
+
+    fn slippery(&self) -> bool {
+        true
+    }
+}
+
+impl Ice {
+    pub fn new(index: i32, y: i32) -> Self {
+        Self {
+            index,
+            y,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn empty(y: i32) -> Self {
+        Self::new(0, y)
+    }
+}
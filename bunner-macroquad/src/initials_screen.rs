@@ -0,0 +1,101 @@
+use crate::{
+    input::{key_name, Action, InputEvent, KeyBindings},
+    player_direction::PlayerDirection,
+    HEIGHT, WIDTH,
+};
+use macroquad::{
+    color::{colors::WHITE, Color, YELLOW},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+};
+
+const FONT_SIZE: f32 = 24.;
+const LETTER_FONT_SIZE: f32 = 64.;
+const LETTER_SPACING: f32 = 60.;
+
+// Initials can be typed with the keyboard, or chosen via the movement actions (so that they can be
+// entered with a gamepad).
+pub struct InitialsScreen {
+    letters: [u8; 3],
+    cursor: usize,
+}
+
+impl Default for InitialsScreen {
+    fn default() -> Self {
+        Self {
+            letters: [b'A'; 3],
+            cursor: 0,
+        }
+    }
+}
+
+impl InitialsScreen {
+    // Returns the initials once confirmed.
+    pub fn update(&mut self, events: &[InputEvent], key_bindings: &KeyBindings) -> Option<String> {
+        for event in events {
+            // Letters are typed directly, even if they're bound to some action.
+            if let InputEvent::Key(key) = event {
+                let name = key_name(*key);
+                if let [letter @ b'A'..=b'Z'] = name.as_bytes() {
+                    self.letters[self.cursor] = *letter;
+                    self.cursor = (self.cursor + 1).min(self.letters.len() - 1);
+                    continue;
+                }
+            }
+
+            for action in key_bindings.actions(*event) {
+                match action {
+                    Action::Move(0, PlayerDirection::Up) => self.cycle_letter(1),
+                    Action::Move(0, PlayerDirection::Down) => self.cycle_letter(25),
+                    Action::Move(0, PlayerDirection::Left) => {
+                        self.cursor = self.cursor.saturating_sub(1)
+                    }
+                    Action::Move(0, PlayerDirection::Right) => {
+                        self.cursor = (self.cursor + 1).min(self.letters.len() - 1)
+                    }
+                    Action::Start => {
+                        return Some(String::from_utf8_lossy(&self.letters).into_owned())
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn draw(&self, key_bindings: &KeyBindings) {
+        draw_rectangle(
+            0.,
+            0.,
+            WIDTH as f32,
+            HEIGHT as f32,
+            Color::new(0., 0., 0., 0.8),
+        );
+
+        Self::draw_centered("NEW HIGH SCORE!", 240., FONT_SIZE, WHITE);
+        Self::draw_centered("ENTER YOUR INITIALS", 280., FONT_SIZE, WHITE);
+
+        let left_x = (WIDTH as f32 - LETTER_SPACING * self.letters.len() as f32) / 2.;
+        for (i, letter) in self.letters.iter().enumerate() {
+            let color = if i == self.cursor { YELLOW } else { WHITE };
+            let text = (*letter as char).to_string();
+            let text_width = measure_text(&text, None, LETTER_FONT_SIZE as u16, 1.).width;
+            let x = left_x + LETTER_SPACING * i as f32 + (LETTER_SPACING - text_width) / 2.;
+            draw_text(&text, x, 400., LETTER_FONT_SIZE, color);
+        }
+
+        let hint = format!("{}: CONFIRM", key_bindings.key_hint(Action::Start));
+        Self::draw_centered(&hint, 480., FONT_SIZE, WHITE);
+    }
+
+    fn cycle_letter(&mut self, steps: u8) {
+        let letter = &mut self.letters[self.cursor];
+        *letter = b'A' + (*letter - b'A' + steps) % 26;
+    }
+
+    fn draw_centered(text: &str, y: f32, font_size: f32, color: Color) {
+        let text_width = measure_text(text, None, font_size as u16, 1.).width;
+        draw_text(text, (WIDTH as f32 - text_width) / 2., y, font_size, color);
+    }
+}
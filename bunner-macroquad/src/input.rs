@@ -0,0 +1,475 @@
+use crate::{player_direction::PlayerDirection, storage};
+use macroquad::input::KeyCode;
+use std::collections::VecDeque;
+
+const CONTROLS_FILE: &str = "controls.txt";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    // The player index is 0 or 1.
+    Move(usize, PlayerDirection),
+    Start,
+    DailyChallenge,
+    Versus,
+    Options,
+    Quit,
+}
+
+impl Action {
+    // Name used both in the config file and on the options screen.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Move(0, PlayerDirection::Up) => "up",
+            Action::Move(0, PlayerDirection::Right) => "right",
+            Action::Move(0, PlayerDirection::Down) => "down",
+            Action::Move(0, PlayerDirection::Left) => "left",
+            Action::Move(_, PlayerDirection::Up) => "p2_up",
+            Action::Move(_, PlayerDirection::Right) => "p2_right",
+            Action::Move(_, PlayerDirection::Down) => "p2_down",
+            Action::Move(_, PlayerDirection::Left) => "p2_left",
+            Action::Start => "start",
+            Action::DailyChallenge => "daily",
+            Action::Versus => "versus",
+            Action::Options => "options",
+            Action::Quit => "quit",
+        }
+    }
+
+    // Whether a screen reads both the actions, in which case they can't share an input. Quit is read
+    // everywhere but on the initials screen, and, on the options screen, it's the way back.
+    fn shares_screen(self, other: Action) -> bool {
+        use PlayerDirection::{Down, Up};
+
+        let screens: [fn(Action) -> bool; 4] = [
+            // Menu, and game over.
+            |action| {
+                matches!(
+                    action,
+                    Action::Start
+                        | Action::DailyChallenge
+                        | Action::Versus
+                        | Action::Options
+                        | Action::Quit
+                )
+            },
+            // Play.
+            |action| matches!(action, Action::Move(..) | Action::Quit),
+            // Options.
+            |action| {
+                matches!(
+                    action,
+                    Action::Move(0, Up | Down) | Action::Start | Action::Quit
+                )
+            },
+            // Initials.
+            |action| matches!(action, Action::Move(0, _) | Action::Start),
+        ];
+
+        screens.iter().any(|screen| screen(self) && screen(other))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RebindError {
+    // The key is not bindable (see BINDABLE_KEYS).
+    Unbindable,
+    // The input is bound to another action read by the same screen.
+    Conflict(Action),
+}
+
+// Rust: This mirrors the gilrs buttons, so that the bindings (and the config file) don't depend on
+// whether gamepad support is compiled in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GamepadButton {
+    South,
+    East,
+    North,
+    West,
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+    Select,
+    Start,
+    Mode,
+    LeftThumb,
+    RightThumb,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl GamepadButton {
+    const ALL: [GamepadButton; 17] = [
+        GamepadButton::South,
+        GamepadButton::East,
+        GamepadButton::North,
+        GamepadButton::West,
+        GamepadButton::LeftTrigger,
+        GamepadButton::LeftTrigger2,
+        GamepadButton::RightTrigger,
+        GamepadButton::RightTrigger2,
+        GamepadButton::Select,
+        GamepadButton::Start,
+        GamepadButton::Mode,
+        GamepadButton::LeftThumb,
+        GamepadButton::RightThumb,
+        GamepadButton::DPadUp,
+        GamepadButton::DPadDown,
+        GamepadButton::DPadLeft,
+        GamepadButton::DPadRight,
+    ];
+}
+
+// The keys that can be bound; the others (e.g. the function keys) are left to the system.
+const BINDABLE_KEYS: [KeyCode; 56] = [
+    KeyCode::Space,
+    KeyCode::Enter,
+    KeyCode::Escape,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::Up,
+    KeyCode::Right,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::Key0,
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Kp2,
+    KeyCode::Kp4,
+    KeyCode::Kp6,
+    KeyCode::Kp8,
+    KeyCode::KpEnter,
+    KeyCode::LeftShift,
+    KeyCode::RightShift,
+    KeyCode::LeftControl,
+    KeyCode::RightControl,
+    KeyCode::LeftAlt,
+    KeyCode::RightAlt,
+];
+
+/// A raw input, before it's mapped to actions via the bindings. Buttons carry the index of their
+/// gamepad, since the move actions of each player are read from their own gamepad.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputEvent {
+    Key(KeyCode),
+    Button(usize, GamepadButton),
+}
+
+pub struct Binding {
+    pub action: Action,
+    pub keys: Vec<KeyCode>,
+    pub buttons: Vec<GamepadButton>,
+}
+
+pub struct KeyBindings {
+    bindings: Vec<Binding>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let binding = |action, keys: &[KeyCode], buttons: &[GamepadButton]| Binding {
+            action,
+            keys: keys.to_vec(),
+            buttons: buttons.to_vec(),
+        };
+
+        Self {
+            bindings: vec![
+                binding(
+                    Action::Move(0, PlayerDirection::Up),
+                    &[KeyCode::Up],
+                    &[GamepadButton::DPadUp],
+                ),
+                binding(
+                    Action::Move(0, PlayerDirection::Right),
+                    &[KeyCode::Right],
+                    &[GamepadButton::DPadRight],
+                ),
+                binding(
+                    Action::Move(0, PlayerDirection::Down),
+                    &[KeyCode::Down],
+                    &[GamepadButton::DPadDown],
+                ),
+                binding(
+                    Action::Move(0, PlayerDirection::Left),
+                    &[KeyCode::Left],
+                    &[GamepadButton::DPadLeft],
+                ),
+                binding(
+                    Action::Move(1, PlayerDirection::Up),
+                    &[KeyCode::W],
+                    &[GamepadButton::DPadUp],
+                ),
+                binding(
+                    Action::Move(1, PlayerDirection::Right),
+                    &[KeyCode::D],
+                    &[GamepadButton::DPadRight],
+                ),
+                binding(
+                    Action::Move(1, PlayerDirection::Down),
+                    &[KeyCode::S],
+                    &[GamepadButton::DPadDown],
+                ),
+                binding(
+                    Action::Move(1, PlayerDirection::Left),
+                    &[KeyCode::A],
+                    &[GamepadButton::DPadLeft],
+                ),
+                binding(
+                    Action::Start,
+                    &[KeyCode::Space],
+                    &[GamepadButton::South, GamepadButton::Start],
+                ),
+                binding(
+                    Action::DailyChallenge,
+                    &[KeyCode::D],
+                    &[GamepadButton::North],
+                ),
+                binding(Action::Versus, &[KeyCode::V], &[GamepadButton::West]),
+                binding(Action::Options, &[KeyCode::O], &[GamepadButton::Select]),
+                binding(
+                    Action::Quit,
+                    &[KeyCode::Escape, KeyCode::Q],
+                    &[GamepadButton::Mode],
+                ),
+            ],
+        }
+    }
+}
+
+impl KeyBindings {
+    // The config file has one line per action, in the format `<action> = <keys> ; <buttons>`; unknown
+    // names, and missing actions, are ignored, leaving the defaults in place.
+    pub fn load() -> Self {
+        let mut key_bindings = Self::default();
+
+        if let Some(config) = storage::read(CONTROLS_FILE) {
+            for line in config.lines() {
+                if let Some((action_name, inputs)) = line.split_once('=') {
+                    let (keys, buttons) = inputs.split_once(';').unwrap_or((inputs, ""));
+                    if let Some(binding) = key_bindings
+                        .bindings
+                        .iter_mut()
+                        .find(|binding| binding.action.name() == action_name.trim())
+                    {
+                        binding.keys = keys
+                            .split_whitespace()
+                            .filter_map(|name| {
+                                BINDABLE_KEYS.into_iter().find(|key| key_name(*key) == name)
+                            })
+                            .collect();
+                        binding.buttons = buttons
+                            .split_whitespace()
+                            .filter_map(|name| {
+                                GamepadButton::ALL
+                                    .into_iter()
+                                    .find(|button| button_name(*button) == name)
+                            })
+                            .collect();
+                    }
+                }
+            }
+        }
+
+        key_bindings
+    }
+
+    pub fn save(&self) {
+        let config = self
+            .bindings
+            .iter()
+            .map(|binding| {
+                format!(
+                    "{} = {} ; {}\n",
+                    binding.action.name(),
+                    names(&binding.keys, key_name),
+                    names(&binding.buttons, button_name)
+                )
+            })
+            .collect::<String>();
+        storage::write(CONTROLS_FILE, &config);
+    }
+
+    pub fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+
+    pub fn actions(&self, event: InputEvent) -> Vec<Action> {
+        self.bindings
+            .iter()
+            .filter(|binding| match event {
+                InputEvent::Key(key) => binding.keys.contains(&key),
+                InputEvent::Button(gamepad, button) => {
+                    let own_gamepad = match binding.action {
+                        Action::Move(player, _) => player == gamepad,
+                        _ => true,
+                    };
+                    own_gamepad && binding.buttons.contains(&button)
+                }
+            })
+            .map(|binding| binding.action)
+            .collect()
+    }
+
+    // Replaces the keys, or the buttons, of the action, depending on the event. Conflicting rebinds
+    // are refused rather than resolved by unbinding the other action, which could leave it (e.g. Quit)
+    // without inputs.
+    pub fn rebind(&mut self, action: Action, event: InputEvent) -> Result<(), RebindError> {
+        if let InputEvent::Key(key) = event {
+            if !BINDABLE_KEYS.contains(&key) {
+                return Err(RebindError::Unbindable);
+            }
+        }
+
+        let conflict = self.bindings.iter().find(|binding| {
+            binding.action != action
+                && binding.action.shares_screen(action)
+                && match event {
+                    InputEvent::Key(key) => binding.keys.contains(&key),
+                    // The moves of each player are read from their own gamepad.
+                    InputEvent::Button(_, button) => {
+                        let other_player = matches!(
+                            (binding.action, action),
+                            (Action::Move(player, _), Action::Move(other, _)) if player != other
+                        );
+                        !other_player && binding.buttons.contains(&button)
+                    }
+                }
+        });
+        if let Some(conflict) = conflict {
+            return Err(RebindError::Conflict(conflict.action));
+        }
+
+        let binding = self
+            .bindings
+            .iter_mut()
+            .find(|binding| binding.action == action)
+            .unwrap();
+
+        match event {
+            InputEvent::Key(key) => binding.keys = vec![key],
+            InputEvent::Button(_, button) => binding.buttons = vec![button],
+        }
+
+        Ok(())
+    }
+
+    // Name of the (first) key bound to the action, for the on-screen hints.
+    pub fn key_hint(&self, action: Action) -> String {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == action)
+            .and_then(|binding| binding.keys.first())
+            .map_or_else(|| "-".to_string(), |key| key_name(*key).to_uppercase())
+    }
+}
+
+pub fn key_name(key: KeyCode) -> String {
+    format!("{:?}", key)
+}
+
+pub fn button_name(button: GamepadButton) -> String {
+    format!("{:?}", button)
+}
+
+pub fn names<T: Copy>(values: &[T], name: fn(T) -> String) -> String {
+    values
+        .iter()
+        .map(|value| name(*value))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Rust: Macroquad doesn't support gamepads, so they're read via gilrs. This is optional (the
+/// `gamepad` feature), since on Linux it requires libudev, and it's not available on the web.
+pub struct Gamepads {
+    #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+    gilrs: Option<gilrs::Gilrs>,
+}
+
+// Derivable only when gamepad support is not compiled in.
+#[allow(clippy::derivable_impls)]
+impl Default for Gamepads {
+    fn default() -> Self {
+        Self {
+            #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+            gilrs: gilrs::Gilrs::new().ok(),
+        }
+    }
+}
+
+impl Gamepads {
+    pub fn poll(&mut self, _input_queue: &mut VecDeque<InputEvent>) {
+        #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+        if let Some(gilrs) = self.gilrs.as_mut() {
+            while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+                if let gilrs::EventType::ButtonPressed(button, _) = event {
+                    if let Some(button) = Self::convert_button(button) {
+                        _input_queue.push_back(InputEvent::Button(usize::from(id), button));
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+    fn convert_button(button: gilrs::Button) -> Option<GamepadButton> {
+        use gilrs::Button;
+
+        match button {
+            Button::South => Some(GamepadButton::South),
+            Button::East => Some(GamepadButton::East),
+            Button::North => Some(GamepadButton::North),
+            Button::West => Some(GamepadButton::West),
+            Button::LeftTrigger => Some(GamepadButton::LeftTrigger),
+            Button::LeftTrigger2 => Some(GamepadButton::LeftTrigger2),
+            Button::RightTrigger => Some(GamepadButton::RightTrigger),
+            Button::RightTrigger2 => Some(GamepadButton::RightTrigger2),
+            Button::Select => Some(GamepadButton::Select),
+            Button::Start => Some(GamepadButton::Start),
+            Button::Mode => Some(GamepadButton::Mode),
+            Button::LeftThumb => Some(GamepadButton::LeftThumb),
+            Button::RightThumb => Some(GamepadButton::RightThumb),
+            Button::DPadUp => Some(GamepadButton::DPadUp),
+            Button::DPadDown => Some(GamepadButton::DPadDown),
+            Button::DPadLeft => Some(GamepadButton::DPadLeft),
+            Button::DPadRight => Some(GamepadButton::DPadRight),
+            Button::C | Button::Z | Button::Unknown => None,
+        }
+    }
+}
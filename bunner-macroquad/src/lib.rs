@@ -1,8 +1,10 @@
 pub mod active_row;
 pub mod actor;
+pub mod bird;
 pub mod bunner;
 pub mod car;
 pub mod child;
+pub mod conveyor;
 pub mod dirt;
 pub mod drawing;
 pub mod eagle;
@@ -13,8 +15,13 @@ pub mod hedge;
 pub mod hedge_mask;
 pub mod hedge_row;
 pub mod hedge_tile;
+pub mod high_scores;
+pub mod ice;
+pub mod initials_screen;
+pub mod input;
 pub mod log;
 pub mod mover;
+pub mod options_screen;
 pub mod pavement;
 pub mod player_direction;
 pub mod player_state;
@@ -25,6 +32,7 @@ pub mod road;
 pub mod row;
 pub mod splat;
 pub mod state;
+pub mod storage;
 pub mod train;
 pub mod water;
 
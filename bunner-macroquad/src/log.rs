@@ -1,14 +1,24 @@
 use crate::{actor::Actor, mover::Mover, position::Position, resources::Resources};
 use macroquad::{
+    color::Color,
     prelude::{collections::storage, draw_texture, WHITE},
-    rand::gen_range,
+    rand::RandGenerator,
 };
 
+// Crocodiles are (tinted) logs that periodically sink: they float for most of the cycle, blink
+// while sinking, then can't be stood on until they surface again.
+const CROCODILE_CYCLE: i32 = 300;
+const CROCODILE_SINKING: i32 = 200;
+const CROCODILE_SUBMERGED: i32 = 240;
+const CROCODILE_TINT: Color = Color::new(0.45, 0.8, 0.35, 1.);
+
 #[derive(Clone)]
 pub struct Log {
     dx: i32,
     position: Position,
     image_index: usize,
+    // Position in the sink cycle, for crocodiles.
+    crocodile_timer: Option<i32>,
 }
 
 impl Mover for Log {
@@ -20,6 +30,9 @@ impl Mover for Log {
 impl Actor for Log {
     fn update(&mut self) {
         self.position.x += self.dx;
+        if let Some(timer) = self.crocodile_timer.as_mut() {
+            *timer = (*timer + 1) % CROCODILE_CYCLE;
+        }
     }
 
     fn draw(&self, offset_x: i32, offset_y: i32) {
@@ -27,11 +40,20 @@ impl Actor for Log {
             .log_textures
             .get(self.image_index)
             .unwrap();
+        let color = match self.crocodile_timer {
+            None => WHITE,
+            Some(timer) if timer < CROCODILE_SINKING => CROCODILE_TINT,
+            Some(timer) if timer < CROCODILE_SUBMERGED && (timer / 5) % 2 == 0 => CROCODILE_TINT,
+            Some(_) => Color {
+                a: 0.3,
+                ..CROCODILE_TINT
+            },
+        };
         draw_texture(
             image,
             (self.position.x + offset_x) as f32 - image.width() / 2.,
             (self.position.y + offset_y) as f32 - image.height(),
-            WHITE,
+            color,
         );
     }
 
@@ -53,12 +75,26 @@ impl Actor for Log {
 }
 
 impl Log {
-    pub fn new(dx: i32, position: Position) -> Self {
-        let image_index = gen_range::<usize>(0, 2);
+    pub fn new(dx: i32, position: Position, rng: &RandGenerator) -> Self {
+        let image_index = rng.gen_range::<usize>(0, 2);
         Self {
             dx,
             position,
             image_index,
+            crocodile_timer: None,
+        }
+    }
+
+    // The cycles are staggered, so that the crocodiles of a row don't sink all at once.
+    pub fn crocodile(dx: i32, position: Position, rng: &RandGenerator) -> Self {
+        Self {
+            crocodile_timer: Some(rng.gen_range(0, CROCODILE_SINKING)),
+            ..Self::new(dx, position, rng)
         }
     }
+
+    pub fn submerged(&self) -> bool {
+        self.crocodile_timer
+            .is_some_and(|timer| timer >= CROCODILE_SUBMERGED)
+    }
 }
@@ -3,7 +3,7 @@
 
 use macroquad::{
     audio::{self},
-    input::{is_key_pressed, utils::*, KeyCode},
+    input::utils::*,
     time::get_frame_time,
     window::{next_frame, Conf},
 };
@@ -36,15 +36,15 @@ async fn main() -> Result<(), Box<dyn error::Error>> {
     let input_subscriber = register_input_subscriber();
     let mut frame_time: f32 = 0.;
     loop {
-        if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::Q) {
-            std::process::exit(0);
-        }
         repeat_all_miniquad_input(&mut global_state, input_subscriber);
         frame_time += get_frame_time().min(0.25);
         while frame_time >= TIME_PER_FRAME {
             global_state.update();
             frame_time -= TIME_PER_FRAME;
         }
+        if global_state.quit() {
+            std::process::exit(0);
+        }
         global_state.draw();
 
         next_frame().await
@@ -0,0 +1,121 @@
+use crate::{
+    input::{button_name, key_name, names, Action, InputEvent, KeyBindings, RebindError},
+    player_direction::PlayerDirection,
+    HEIGHT, WIDTH,
+};
+use macroquad::{
+    color::{colors::WHITE, Color, YELLOW},
+    shapes::draw_rectangle,
+    text::{draw_text, measure_text},
+};
+
+const FONT_SIZE: f32 = 24.;
+const LINE_HEIGHT: f32 = 36.;
+
+#[derive(Default)]
+pub struct OptionsScreen {
+    selected: usize,
+    // Waiting for the key (or button) to bind to the selected action.
+    rebinding: bool,
+    // The last input refused because it's bound to another action, with the action.
+    conflict: Option<(InputEvent, Action)>,
+}
+
+impl OptionsScreen {
+    // Returns true when the player leaves the screen.
+    pub fn update(&mut self, events: &[InputEvent], key_bindings: &mut KeyBindings) -> bool {
+        let bindings_count = key_bindings.bindings().len();
+
+        for event in events {
+            if self.rebinding {
+                let action = key_bindings.bindings()[self.selected].action;
+                match key_bindings.rebind(action, *event) {
+                    Ok(()) => {
+                        key_bindings.save();
+                        self.rebinding = false;
+                        self.conflict = None;
+                    }
+                    Err(RebindError::Conflict(other_action)) => {
+                        self.conflict = Some((*event, other_action));
+                    }
+                    Err(RebindError::Unbindable) => {}
+                }
+                continue;
+            }
+
+            for action in key_bindings.actions(*event) {
+                match action {
+                    Action::Move(0, PlayerDirection::Up) => {
+                        self.selected = (self.selected + bindings_count - 1) % bindings_count;
+                    }
+                    Action::Move(0, PlayerDirection::Down) => {
+                        self.selected = (self.selected + 1) % bindings_count;
+                    }
+                    Action::Start => self.rebinding = true,
+                    Action::Quit => return true,
+                    _ => {}
+                }
+            }
+        }
+
+        false
+    }
+
+    pub fn draw(&self, key_bindings: &KeyBindings) {
+        draw_rectangle(
+            0.,
+            0.,
+            WIDTH as f32,
+            HEIGHT as f32,
+            Color::new(0., 0., 0., 0.8),
+        );
+
+        Self::draw_centered("CONTROLS", 120., WHITE);
+
+        for (i, binding) in key_bindings.bindings().iter().enumerate() {
+            let y = 180. + i as f32 * LINE_HEIGHT;
+            let color = if i == self.selected { YELLOW } else { WHITE };
+            let inputs = if self.rebinding && i == self.selected {
+                "PRESS A KEY OR BUTTON".to_string()
+            } else {
+                format!(
+                    "{} / {}",
+                    names(&binding.keys, key_name),
+                    names(&binding.buttons, button_name)
+                )
+                .to_uppercase()
+            };
+            draw_text(
+                &binding.action.name().to_uppercase(),
+                40.,
+                y,
+                FONT_SIZE,
+                color,
+            );
+            draw_text(&inputs, 160., y, FONT_SIZE, color);
+        }
+
+        if let Some((event, action)) = self.conflict {
+            let input = match event {
+                InputEvent::Key(key) => key_name(key),
+                InputEvent::Button(_, button) => button_name(button),
+            };
+            let message = format!("{} IS USED BY {}", input, action.name()).to_uppercase();
+            Self::draw_centered(&message, (HEIGHT - 80) as f32, YELLOW);
+        }
+
+        let hint = format!(
+            "{}/{}: SELECT  {}: CHANGE  {}: BACK",
+            key_bindings.key_hint(Action::Move(0, PlayerDirection::Up)),
+            key_bindings.key_hint(Action::Move(0, PlayerDirection::Down)),
+            key_bindings.key_hint(Action::Start),
+            key_bindings.key_hint(Action::Quit),
+        );
+        Self::draw_centered(&hint, (HEIGHT - 40) as f32, WHITE);
+    }
+
+    fn draw_centered(text: &str, y: f32, color: Color) {
+        let text_width = measure_text(text, None, FONT_SIZE as u16, 1.).width;
+        draw_text(text, (WIDTH as f32 - text_width) / 2., y, FONT_SIZE, color);
+    }
+}
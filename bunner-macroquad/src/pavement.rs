@@ -1,5 +1,12 @@
-use crate::{child::Child, resources::Resources, road::Road, row::Row, ROW_HEIGHT};
-use macroquad::{audio::play_sound_once, prelude::collections::storage, texture::Texture2D};
+use crate::{
+    child::Child,
+    conveyor::Conveyor,
+    resources::{play_sound_once, Resources},
+    road::Road,
+    row::{chance, difficulty, Row},
+    ROW_HEIGHT,
+};
+use macroquad::{prelude::collections::storage, rand::RandGenerator, texture::Texture2D};
 
 #[derive(Clone)]
 pub struct Pavement {
@@ -29,20 +36,24 @@ impl Row for Pavement {
     }
 
     fn play_sound(&self) {
-        play_sound_once(storage::get::<Resources>().sidewalk_sound);
+        play_sound_once(|resources| resources.sidewalk_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
         if self.index < 2 {
             Box::new(Pavement::new(self.index + 1, y))
+        } else if chance(Pavement::CONVEYOR_CHANCE * difficulty(y), rng) {
+            Box::new(Conveyor::new(y, rng))
         } else {
-            Box::new(Road::empty(y))
+            Box::new(Road::empty(y, rng))
         }
     }
 }
 
 impl Pavement {
+    const CONVEYOR_CHANCE: f32 = 0.6;
+
     pub fn new(index: i32, y: i32) -> Self {
         Self {
             index,
@@ -1,13 +1,8 @@
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum PlayerDirection {
     Up,
     Right,
+    #[default]
     Down,
     Left,
 }
-
-impl Default for PlayerDirection {
-    fn default() -> Self {
-        PlayerDirection::Down
-    }
-}
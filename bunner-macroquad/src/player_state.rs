@@ -1,13 +1,8 @@
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum PlayerState {
+    #[default]
     Alive,
     Splat(i32),
     Splash,
     Eagle(i32),
 }
-
-impl Default for PlayerState {
-    fn default() -> Self {
-        PlayerState::Alive
-    }
-}
@@ -1,4 +1,4 @@
-#[derive(Default, Copy, Clone)]
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Position {
     pub x: i32,
     pub y: i32,
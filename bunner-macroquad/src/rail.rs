@@ -1,20 +1,26 @@
 use crate::{
-    child::Child, player_state::PlayerState, position::Position, resources::Resources, road::Road,
-    row::Row, train::Train, water::Water, HEIGHT, ROW_HEIGHT, WIDTH,
+    child::Child,
+    player_state::PlayerState,
+    position::Position,
+    resources::{play_sound_once, Resources},
+    road::Road,
+    row::{row_rng, Row},
+    train::Train,
+    water::Water,
+    HEIGHT, ROW_HEIGHT, WIDTH,
 };
 
 use macroquad::{
-    audio::play_sound_once,
     prelude::collections::storage,
-    rand::{self, ChooseRandom},
+    rand::{self, ChooseRandom, RandGenerator},
     texture::Texture2D,
 };
 
-#[derive(Clone)]
 pub struct Rail {
     index: i32,
     y: i32,
     children: Vec<Child>,
+    rng: RandGenerator,
 }
 
 impl Row for Rail {
@@ -37,21 +43,18 @@ impl Row for Rail {
                 .retain(|c| c.x() > -1000 && c.x() < WIDTH + 1000);
             if self.y < scroll_pos + HEIGHT
                 && self.children.is_empty()
-                && rand::gen_range::<u8>(0, 100) < 1
+                && self.rng.gen_range::<u8>(0, 100) < 1
             {
-                let dx = *vec![-20, 20].choose().unwrap();
+                let dx = *[-20, 20].choose_with_state(&self.rng).unwrap();
                 let position = if dx < 0 {
                     Position::new(WIDTH + 1000, 47)
                 } else {
                     Position::new(WIDTH - 1000, 47)
                 };
-                self.children.push(Child::Train(Train::new(dx, position)));
-                play_sound_once(storage::get::<Resources>().bell_sound);
-                let train_sound = *storage::get::<Resources>()
-                    .train_sounds
-                    .get(rand::gen_range::<usize>(0, 2))
-                    .unwrap();
-                play_sound_once(train_sound);
+                self.children
+                    .push(Child::Train(Train::new(dx, position, &self.rng)));
+                play_sound_once(|resources| resources.bell_sound);
+                play_sound_once(|resources| resources.train_sounds[rand::gen_range::<usize>(0, 2)]);
             }
         }
     }
@@ -64,17 +67,17 @@ impl Row for Rail {
     }
 
     fn play_sound(&self) {
-        play_sound_once(storage::get::<Resources>().grass_sound);
+        play_sound_once(|resources| resources.grass_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
         if self.index < 3 {
-            Box::new(Rail::new(self.index + 1, y))
-        } else if rand::gen_range::<u8>(0, 2) == 0 {
-            Box::new(Road::empty(y))
+            Box::new(Rail::new(self.index + 1, y, rng))
+        } else if rng.gen_range::<u8>(0, 2) == 0 {
+            Box::new(Road::empty(y, rng))
         } else {
-            Box::new(Water::empty(y))
+            Box::new(Water::empty(y, rng))
         }
     }
 
@@ -87,15 +90,16 @@ impl Row for Rail {
 }
 
 impl Rail {
-    pub fn new(index: i32, y: i32) -> Self {
+    pub fn new(index: i32, y: i32, rng: &RandGenerator) -> Self {
         Self {
             index,
             y,
             children: Vec::new(),
+            rng: row_rng(rng),
         }
     }
 
-    pub fn empty(y: i32) -> Self {
-        Self::new(0, y)
+    pub fn empty(y: i32, rng: &RandGenerator) -> Self {
+        Self::new(0, y, rng)
     }
 }
@@ -5,7 +5,30 @@ use macroquad::{
     audio::{self, load_sound, Sound},
     prelude::{collections::storage, coroutines::start_coroutine, load_texture, Texture2D, *},
 };
-use std::error;
+use std::{
+    error,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+// Rust: When running headless (e.g. in the tests), there is no window, and therefore neither loaded
+// resources nor an audio context, so the simulation must not touch them. This is a process-wide
+// switch (rather than a check on the storage), since the macroquad storage is not thread-safe.
+static HEADLESS: AtomicBool = AtomicBool::new(false);
+
+pub fn set_headless(headless: bool) {
+    HEADLESS.store(headless, Ordering::Relaxed);
+}
+
+pub fn is_headless() -> bool {
+    HEADLESS.load(Ordering::Relaxed)
+}
+
+// Play a sound, selected from the resources; this is a no-op when running headless.
+pub fn play_sound_once(select: impl FnOnce(&Resources) -> Sound) {
+    if !is_headless() {
+        audio::play_sound_once(select(&storage::get::<Resources>()));
+    }
+}
 
 // Async blocks are (as of Jun/2021) unstable, so cycles are used where required.
 //
@@ -1,23 +1,34 @@
 use crate::{
-    active_row::ActiveRow, actor::Actor, car::Car, car::CarSound, car::TrafficSound, child::Child,
-    grass::Grass, mover::Mover, pavement::Pavement, player_state::PlayerState, position::Position,
-    rail::Rail, resources::Resources, row::Row, row::RowSound, ROW_HEIGHT, WIDTH,
+    active_row::ActiveRow,
+    actor::Actor,
+    car::Car,
+    car::CarSound,
+    car::TrafficSound,
+    child::Child,
+    grass::Grass,
+    mover::Mover,
+    pavement::Pavement,
+    player_state::PlayerState,
+    position::Position,
+    rail::Rail,
+    resources::{play_sound_once, Resources},
+    row::{difficulty, row_rng, Row, RowSound},
+    ROW_HEIGHT, WIDTH,
 };
 
 use macroquad::{
-    audio::play_sound_once,
     prelude::collections::storage,
-    rand::{self, ChooseRandom},
+    rand::{ChooseRandom, RandGenerator},
     texture::Texture2D,
 };
 
-#[derive(Clone)]
 pub struct Road {
     dx: i32,
     timer: f32,
     index: i32,
     y: i32,
     children: Vec<Child>,
+    rng: RandGenerator,
 }
 
 impl Row for Road {
@@ -40,8 +51,9 @@ impl Row for Road {
 
         // Create new child objects on a random interval
         if self.timer < 0. {
-            self.children.push(self.create_random_child(self.dx));
-            self.timer = self.random_interval(self.dx);
+            self.children
+                .push(self.create_random_child(self.dx, difficulty(self.y), &self.rng));
+            self.timer = self.random_interval(self.dx, &self.rng);
         }
 
         if let Some(bunner_pos) = bunner_pos {
@@ -77,30 +89,30 @@ impl Row for Road {
     }
 
     fn play_sound(&self) {
-        play_sound_once(storage::get::<Resources>().road_sound);
+        play_sound_once(|resources| resources.road_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
         if self.index == 0 {
-            Box::new(Road::new(self.dx, 1, y))
+            Box::new(Road::new(self.dx, 1, y, rng))
         } else if self.index < 5 {
-            let random = rand::gen_range::<u8>(0, 100);
+            let random = rng.gen_range::<u8>(0, 100);
             if random < 80 {
-                Box::new(Road::new(self.dx, self.index + 1, y))
+                Box::new(Road::new(self.dx, self.index + 1, y, rng))
             } else if random < 88 {
-                Box::new(Grass::without_hedge(rand::gen_range(0, 7), y))
+                Box::new(Grass::without_hedge(rng.gen_range(0, 7), y, rng))
             } else if random < 94 {
-                Box::new(Rail::empty(y))
+                Box::new(Rail::empty(y, rng))
             } else {
                 Box::new(Pavement::empty(y))
             }
         } else {
-            let random = rand::gen_range::<u8>(0, 100);
+            let random = rng.gen_range::<u8>(0, 100);
             if random < 60 {
-                Box::new(Grass::without_hedge(rand::gen_range(0, 7), y))
+                Box::new(Grass::without_hedge(rng.gen_range(0, 7), y, rng))
             } else if random < 90 {
-                Box::new(Rail::empty(y))
+                Box::new(Rail::empty(y, rng))
             } else {
                 Box::new(Pavement::empty(y))
             }
@@ -129,8 +141,8 @@ impl Row for Road {
 }
 
 impl ActiveRow for Road {
-    fn build_child(dx: i32, position: Position) -> Child {
-        Child::Car(Car::new(dx, position))
+    fn build_child(dx: i32, position: Position, _difficulty: f32, rng: &RandGenerator) -> Child {
+        Child::Car(Car::new(dx, position, rng))
     }
 }
 
@@ -151,24 +163,25 @@ impl Road {
     ];
     const DXS: &'static [i32] = &[-5, -4, -3, -2, -1, 1, 2, 3, 4, 5];
 
-    pub fn new(previous_dx: i32, index: i32, y: i32) -> Self {
+    pub fn new(previous_dx: i32, index: i32, y: i32, rng: &RandGenerator) -> Self {
         // Populate the row with child objects (cars or logs). Without this, the row would initially be empty.
         let dx = **Self::DXS
             .iter()
             .filter(|&dx| *dx != previous_dx)
             .collect::<Vec<&i32>>()
-            .choose()
+            .choose_with_state(rng)
             .unwrap();
         Self {
             dx,
             timer: 0.,
             index,
             y,
-            children: Self::build_children(dx),
+            children: Self::build_children(dx, difficulty(y), rng),
+            rng: row_rng(rng),
         }
     }
 
-    pub fn empty(y: i32) -> Self {
-        Self::new(0, 0, y)
+    pub fn empty(y: i32, rng: &RandGenerator) -> Self {
+        Self::new(0, 0, y, rng)
     }
 }
@@ -1,9 +1,50 @@
-use crate::{child::Child, player_state::PlayerState, position::Position, WIDTH};
+use crate::{child::Child, game::distance, player_state::PlayerState, position::Position, WIDTH};
 use macroquad::{
-    color::colors::WHITE,
+    color::{colors::WHITE, Color},
+    rand::RandGenerator,
     texture::{draw_texture, Texture2D},
 };
 
+// The new hazards don't show up before this distance, and are at their most frequent after
+// DIFFICULTY_RAMP further rows.
+const EASY_DISTANCE: f32 = 20.;
+const DIFFICULTY_RAMP: f32 = 150.;
+
+// Between 0 and 1. It's based on the score a bunner reaching the row would have, rather than on the
+// current score, so that the level generated by a seed doesn't depend on how it's played; for the
+// same reason, the hazards spawned while a row is on screen (e.g. the birds) are drawn from the row
+// generator (see row_rng()).
+pub fn difficulty(y: i32) -> f32 {
+    ((distance(y) as f32 - EASY_DISTANCE) / DIFFICULTY_RAMP).clamp(0., 1.)
+}
+
+// The RNG is not invoked for impossible events, so that the hazards don't alter the easy part of
+// the levels.
+pub fn chance(probability: f32, rng: &RandGenerator) -> bool {
+    probability > 0. && rng.gen_range::<f32>(0., 1.) < probability
+}
+
+// Rust: The rows that spawn children while on screen (e.g. the cars) draw from their own generator,
+// seeded from the level one when the row is created. The number of draws depends on how long the
+// row stays on screen, so sharing the level generator would make the next rows depend on the pace
+// of the player.
+pub fn row_rng(level_rng: &RandGenerator) -> RandGenerator {
+    let rng = RandGenerator::new();
+    rng.srand(level_rng.rand() as u64);
+    rng
+}
+
+pub fn collide_children<'a>(
+    children: impl Iterator<Item = &'a Child>,
+    x: i32,
+    margin: i32,
+) -> bool {
+    children.filter(|child| child.tangible()).any(|child| {
+        x >= child.x() - (child.width() / 2) - margin
+            && x < child.x() + (child.width() / 2) + margin
+    })
+}
+
 pub trait Row {
     fn y(&self) -> i32;
     fn children(&self) -> &[Child];
@@ -21,6 +62,11 @@ pub trait Row {
 
     fn image(&self) -> Texture2D;
 
+    // Rust: Allows rows to reuse the textures of other rows.
+    fn tint(&self) -> Color {
+        WHITE
+    }
+
     fn draw(&self, offset_x: i32, offset_y: i32) {
         let (x, y) = self.draw_row(offset_x, offset_y);
         self.draw_children(x, y);
@@ -30,7 +76,7 @@ pub trait Row {
         let image = self.image();
         let x = offset_x;
         let y = self.y() + offset_y;
-        draw_texture(image, x as f32, y as f32 - image.height(), WHITE);
+        draw_texture(image, x as f32, y as f32 - image.height(), self.tint());
         (x, y)
     }
 
@@ -42,7 +88,9 @@ pub trait Row {
 
     fn play_sound(&self);
 
-    fn next(&self) -> Box<dyn Row>;
+    // The rows, and their children, are generated via the game RNG, so that a seed always generates the
+    // same level.
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row>;
 
     fn check_collision(&self, _x: i32) -> PlayerState {
         PlayerState::Alive
@@ -53,20 +101,18 @@ pub trait Row {
     }
 
     fn collide(&self, x: i32, margin: i32) -> bool {
-        for child in self.children().iter() {
-            if x >= child.x() - (child.width() / 2) - margin
-                && x < child.x() + (child.width() / 2) + margin
-            {
-                return true;
-            }
-        }
-        false
+        collide_children(self.children().iter(), x, margin)
     }
 
     fn push(&self) -> i32 {
         0
     }
 
+    // On slippery rows, the bunner keeps moving in the same direction after landing.
+    fn slippery(&self) -> bool {
+        false
+    }
+
     fn sound(&self) -> Option<RowSound> {
         None
     }
@@ -3,4 +3,6 @@ pub enum State {
     Menu,
     Play,
     GameOver,
+    Options,
+    EnterInitials,
 }
@@ -0,0 +1,51 @@
+// Where the persistent data (high scores, controls...) is stored; on native platforms, it's a file
+// in the user data directory, while on the web, it's the browser local storage.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use file_storage::{read, write};
+#[cfg(target_arch = "wasm32")]
+pub use local_storage::{read, write};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod file_storage {
+    use std::{fs, path::PathBuf};
+
+    const DATA_DIR: &str = "bunner-macroquad";
+
+    // Falls back to the current directory if the platform has no data directory.
+    fn path(name: &str) -> PathBuf {
+        dirs::data_dir()
+            .map(|data_dir| data_dir.join(DATA_DIR))
+            .unwrap_or_default()
+            .join(name)
+    }
+
+    pub fn read(name: &str) -> Option<String> {
+        fs::read_to_string(path(name)).ok()
+    }
+
+    // Persistence is best effort; a failure is not worth interrupting the game for.
+    pub fn write(name: &str, data: &str) {
+        let path = path(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).ok();
+        }
+        fs::write(path, data).ok();
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod local_storage {
+    // The storage requires the `quad-storage` JS plugin to be loaded by the web page.
+    fn key(name: &str) -> String {
+        format!("bunner-macroquad-{}", name)
+    }
+
+    pub fn read(name: &str) -> Option<String> {
+        quad_storage::STORAGE.lock().unwrap().get(&key(name))
+    }
+
+    pub fn write(name: &str, data: &str) {
+        quad_storage::STORAGE.lock().unwrap().set(&key(name), data);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{actor::Actor, position::Position, resources::Resources};
 use macroquad::{
     prelude::{collections::storage, draw_texture, WHITE},
-    rand::ChooseRandom,
+    rand::{ChooseRandom, RandGenerator},
 };
 
 #[derive(Clone)]
@@ -43,11 +43,11 @@ impl Actor for Train {
 }
 
 impl Train {
-    pub fn new(dx: i32, position: Position) -> Self {
+    pub fn new(dx: i32, position: Position, rng: &RandGenerator) -> Self {
         let image_index = if dx < 0 {
-            *vec![0, 2, 4].choose().unwrap()
+            *[0, 2, 4].choose_with_state(rng).unwrap()
         } else {
-            *vec![1, 3, 5].choose().unwrap()
+            *[1, 3, 5].choose_with_state(rng).unwrap()
         };
         Self {
             dx,
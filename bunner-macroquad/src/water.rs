@@ -1,21 +1,23 @@
 use crate::{
-    active_row::ActiveRow, child::Child, dirt::Dirt, log::Log, player_state::PlayerState,
-    position::Position, resources::Resources, row::Row, row::RowSound, ROW_HEIGHT, WIDTH,
-};
-use macroquad::{
-    audio::play_sound_once,
-    prelude::collections::storage,
-    rand::{self},
-    texture::Texture2D,
+    active_row::ActiveRow,
+    child::Child,
+    dirt::Dirt,
+    log::Log,
+    player_state::PlayerState,
+    position::Position,
+    resources::{play_sound_once, Resources},
+    row::{chance, difficulty, row_rng, Row, RowSound},
+    ROW_HEIGHT, WIDTH,
 };
+use macroquad::{prelude::collections::storage, rand::RandGenerator, texture::Texture2D};
 
-#[derive(Clone)]
 pub struct Water {
     dx: i32,
     timer: f32,
     index: i32,
     y: i32,
     children: Vec<Child>,
+    rng: RandGenerator,
 }
 
 impl Row for Water {
@@ -38,8 +40,9 @@ impl Row for Water {
 
         // Create new child objects on a random interval
         if self.timer < 0. {
-            self.children.push(self.create_random_child(self.dx));
-            self.timer = self.random_interval(self.dx);
+            self.children
+                .push(self.create_random_child(self.dx, difficulty(self.y), &self.rng));
+            self.timer = self.random_interval(self.dx, &self.rng);
         }
     }
 
@@ -51,15 +54,15 @@ impl Row for Water {
     }
 
     fn play_sound(&self) {
-        play_sound_once(storage::get::<Resources>().log_sound);
+        play_sound_once(|resources| resources.log_sound);
     }
 
-    fn next(&self) -> Box<dyn Row> {
+    fn next(&self, rng: &RandGenerator) -> Box<dyn Row> {
         let y = self.y - ROW_HEIGHT;
-        if self.index == 7 || (self.index >= 1 && rand::gen_range(0, 2) == 0) {
-            Box::new(Dirt::new(rand::gen_range(4, 7), y))
+        if self.index == 7 || (self.index >= 1 && rng.gen_range(0, 2) == 0) {
+            Box::new(Dirt::new(rng.gen_range(4, 7), y))
         } else {
-            Box::new(Water::new(self.dx, self.index + 1, y))
+            Box::new(Water::new(self.dx, self.index + 1, y, rng))
         }
     }
 
@@ -80,28 +83,35 @@ impl Row for Water {
 }
 
 impl ActiveRow for Water {
-    fn build_child(dx: i32, position: Position) -> Child {
-        Child::Log(Log::new(dx, position))
+    fn build_child(dx: i32, position: Position, difficulty: f32, rng: &RandGenerator) -> Child {
+        if chance(Water::CROCODILE_CHANCE * difficulty, rng) {
+            Child::Log(Log::crocodile(dx, position, rng))
+        } else {
+            Child::Log(Log::new(dx, position, rng))
+        }
     }
 }
 
 impl Water {
-    pub fn new(previous_dx: i32, index: i32, y: i32) -> Self {
+    const CROCODILE_CHANCE: f32 = 0.4;
+
+    pub fn new(previous_dx: i32, index: i32, y: i32, rng: &RandGenerator) -> Self {
         let dx = if previous_dx >= 0 {
-            -rand::gen_range(1, 3)
+            -rng.gen_range(1, 3)
         } else {
-            rand::gen_range(1, 3)
+            rng.gen_range(1, 3)
         };
         Self {
             dx,
             timer: 0.,
             index,
             y,
-            children: Self::build_children(dx),
+            children: Self::build_children(dx, difficulty(y), rng),
+            rng: row_rng(rng),
         }
     }
 
-    pub fn empty(y: i32) -> Self {
-        Self::new(0, 0, y)
+    pub fn empty(y: i32, rng: &RandGenerator) -> Self {
+        Self::new(0, 0, y, rng)
     }
 }
@@ -0,0 +1,57 @@
+use bunner_macroquad::high_scores::{format_date, DeathCause, HighScore, HighScores};
+
+fn high_score(initials: &str, distance: u32) -> HighScore {
+    HighScore {
+        initials: initials.to_string(),
+        date: "2026-10-19".to_string(),
+        distance,
+        cause: DeathCause::Splash,
+    }
+}
+
+#[test]
+fn entries_are_sorted_and_capped() {
+    let mut high_scores = HighScores::default();
+    for distance in 1..=12 {
+        high_scores.insert(high_score("AAA", distance));
+    }
+
+    let distances = high_scores
+        .entries()
+        .iter()
+        .map(|entry| entry.distance)
+        .collect::<Vec<_>>();
+    assert_eq!(distances, (3..=12).rev().collect::<Vec<_>>());
+    assert_eq!(high_scores.best(), 12);
+}
+
+#[test]
+fn ties_rank_older_entries_first() {
+    let mut high_scores = HighScores::default();
+    high_scores.insert(high_score("OLD", 5));
+    high_scores.insert(high_score("NEW", 5));
+
+    assert_eq!(high_scores.entries()[0].initials, "OLD");
+    assert_eq!(high_scores.entries()[1].initials, "NEW");
+}
+
+#[test]
+fn qualification() {
+    let mut high_scores = HighScores::default();
+    assert!(!high_scores.qualifies(0));
+    assert!(high_scores.qualifies(1));
+
+    for _ in 0..10 {
+        high_scores.insert(high_score("AAA", 5));
+    }
+    assert!(!high_scores.qualifies(5));
+    assert!(high_scores.qualifies(6));
+}
+
+#[test]
+fn dates_are_formatted_in_utc() {
+    assert_eq!(format_date(0.), "1970-01-01");
+    assert_eq!(format_date(946_598_400.), "1999-12-31");
+    assert_eq!(format_date(951_782_400.), "2000-02-29");
+    assert_eq!(format_date(1_792_368_000. + 86_399.), "2026-10-19");
+}
@@ -0,0 +1,82 @@
+use bunner_macroquad::{
+    input::{Action, GamepadButton, InputEvent, KeyBindings, RebindError},
+    player_direction::PlayerDirection,
+};
+use macroquad::input::KeyCode;
+
+#[test]
+fn rebind_replaces_the_inputs() {
+    let mut key_bindings = KeyBindings::default();
+    let up = Action::Move(0, PlayerDirection::Up);
+
+    assert_eq!(key_bindings.rebind(up, InputEvent::Key(KeyCode::I)), Ok(()));
+
+    assert_eq!(key_bindings.actions(InputEvent::Key(KeyCode::I)), vec![up]);
+    assert!(key_bindings
+        .actions(InputEvent::Key(KeyCode::Up))
+        .is_empty());
+}
+
+#[test]
+fn rebind_refuses_inputs_of_actions_on_the_same_screen() {
+    let mut key_bindings = KeyBindings::default();
+
+    assert_eq!(
+        key_bindings.rebind(
+            Action::Move(1, PlayerDirection::Left),
+            InputEvent::Key(KeyCode::Escape)
+        ),
+        Err(RebindError::Conflict(Action::Quit))
+    );
+    assert_eq!(
+        key_bindings.rebind(Action::Start, InputEvent::Key(KeyCode::Q)),
+        Err(RebindError::Conflict(Action::Quit))
+    );
+    assert_eq!(
+        key_bindings.actions(InputEvent::Key(KeyCode::Escape)),
+        vec![Action::Quit]
+    );
+}
+
+#[test]
+fn rebind_allows_inputs_of_actions_on_other_screens() {
+    let mut key_bindings = KeyBindings::default();
+
+    // The moves are read only while playing, and the daily challenge only in the menu.
+    assert_eq!(
+        key_bindings.rebind(Action::DailyChallenge, InputEvent::Key(KeyCode::W)),
+        Ok(())
+    );
+    // Each player moves with their own gamepad.
+    assert_eq!(
+        key_bindings.rebind(
+            Action::Move(1, PlayerDirection::Up),
+            InputEvent::Button(1, GamepadButton::LeftTrigger)
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        key_bindings.rebind(
+            Action::Move(0, PlayerDirection::Up),
+            InputEvent::Button(0, GamepadButton::LeftTrigger)
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        key_bindings.rebind(
+            Action::Move(0, PlayerDirection::Left),
+            InputEvent::Button(0, GamepadButton::LeftTrigger)
+        ),
+        Err(RebindError::Conflict(Action::Move(0, PlayerDirection::Up)))
+    );
+}
+
+#[test]
+fn rebind_refuses_unbindable_keys() {
+    let mut key_bindings = KeyBindings::default();
+
+    assert_eq!(
+        key_bindings.rebind(Action::Start, InputEvent::Key(KeyCode::F1)),
+        Err(RebindError::Unbindable)
+    );
+}
@@ -0,0 +1,388 @@
+// Headless simulation tests: no window is opened, so nothing here may load or draw resources.
+
+use bunner_macroquad::{
+    actor::Actor, bird::Bird, bunner::Bunner, child::Child, conveyor::Conveyor, game::Game,
+    grass::Grass, ice::Ice, log::Log, player_direction::PlayerDirection, player_state::PlayerState,
+    position::Position, resources::set_headless, road::Road, row::difficulty, row::Row,
+    water::Water, HEIGHT, ROW_HEIGHT, WIDTH,
+};
+use macroquad::rand::RandGenerator;
+use std::collections::VecDeque;
+
+// A jump lasts 10 frames.
+const JUMP_FRAMES: usize = 10;
+
+fn seeded_rng(seed: u64) -> RandGenerator {
+    let rng = RandGenerator::new();
+    rng.srand(seed);
+    rng
+}
+
+fn moves(directions: &[PlayerDirection]) -> VecDeque<PlayerDirection> {
+    directions.iter().copied().collect()
+}
+
+// Jumps up from a grass row at y=0, to the row at y=-ROW_HEIGHT, and returns the bunner once landed.
+fn jump_up_onto(target_row: Box<dyn Row>, x: i32) -> Bunner {
+    let rng = seeded_rng(0);
+    let mut rows: Vec<Box<dyn Row>> = vec![Box::new(Grass::without_hedge(0, 0, &rng)), target_row];
+    let mut bunner = Bunner::new(Position::new(x, 0));
+
+    bunner.update(0, &mut rows, moves(&[PlayerDirection::Up]));
+    for _ in 1..JUMP_FRAMES {
+        bunner.update(0, &mut rows, VecDeque::new());
+    }
+
+    bunner
+}
+
+// Finds, across seeds, a row satisfying the predicate for some on-screen x, which is returned.
+fn find_row<R: Row>(
+    build_row: impl Fn(&RandGenerator) -> R,
+    predicate: impl Fn(&R, i32) -> bool,
+) -> (R, i32) {
+    (0..)
+        .find_map(|seed| {
+            let row = build_row(&seeded_rng(seed));
+            (16..=WIDTH - 16)
+                .find(|&x| predicate(&row, x))
+                .map(|x| (row, x))
+        })
+        .unwrap()
+}
+
+#[test]
+fn jump_onto_grass() {
+    set_headless(true);
+
+    let rng = seeded_rng(0);
+    let bunner = jump_up_onto(Box::new(Grass::without_hedge(0, -ROW_HEIGHT, &rng)), 240);
+
+    assert_eq!(bunner.state, PlayerState::Alive);
+    assert_eq!(bunner.position, Position::new(240, -ROW_HEIGHT));
+    assert_eq!(bunner.timer, 0);
+    assert_eq!(bunner.min_y, -ROW_HEIGHT);
+}
+
+#[test]
+fn jump_into_water_splashes() {
+    set_headless(true);
+
+    let (water, x) = find_row(
+        |rng| Water::empty(-ROW_HEIGHT, rng),
+        |water, x| water.check_collision(x) == PlayerState::Splash,
+    );
+    let bunner = jump_up_onto(Box::new(water), x);
+
+    assert_eq!(bunner.state, PlayerState::Splash);
+    assert_eq!(bunner.timer, 100);
+}
+
+#[test]
+fn jump_onto_log_is_pushed() {
+    set_headless(true);
+
+    let (water, x) = find_row(
+        |rng| Water::empty(-ROW_HEIGHT, rng),
+        // Leave some room for the push, so that the x position isn't clamped.
+        |water, x| water.check_collision(x) == PlayerState::Alive && (32..=WIDTH - 32).contains(&x),
+    );
+    let push = water.push();
+    let bunner = jump_up_onto(Box::new(water), x);
+
+    assert_eq!(bunner.state, PlayerState::Alive);
+    assert_eq!(bunner.position, Position::new(x + push, -ROW_HEIGHT));
+}
+
+#[test]
+fn jump_in_front_of_car_splats() {
+    set_headless(true);
+
+    let (road, x) = find_row(
+        |rng| Road::empty(-ROW_HEIGHT, rng),
+        |road, x| road.check_collision(x) == PlayerState::Splat(0),
+    );
+    let bunner = jump_up_onto(Box::new(road), x);
+
+    assert_eq!(bunner.state, PlayerState::Splat(0));
+    assert_eq!(bunner.timer, 100);
+}
+
+#[test]
+fn splat_leaves_splat_on_row() {
+    set_headless(true);
+
+    let rng = seeded_rng(0);
+    let (road, x) = find_row(
+        |rng| Road::empty(-ROW_HEIGHT, rng),
+        |road, x| road.check_collision(x) == PlayerState::Splat(0),
+    );
+    let mut rows: Vec<Box<dyn Row>> =
+        vec![Box::new(Grass::without_hedge(0, 0, &rng)), Box::new(road)];
+    let mut bunner = Bunner::new(Position::new(x, 0));
+
+    bunner.update(0, &mut rows, moves(&[PlayerDirection::Up]));
+    for _ in 1..JUMP_FRAMES {
+        bunner.update(0, &mut rows, VecDeque::new());
+    }
+
+    assert!(matches!(rows[1].children().first(), Some(Child::Splat(_))));
+}
+
+#[test]
+fn ice_keeps_bunner_sliding() {
+    set_headless(true);
+
+    let rng = seeded_rng(0);
+    let mut rows: Vec<Box<dyn Row>> = vec![
+        Box::new(Grass::without_hedge(0, 0, &rng)),
+        Box::new(Ice::new(0, -ROW_HEIGHT)),
+        Box::new(Ice::new(1, -ROW_HEIGHT * 2)),
+        Box::new(Grass::without_hedge(0, -ROW_HEIGHT * 3, &rng)),
+    ];
+    let mut bunner = Bunner::new(Position::new(240, 0));
+
+    // A single jump slides across the ice, up to the grass.
+    bunner.update(0, &mut rows, moves(&[PlayerDirection::Up]));
+    for _ in 1..JUMP_FRAMES * 5 {
+        bunner.update(0, &mut rows, VecDeque::new());
+    }
+
+    assert_eq!(bunner.state, PlayerState::Alive);
+    assert_eq!(bunner.position, Position::new(240, -ROW_HEIGHT * 3));
+}
+
+#[test]
+fn conveyor_pushes_bunner() {
+    set_headless(true);
+
+    let conveyor = Conveyor::new(-ROW_HEIGHT, &seeded_rng(0));
+    let push = conveyor.push();
+    let bunner = jump_up_onto(Box::new(conveyor), 240);
+
+    assert_ne!(push, 0);
+    assert_eq!(bunner.state, PlayerState::Alive);
+    assert_eq!(bunner.position, Position::new(240 + push, -ROW_HEIGHT));
+}
+
+#[test]
+fn crocodiles_sink_periodically() {
+    let mut crocodile = Log::crocodile(1, Position::new(240, 0), &seeded_rng(0));
+    assert!(!crocodile.submerged());
+
+    let mut submerged_frames = 0;
+    for _ in 0..300 {
+        crocodile.update();
+        submerged_frames += crocodile.submerged() as usize;
+    }
+
+    // Sunk once per cycle, then surfaced again.
+    assert_eq!(submerged_frames, 60);
+    assert!(!Log::new(1, Position::new(240, 0), &seeded_rng(0)).submerged());
+}
+
+#[test]
+fn swooping_bird_catches_bunner() {
+    set_headless(true);
+
+    let rng = seeded_rng(0);
+    let mut grass = Grass::without_hedge(0, -ROW_HEIGHT, &rng);
+    grass
+        .children_mut()
+        .push(Child::Bird(Bird::new(0, Position::new(240, 0))));
+
+    // Birds start at the top of their swoop.
+    assert_eq!(grass.check_collision(240), PlayerState::Alive);
+    assert!(grass.allow_movement(240));
+    for _ in 0..45 {
+        grass.update(0, None);
+    }
+    assert_eq!(grass.check_collision(240), PlayerState::Eagle(240));
+}
+
+#[test]
+fn rows_dont_depend_on_pace() {
+    set_headless(true);
+
+    // Updates each row for the given number of frames before generating the next one, like a slower
+    // or faster player would; the rows are compared as generated, before they're updated.
+    let generate_rows = |frames_per_row| {
+        let rng = seeded_rng(1234);
+        let mut row: Box<dyn Row> = Box::new(Grass::without_hedge(0, 0, &rng));
+        let mut rows = vec![];
+        for _ in 0..200 {
+            for _ in 0..frames_per_row {
+                row.update(0, None);
+            }
+            row = row.next(&rng);
+            let children_x = row
+                .children()
+                .iter()
+                .map(|child| child.x())
+                .collect::<Vec<_>>();
+            rows.push((row.y(), row.push(), children_x));
+        }
+        rows
+    };
+
+    assert_eq!(generate_rows(1), generate_rows(300));
+}
+
+#[test]
+fn birds_dont_depend_on_level_generation() {
+    set_headless(true);
+
+    // Birds only cross rows far enough.
+    let y = -320 - ROW_HEIGHT * 1_000;
+
+    // The level generator draws in between the updates, like when other rows are generated; the
+    // birds must be the same regardless.
+    let spawn_birds = |level_draws_per_frame| {
+        let rng = seeded_rng(1234);
+        let mut grass = Grass::without_hedge(0, y, &rng);
+        let mut birds = vec![];
+        for _ in 0..2_000 {
+            for _ in 0..level_draws_per_frame {
+                rng.rand();
+            }
+            grass.update(0, None);
+            birds.extend(grass.children().iter().filter_map(|child| match child {
+                Child::Bird(_) => Some(child.x()),
+                _ => None,
+            }));
+        }
+        birds
+    };
+
+    let birds = spawn_birds(0);
+    assert!(!birds.is_empty());
+    assert_eq!(birds, spawn_birds(3));
+}
+
+#[test]
+fn difficulty_rises_with_distance() {
+    assert_eq!(difficulty(0), 0.);
+    assert_eq!(difficulty(-320 - ROW_HEIGHT * 20), 0.);
+    assert!(difficulty(-320 - ROW_HEIGHT * 50) > 0.);
+    assert_eq!(difficulty(-320 - ROW_HEIGHT * 1_000), 1.);
+}
+
+#[test]
+fn scrolled_off_bunner_is_taken_by_eagle() {
+    set_headless(true);
+
+    let mut rows: Vec<Box<dyn Row>> = vec![];
+    let mut bunner = Bunner::new(Position::new(240, 0));
+
+    bunner.update(-HEIGHT - 81, &mut rows, VecDeque::new());
+
+    assert_eq!(bunner.state, PlayerState::Eagle(240));
+    assert_eq!(bunner.timer, 150);
+}
+
+#[test]
+fn idle_game_ends_with_eagle() {
+    set_headless(true);
+
+    let mut game = Game::new(vec![Bunner::new(Position::new(240, -320))], 0);
+    let mut frames = 0;
+    while !game.game_over() {
+        game.update(vec![]);
+        frames += 1;
+        assert!(frames < 10_000, "the game didn't end");
+    }
+
+    assert!(matches!(game.bunners[0].state, PlayerState::Eagle(_)));
+    assert_eq!(game.score(), 0);
+}
+
+#[test]
+fn game_scores_rows_advanced() {
+    set_headless(true);
+
+    // The first row beyond the start is either a road or a water row, which never block movement;
+    // the score counts the furthest row reached, even if the bunner doesn't survive it.
+    let mut game = Game::new(vec![Bunner::new(Position::new(240, -320))], 0);
+    game.update(vec![moves(&[PlayerDirection::Up])]);
+    for _ in 1..JUMP_FRAMES {
+        game.update(vec![]);
+    }
+
+    assert_eq!(game.bunners[0].position.y, -320 - ROW_HEIGHT);
+    assert_eq!(game.score(), 1);
+}
+
+#[test]
+fn same_seed_and_inputs_replay_identically() {
+    set_headless(true);
+
+    let play = |seed| {
+        let mut game = Game::new(vec![Bunner::new(Position::new(240, -320))], seed);
+        let mut trace = vec![];
+        for frame in 0..3_000 {
+            let input = match frame % 40 {
+                0 => moves(&[PlayerDirection::Up]),
+                20 => moves(&[if frame % 80 == 20 {
+                    PlayerDirection::Left
+                } else {
+                    PlayerDirection::Right
+                }]),
+                _ => VecDeque::new(),
+            };
+            game.update(vec![input]);
+            let bunner = &game.bunners[0];
+            trace.push((bunner.position, bunner.state, game.score()));
+        }
+        trace
+    };
+
+    assert_eq!(play(1234), play(1234));
+}
+
+#[test]
+fn versus_laggard_is_taken_by_eagle_first() {
+    set_headless(true);
+
+    // The screen follows the leader, so the second bunner, further behind, falls off first.
+    let mut game = Game::new(
+        vec![
+            Bunner::new(Position::new(200, -320)),
+            Bunner::new(Position::new(280, -120)),
+        ],
+        0,
+    );
+    while !matches!(game.bunners[1].state, PlayerState::Eagle(_)) {
+        game.update(vec![]);
+    }
+
+    assert_eq!(game.bunners[0].state, PlayerState::Alive);
+    assert!(!game.game_over());
+
+    while !game.game_over() {
+        game.update(vec![]);
+    }
+
+    assert!(matches!(game.bunners[0].state, PlayerState::Eagle(_)));
+    assert_eq!(game.scores(), vec![0, 0]);
+    assert_eq!(game.winner(), None);
+}
+
+#[test]
+fn versus_winner_has_best_score() {
+    set_headless(true);
+
+    let mut game = Game::new(
+        vec![
+            Bunner::new(Position::new(200, -320)),
+            Bunner::new(Position::new(280, -320)),
+        ],
+        0,
+    );
+    game.update(vec![VecDeque::new(), moves(&[PlayerDirection::Up])]);
+    for _ in 1..JUMP_FRAMES {
+        game.update(vec![]);
+    }
+
+    assert_eq!(game.scores(), vec![0, 1]);
+    assert_eq!(game.winner(), Some(1));
+}
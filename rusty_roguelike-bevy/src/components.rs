@@ -54,6 +54,14 @@ pub struct Name(pub String);
 #[derive(Component)]
 pub struct Carried(pub Entity);
 
+// Entities of the levels that the player left; they don't have a PointC, so they're ignored by the
+// systems, until the player goes back to their level.
+#[derive(Component)]
+pub struct ParkedOnLevel {
+    pub level: u32,
+    pub pos: Point,
+}
+
 #[derive(Component)]
 pub struct ActivateItem {
     pub used_by: Entity,
@@ -0,0 +1,81 @@
+use crate::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// The source project discards a level once the player leaves it. In order to allow the player to go
+// back upstairs, the maps of the levels left are stored in this resource.
+//
+// The entities of the levels left (surviving monsters and floor items) are not stored here; since
+// they're ECS entities, it's simpler to keep them in the world, and park them (see `ParkedOnLevel`),
+// so that the systems don't see them.
+//
+#[derive(Default)]
+pub struct Dungeon {
+    levels: HashMap<u32, StoredLevel>,
+}
+
+pub struct StoredLevel {
+    pub map: Map,
+    pub theme: Box<dyn MapTheme>,
+}
+
+impl Dungeon {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn store_level(&mut self, level: u32, map: Map, theme: Box<dyn MapTheme>) {
+        self.levels.insert(level, StoredLevel { map, theme });
+    }
+
+    pub fn take_level(&mut self, level: u32) -> Option<StoredLevel> {
+        self.levels.remove(&level)
+    }
+}
+
+// Entering a level through the stairs would immediately trigger them when the player performs the
+// next action, so the player is placed on the nearest tile that can be entered, and isn't a stairs
+// tile, instead. The search goes outward from the stairs, through the tiles that can be entered;
+// if none is reachable, the nearest one is taken, regardless of the path.
+//
+pub fn stairs_arrival_point(map: &Map, stairs: Point) -> Point {
+    let is_stairs = |pt: Point| {
+        matches!(
+            map.tiles[map.point2d_to_index(pt)],
+            TileType::Exit | TileType::UpStairs
+        )
+    };
+    let is_arrival_point = |pt: Point| map.can_enter_tile(pt) && !is_stairs(pt);
+
+    let mut visited = HashSet::from([stairs]);
+    let mut frontier = VecDeque::from([stairs]);
+
+    while let Some(pt) = frontier.pop_front() {
+        for delta in [
+            Point::new(-1, 0),
+            Point::new(1, 0),
+            Point::new(0, -1),
+            Point::new(0, 1),
+        ] {
+            let next = pt + delta;
+
+            if !map.can_enter_tile(next) || !visited.insert(next) {
+                continue;
+            }
+            if is_arrival_point(next) {
+                return next;
+            }
+
+            frontier.push_back(next);
+        }
+    }
+
+    (0..map.tiles.len())
+        .map(|idx| map.index_to_point2d(idx))
+        .filter(|pt| is_arrival_point(*pt))
+        .min_by(|a, b| {
+            let distance_a = DistanceAlg::Pythagoras.distance2d(stairs, *a);
+            let distance_b = DistanceAlg::Pythagoras.distance2d(stairs, *b);
+            distance_a.partial_cmp(&distance_b).unwrap()
+        })
+        .expect("The map has no tiles the player can enter")
+}
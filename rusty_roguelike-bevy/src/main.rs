@@ -3,13 +3,13 @@
 
 mod camera;
 mod components;
+mod dungeon;
 mod events;
 mod game_stage;
-mod map;
-mod map_builder;
 mod spawner;
 mod state_label;
 mod systems;
+mod themes;
 mod turn_state;
 
 mod prelude {
@@ -17,19 +17,18 @@ mod prelude {
     // Keep a space, in order to prevent IDEs to reorder imports, which causes clashing.
     pub use bevy::prelude::*;
     pub use iyes_loopless::prelude::*;
-    pub const SCREEN_WIDTH: i32 = 80;
-    pub const SCREEN_HEIGHT: i32 = 50;
+    pub use rusty_roguelike_common::prelude::*;
     pub const DISPLAY_WIDTH: i32 = SCREEN_WIDTH / 2;
     pub const DISPLAY_HEIGHT: i32 = SCREEN_HEIGHT / 2;
     pub use crate::camera::*;
     pub use crate::components::*;
+    pub use crate::dungeon::*;
     pub use crate::events::*;
     pub use crate::game_stage::*;
-    pub use crate::map::*;
-    pub use crate::map_builder::*;
     pub use crate::spawner::*;
     pub use crate::state_label::*;
     pub use crate::systems::*;
+    pub use crate::themes::*;
     pub use crate::turn_state::*;
 }
 
@@ -45,7 +44,7 @@ impl State {
 
         let mut ecs = App::new();
         let mut rng = RandomNumberGenerator::new();
-        let mut map_builder = MapBuilder::new(&mut rng);
+        let mut map_builder = MapBuilder::with_interactive_tiles(&mut rng);
         // This is not a strict-ECS approach (a system would), but we mimick the source project design.
         spawn_player(&mut ecs.world, map_builder.player_start);
         //spawn_amulet_of_yala(&mut ecs, map_builder.amulet_start);
@@ -68,7 +67,8 @@ impl State {
             .add_stage_after(MoveMonsters, MonsterFov, SystemStage::parallel());
         // Set the startup state.
         ecs.insert_resource(TurnState::AwaitingInput);
-        ecs.insert_resource(map_builder.theme);
+        ecs.insert_resource(theme_for(map_builder.theme));
+        ecs.insert_resource(Dungeon::new());
         // In the source project, set of actions (`Schedule`s) are owned by State (`systems: Schedule`);
         // here, they're owned by the Bevy ECS, as `SystemSet`s.
         build_system_sets(&mut ecs);
@@ -85,7 +85,7 @@ impl State {
         // Note that we can also just replace the current app with a new one.
         self.ecs.world.clear_entities();
         let mut rng = RandomNumberGenerator::new();
-        let mut map_builder = MapBuilder::new(&mut rng);
+        let mut map_builder = MapBuilder::with_interactive_tiles(&mut rng);
         spawn_player(&mut self.ecs.world, map_builder.player_start);
         let exit_idx = map_builder.map.point2d_to_index(map_builder.amulet_start);
         map_builder.map.tiles[exit_idx] = TileType::Exit;
@@ -99,71 +99,112 @@ impl State {
         self.ecs
             .insert_resource(Camera::new(map_builder.player_start));
         self.ecs.insert_resource(TurnState::AwaitingInput);
-        self.ecs.insert_resource(map_builder.theme);
+        self.ecs.insert_resource(theme_for(map_builder.theme));
+        self.ecs.insert_resource(Dungeon::new());
         // Don't forget! :)
         self.ecs.world.remove_resource::<VirtualKeyCode>();
     }
 
-    fn advance_level(&mut self) {
-        let mut player_query = self.ecs.world.query_filtered::<Entity, With<Player>>();
-        let player_entity = player_query.iter(&self.ecs.world).next().unwrap();
+    // The source project generates a new level every time the player advances; here, the levels are
+    // stored in the `Dungeon` resource, so that the player can go back to them.
+    fn change_level(&mut self, going_down: bool) {
+        let mut player_query = self.ecs.world.query::<&Player>();
+        let current_level = player_query.iter(&self.ecs.world).next().unwrap().map_level;
+        let new_level = if going_down {
+            current_level + 1
+        } else {
+            current_level - 1
+        };
 
-        use std::collections::HashSet;
-        let mut entities_to_keep = HashSet::new();
-        entities_to_keep.insert(player_entity);
-        let mut carry_query = self.ecs.world.query::<(Entity, &Carried)>();
-        for (e, carry) in carry_query.iter(&self.ecs.world) {
-            if carry.0 == player_entity {
-                entities_to_keep.insert(e);
-            }
-        }
-        let mut entities_query = self.ecs.world.query::<Entity>();
-        // In Bevy, we can't query the world and write to it at the same time, so we need an intermediate
-        // collection.
-        // We're using a convenient Rust construct: filter_map() + bool#then, in order to compactly
-        // filter an iterator.
-        let entities_to_remove = entities_query
+        // Carried items don't have a position, so they're naturally excluded.
+        let mut entities_query = self
+            .ecs
+            .world
+            .query_filtered::<(Entity, &PointC), Without<Player>>();
+        let entities_to_park = entities_query
             .iter(&self.ecs.world)
-            .filter_map(|e| (!entities_to_keep.contains(&e)).then(|| e))
+            .map(|(e, pos)| (e, pos.0))
             .collect::<Vec<_>>();
-        // We don't need flushing; when manipulating World directly in Bevy, flushes are implicit.
-        for e in entities_to_remove {
-            self.ecs.world.despawn(e);
+        for (e, pos) in entities_to_park {
+            let mut entity = self.ecs.world.entity_mut(e);
+            entity.remove::<PointC>();
+            entity.insert(ParkedOnLevel {
+                level: current_level,
+                pos,
+            });
         }
 
-        let mut fov_query = self.ecs.world.query::<&mut FieldOfView>();
-        for mut fov in fov_query.iter_mut(&mut self.ecs.world) {
-            fov.is_dirty = true;
-        }
+        let map = self.ecs.world.remove_resource::<Map>().unwrap();
+        let theme = self
+            .ecs
+            .world
+            .remove_resource::<Box<dyn MapTheme>>()
+            .unwrap();
+        let stored_level = {
+            let mut dungeon = self.ecs.world.get_resource_mut::<Dungeon>().unwrap();
+            dungeon.store_level(current_level, map, theme);
+            dungeon.take_level(new_level)
+        };
+
+        let (map, theme, player_pos) = if let Some(StoredLevel { map, theme }) = stored_level {
+            let mut parked_query = self.ecs.world.query::<(Entity, &ParkedOnLevel)>();
+            let entities_to_restore = parked_query
+                .iter(&self.ecs.world)
+                .filter_map(|(e, parked)| (parked.level == new_level).then(|| (e, parked.pos)))
+                .collect::<Vec<_>>();
+            for (e, pos) in entities_to_restore {
+                let mut entity = self.ecs.world.entity_mut(e);
+                entity.remove::<ParkedOnLevel>();
+                entity.insert(PointC(pos));
+            }
+
+            let stairs = if going_down {
+                TileType::UpStairs
+            } else {
+                TileType::Exit
+            };
+            let stairs_idx = map.tiles.iter().position(|t| *t == stairs).unwrap();
+            let player_pos = stairs_arrival_point(&map, map.index_to_point2d(stairs_idx));
+
+            (map, theme, player_pos)
+        } else {
+            let mut rng = RandomNumberGenerator::new();
+            let mut map_builder = MapBuilder::with_interactive_tiles(&mut rng);
+            if new_level == 2 {
+                spawn_amulet_of_yala(&mut self.ecs.world, map_builder.amulet_start);
+            } else {
+                let exit_idx = map_builder.map.point2d_to_index(map_builder.amulet_start);
+                map_builder.map.tiles[exit_idx] = TileType::Exit;
+            }
+            let up_stairs_idx = map_builder.map.point2d_to_index(map_builder.player_start);
+            map_builder.map.tiles[up_stairs_idx] = TileType::UpStairs;
+            spawn_level(
+                &mut self.ecs.world,
+                &mut rng,
+                0,
+                &map_builder.monster_spawns,
+            );
+            let player_pos = stairs_arrival_point(&map_builder.map, map_builder.player_start);
+
+            (map_builder.map, theme_for(map_builder.theme), player_pos)
+        };
 
-        let mut rng = RandomNumberGenerator::new();
-        let mut map_builder = MapBuilder::new(&mut rng);
-        let mut map_level = 0;
         let mut player_query = self.ecs.world.query::<(&mut Player, &mut PointC)>();
         for (mut player, mut pos) in player_query.iter_mut(&mut self.ecs.world) {
-            player.map_level += 1;
-            map_level = player.map_level;
-            pos.0.x = map_builder.player_start.x;
-            pos.0.y = map_builder.player_start.y;
+            player.map_level = new_level;
+            pos.0 = player_pos;
         }
-        if map_level == 2 {
-            spawn_amulet_of_yala(&mut self.ecs.world, map_builder.amulet_start);
-        } else {
-            let exit_idx = map_builder.map.point2d_to_index(map_builder.amulet_start);
-            map_builder.map.tiles[exit_idx] = TileType::Exit;
+
+        // The restored monsters need to recompute their field of view as well.
+        let mut fov_query = self.ecs.world.query::<&mut FieldOfView>();
+        for mut fov in fov_query.iter_mut(&mut self.ecs.world) {
+            fov.is_dirty = true;
         }
-        spawn_level(
-            &mut self.ecs.world,
-            &mut rng,
-            0,
-            &map_builder.monster_spawns,
-        );
-        self.ecs.world.insert_resource(map_builder.map);
-        self.ecs
-            .world
-            .insert_resource(Camera::new(map_builder.player_start));
+
+        self.ecs.world.insert_resource(map);
+        self.ecs.world.insert_resource(Camera::new(player_pos));
         self.ecs.insert_resource(TurnState::AwaitingInput);
-        self.ecs.world.insert_resource(map_builder.theme);
+        self.ecs.world.insert_resource(theme);
     }
 
     fn game_over(&mut self, ctx: &mut BTerm) {
@@ -247,7 +288,8 @@ impl GameState for State {
         match self.ecs.world.get_resource::<TurnState>() {
             Some(TurnState::GameOver) => self.game_over(ctx),
             Some(TurnState::Victory) => self.victory(ctx),
-            Some(TurnState::NextLevel) => self.advance_level(),
+            Some(TurnState::NextLevel) => self.change_level(true),
+            Some(TurnState::PreviousLevel) => self.change_level(false),
             _ => {}
         }
         self.ecs.update();
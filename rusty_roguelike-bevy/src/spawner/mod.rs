@@ -1,6 +1,6 @@
 use crate::prelude::*;
 mod template;
-use template::Templates;
+use template::*;
 
 pub fn spawn_player(ecs: &mut World, pos: Point) {
     ecs.spawn().insert_bundle((
@@ -25,8 +25,8 @@ pub fn spawn_level(
     level: usize,
     spawn_points: &[Point],
 ) {
-    let template = Templates::load();
-    template.spawn_entities(ecs, rng, level, spawn_points);
+    let templates = load_templates();
+    spawn_entities(&templates, ecs, rng, level, spawn_points);
 }
 
 pub fn spawn_amulet_of_yala(world: &mut World, pos: Point) {
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+
+// Each turn, the player has a chance of spotting every hidden tile adjacent to them.
+const DETECTION_CHANCE: i32 = 3;
+
+pub fn detect_hidden(player_query: Query<&PointC, With<Player>>, mut map: ResMut<Map>) {
+    let player_pos = player_query.single().0;
+    let mut rng = RandomNumberGenerator::new();
+
+    for y in -1..=1 {
+        for x in -1..=1 {
+            if let Some(idx) = map.try_idx(player_pos + Point::new(x, y)) {
+                // Detection doesn't change the tiles opacity, so there is no need to invalidate the
+                // fields of view.
+                let detected_tile = match map.tiles[idx] {
+                    TileType::HiddenTrap => TileType::Trap,
+                    TileType::SecretWall => TileType::DoorClosed,
+                    _ => continue,
+                };
+
+                if rng.range(0, DETECTION_CHANCE) == 0 {
+                    map.tiles[idx] = detected_tile;
+                }
+            }
+        }
+    }
+}
@@ -30,6 +30,9 @@ pub fn end_turn(
     if map.tiles[idx] == TileType::Exit {
         new_state = TurnState::NextLevel;
     }
+    if map.tiles[idx] == TileType::UpStairs {
+        new_state = TurnState::PreviousLevel;
+    }
 
     commands.insert_resource(new_state);
 }
@@ -2,6 +2,7 @@ use crate::prelude::*;
 
 mod chasing;
 mod combat;
+mod detect_hidden;
 mod end_turn;
 mod entity_render;
 mod fov;
@@ -96,6 +97,7 @@ pub fn build_system_sets(app: &mut App) {
         ConditionSet::new()
             .run_if_resource_equals(PlayerTurn)
             .with_system(fov::fov)
+            .with_system(detect_hidden::detect_hidden)
             .into(),
     );
 
@@ -1,9 +1,12 @@
 use crate::prelude::*;
 
+const TRAP_DAMAGE: i32 = 2;
+
 pub fn movement(
     mut commands: Commands,
     mut move_events: EventReader<WantsToMove>,
     query: Query<(Entity, &FieldOfView, Option<&Player>)>,
+    mut health_query: Query<(&mut Health, Option<&Player>)>,
     (mut map, mut camera): (ResMut<Map>, ResMut<Camera>),
 ) {
     for &WantsToMove {
@@ -11,6 +14,26 @@ pub fn movement(
         destination,
     } in move_events.iter()
     {
+        let is_player = matches!(query.get(entity), Ok((_, _, Some(_))));
+
+        // Interactive tiles are handled before the movement. Opening a door or destroying a wall
+        // consumes the move; since they change the map visibility, all the fields of view are invalidated.
+        if let Some(idx) = map.try_idx(destination) {
+            let changed_tile = match map.tiles[idx] {
+                TileType::DoorClosed => Some(TileType::DoorOpen),
+                TileType::CrackedWall if is_player => Some(TileType::Floor),
+                _ => None,
+            };
+
+            if let Some(changed_tile) = changed_tile {
+                map.tiles[idx] = changed_tile;
+                for (entity, fov, _) in query.iter() {
+                    commands.entity(entity).insert(fov.clone_dirty());
+                }
+                continue;
+            }
+        }
+
         if map.can_enter_tile(destination) {
             commands.entity(entity).insert(PointC(destination));
 
@@ -26,6 +49,19 @@ pub fn movement(
                     });
                 }
             }
+
+            let idx = map_idx(destination.x, destination.y);
+            if matches!(map.tiles[idx], TileType::HiddenTrap | TileType::Trap) {
+                map.tiles[idx] = TileType::Trap;
+
+                if let Ok((mut health, player)) = health_query.get_mut(entity) {
+                    health.current -= TRAP_DAMAGE;
+                    // The player death is handled by the end turn system.
+                    if health.current < 1 && player.is_none() {
+                        commands.entity(entity).despawn();
+                    }
+                }
+            }
         }
     }
 }
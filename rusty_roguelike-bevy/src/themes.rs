@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+pub trait MapTheme: Sync + Send {
+    fn tile_to_render(&self, tile_type: TileType) -> FontCharType;
+}
+
+pub fn theme_for(kind: ThemeKind) -> Box<dyn MapTheme> {
+    match kind {
+        ThemeKind::Dungeon => DungeonTheme::new(),
+        ThemeKind::Forest => ForestTheme::new(),
+    }
+}
+
+pub struct DungeonTheme {}
+
+impl DungeonTheme {
+    pub fn new() -> Box<dyn MapTheme> {
+        Box::new(Self {})
+    }
+}
+
+impl MapTheme for DungeonTheme {
+    fn tile_to_render(&self, tile_type: TileType) -> FontCharType {
+        match tile_type {
+            TileType::Floor | TileType::HiddenTrap => to_cp437('.'),
+            TileType::Wall | TileType::SecretWall => to_cp437('#'),
+            TileType::Exit => to_cp437('>'),
+            TileType::UpStairs => to_cp437('<'),
+            TileType::DoorClosed => to_cp437('+'),
+            TileType::DoorOpen => to_cp437('\''),
+            TileType::Trap => to_cp437('^'),
+            TileType::CrackedWall => to_cp437('%'),
+        }
+    }
+}
+
+pub struct ForestTheme {}
+
+impl MapTheme for ForestTheme {
+    fn tile_to_render(&self, tile_type: TileType) -> FontCharType {
+        match tile_type {
+            TileType::Floor | TileType::HiddenTrap => to_cp437(';'),
+            TileType::Wall | TileType::SecretWall => to_cp437('"'),
+            TileType::Exit => to_cp437('>'),
+            TileType::UpStairs => to_cp437('<'),
+            TileType::DoorClosed => to_cp437('+'),
+            TileType::DoorOpen => to_cp437('\''),
+            TileType::Trap => to_cp437('^'),
+            TileType::CrackedWall => to_cp437('%'),
+        }
+    }
+}
+
+impl ForestTheme {
+    pub fn new() -> Box<dyn MapTheme> {
+        Box::new(Self {})
+    }
+}
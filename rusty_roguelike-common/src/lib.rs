@@ -0,0 +1,19 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+// Engine-agnostic part of the Rusty Roguelike ports: map, dungeon generation and entity templates.
+//
+// Everything that depends on the backend (rendering of the map themes, spawning of the ECS entities)
+// is supplied by each port.
+
+mod map;
+mod map_builder;
+mod template;
+
+pub mod prelude {
+    pub const SCREEN_WIDTH: i32 = 80;
+    pub const SCREEN_HEIGHT: i32 = 50;
+    pub use crate::map::*;
+    pub use crate::map_builder::*;
+    pub use crate::template::*;
+}
@@ -1,18 +1,34 @@
 use crate::prelude::*;
+use bracket_pathfinding::prelude::*;
+#[cfg(feature = "nanoserde")]
+use nanoserde::{DeRon, SerRon};
 
 const NUM_TILES: usize = (SCREEN_WIDTH * SCREEN_HEIGHT) as usize;
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "nanoserde", derive(SerRon, DeRon))]
 pub enum TileType {
     Wall,
     Floor,
     Exit,
+    UpStairs,
+    DoorClosed,
+    DoorOpen,
+    // Hidden traps look like floor, until they're detected or triggered.
+    HiddenTrap,
+    Trap,
+    // Secret walls look like walls, until they're detected; once found, they turn into a door.
+    SecretWall,
+    // Cracked walls are destroyed when the player walks into them.
+    CrackedWall,
 }
 
 pub fn map_idx(x: i32, y: i32) -> usize {
     ((y * SCREEN_WIDTH) + x) as usize
 }
 
+#[derive(Clone)]
+#[cfg_attr(feature = "nanoserde", derive(SerRon, DeRon))]
 pub struct Map {
     pub tiles: Vec<TileType>,
     pub revealed_tiles: Vec<bool>,
@@ -38,10 +54,20 @@ impl Map {
         }
     }
 
+    // Closed doors are considered enterable, since walking into them opens them; this also allows the
+    // pathfinding to go through them.
     pub fn can_enter_tile(&self, point: Point) -> bool {
         self.in_bounds(point)
-            && (self.tiles[map_idx(point.x, point.y)] == TileType::Floor
-                || self.tiles[map_idx(point.x, point.y)] == TileType::Exit)
+            && matches!(
+                self.tiles[map_idx(point.x, point.y)],
+                TileType::Floor
+                    | TileType::Exit
+                    | TileType::UpStairs
+                    | TileType::DoorClosed
+                    | TileType::DoorOpen
+                    | TileType::HiddenTrap
+                    | TileType::Trap
+            )
     }
 
     fn valid_exit(&self, loc: Point, delta: Point) -> Option<usize> {
@@ -59,6 +85,12 @@ impl Map {
     }
 }
 
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Algorithm2D for Map {
     fn dimensions(&self) -> Point {
         Point::new(SCREEN_WIDTH, SCREEN_HEIGHT)
@@ -71,7 +103,10 @@ impl Algorithm2D for Map {
 
 impl BaseMap for Map {
     fn is_opaque(&self, idx: usize) -> bool {
-        self.tiles[idx as usize] != TileType::Floor
+        !matches!(
+            self.tiles[idx],
+            TileType::Floor | TileType::DoorOpen | TileType::HiddenTrap | TileType::Trap
+        )
     }
 
     fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
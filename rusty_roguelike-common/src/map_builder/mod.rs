@@ -0,0 +1,393 @@
+mod automata;
+mod drunkard;
+mod prefab;
+mod rooms;
+
+use crate::prelude::*;
+use automata::CellularAutomataArchitect;
+use bracket_pathfinding::prelude::*;
+use bracket_random::prelude::RandomNumberGenerator;
+use drunkard::DrunkardsWalkArchitect;
+use prefab::apply_prefab;
+use rooms::RoomsArchitect;
+
+trait MapArchitect {
+    fn build(&mut self, rng: &mut RandomNumberGenerator) -> MapBuilder;
+}
+
+// The tiles are rendered differently by each port, so the builder only chooses the theme; each port
+// maps it to its own renderer.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ThemeKind {
+    Dungeon,
+    Forest,
+}
+
+const NUM_ROOMS: usize = 20;
+const NUM_TRAPS: usize = 10;
+const NUM_CRACKED_WALLS: usize = 6;
+const NUM_SECRET_WALLS: usize = 4;
+
+pub struct MapBuilder {
+    pub map: Map,
+    pub rooms: Vec<Rect>,
+    pub monster_spawns: Vec<Point>,
+    pub player_start: Point,
+    pub amulet_start: Point,
+    pub theme: ThemeKind,
+}
+
+impl MapBuilder {
+    pub fn new(rng: &mut RandomNumberGenerator) -> Self {
+        Self::build(rng, false)
+    }
+
+    // Doors, traps and special walls need support from the port's movement systems, so they're opt-in.
+    pub fn with_interactive_tiles(rng: &mut RandomNumberGenerator) -> Self {
+        Self::build(rng, true)
+    }
+
+    fn build(rng: &mut RandomNumberGenerator, interactive_tiles: bool) -> Self {
+        let mut architect: Box<dyn MapArchitect> = match rng.range(0, 3) {
+            0 => Box::new(DrunkardsWalkArchitect {}),
+            1 => Box::new(RoomsArchitect {}),
+            _ => Box::new(CellularAutomataArchitect {}),
+        };
+        let mut mb = architect.build(rng);
+        apply_prefab(&mut mb, rng);
+
+        if interactive_tiles {
+            mb.place_doors(rng);
+            mb.place_special_walls(rng);
+            mb.place_traps(rng);
+        }
+
+        mb.theme = match rng.range(0, 2) {
+            0 => ThemeKind::Dungeon,
+            _ => ThemeKind::Forest,
+        };
+
+        mb
+    }
+
+    fn fill(&mut self, tile: TileType) {
+        self.map.tiles.iter_mut().for_each(|t| *t = tile);
+    }
+
+    fn find_most_distant(&self) -> Point {
+        let dijkstra_map = DijkstraMap::new(
+            SCREEN_WIDTH,
+            SCREEN_HEIGHT,
+            &[self.map.point2d_to_index(self.player_start)],
+            &self.map,
+            1024.0,
+        );
+
+        const UNREACHABLE: &f32 = &f32::MAX;
+        self.map.index_to_point2d(
+            dijkstra_map
+                .map
+                .iter()
+                .enumerate()
+                .filter(|(_, dist)| *dist < UNREACHABLE)
+                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+                .unwrap()
+                .0,
+        )
+    }
+
+    fn add_boundaries(&mut self) {
+        for x in 1..SCREEN_WIDTH {
+            self.map.tiles[map_idx(x, 1)] = TileType::Wall;
+            self.map.tiles[map_idx(x, SCREEN_HEIGHT - 1)] = TileType::Wall;
+        }
+        for y in 1..SCREEN_HEIGHT {
+            self.map.tiles[map_idx(1, y)] = TileType::Wall;
+            self.map.tiles[map_idx(SCREEN_WIDTH - 1, y)] = TileType::Wall;
+        }
+    }
+
+    fn build_random_rooms(&mut self, rng: &mut RandomNumberGenerator) {
+        while self.rooms.len() < NUM_ROOMS {
+            let room = Rect::with_size(
+                rng.range(1, SCREEN_WIDTH - 10),
+                rng.range(1, SCREEN_HEIGHT - 10),
+                rng.range(2, 10),
+                rng.range(2, 10),
+            );
+            let mut overlap = false;
+            for r in &self.rooms {
+                if r.intersect(&room) {
+                    overlap = true;
+                }
+            }
+            if !overlap {
+                room.for_each(|p| {
+                    if p.x > 0 && p.x < SCREEN_WIDTH && p.y > 0 && p.y < SCREEN_HEIGHT {
+                        let idx = map_idx(p.x, p.y);
+                        self.map.tiles[idx] = TileType::Floor;
+                    }
+                });
+
+                self.rooms.push(room)
+            }
+        }
+    }
+
+    fn apply_horizontal_tunnel(&mut self, x1: i32, x2: i32, y: i32) {
+        use std::cmp::{max, min};
+        for x in min(x1, x2)..=max(x1, x2) {
+            if let Some(idx) = self.map.try_idx(Point::new(x, y)) {
+                self.map.tiles[idx] = TileType::Floor;
+            }
+        }
+    }
+
+    fn apply_vertical_tunnel(&mut self, y1: i32, y2: i32, x: i32) {
+        use std::cmp::{max, min};
+        for y in min(y1, y2)..=max(y1, y2) {
+            if let Some(idx) = self.map.try_idx(Point::new(x, y)) {
+                self.map.tiles[idx] = TileType::Floor;
+            }
+        }
+    }
+
+    fn build_corridors(&mut self, rng: &mut RandomNumberGenerator) {
+        let mut rooms = self.rooms.clone();
+        rooms.sort_by(|a, b| a.center().x.cmp(&b.center().x));
+
+        for (i, room) in rooms.iter().enumerate().skip(1) {
+            let prev = rooms[i - 1].center();
+            let new = room.center();
+
+            if rng.range(0, 2) == 1 {
+                self.apply_horizontal_tunnel(prev.x, new.x, prev.y);
+                self.apply_vertical_tunnel(prev.y, new.y, new.x);
+            } else {
+                self.apply_vertical_tunnel(prev.y, new.y, prev.x);
+                self.apply_horizontal_tunnel(prev.x, new.x, new.y);
+            }
+        }
+    }
+
+    // Doors are placed on the corridor tiles that enter the rooms, so only the rooms architect generates
+    // them; the other architects don't have rooms.
+    fn place_doors(&mut self, rng: &mut RandomNumberGenerator) {
+        for room in self.rooms.clone() {
+            for x in room.x1 - 1..=room.x2 {
+                self.try_place_door(Point::new(x, room.y1 - 1), Point::new(1, 0), rng);
+                self.try_place_door(Point::new(x, room.y2), Point::new(1, 0), rng);
+            }
+            for y in room.y1..room.y2 {
+                self.try_place_door(Point::new(room.x1 - 1, y), Point::new(0, 1), rng);
+                self.try_place_door(Point::new(room.x2, y), Point::new(0, 1), rng);
+            }
+        }
+    }
+
+    // A tile is a doorway if it's a floor tile surrounded by walls along the given axis.
+    fn try_place_door(&mut self, pt: Point, axis: Point, rng: &mut RandomNumberGenerator) {
+        if pt == self.player_start || pt == self.amulet_start || self.monster_spawns.contains(&pt) {
+            return;
+        }
+
+        let is_wall = |pt: Point| {
+            self.map
+                .try_idx(pt)
+                .map_or(false, |idx| self.map.tiles[idx] == TileType::Wall)
+        };
+
+        if let Some(idx) = self.map.try_idx(pt) {
+            if self.map.tiles[idx] == TileType::Floor
+                && is_wall(pt - axis)
+                && is_wall(pt + axis)
+                && rng.range(0, 2) == 0
+            {
+                self.map.tiles[idx] = TileType::DoorClosed;
+            }
+        }
+    }
+
+    // Cracked and secret walls are placed only on walls that separate two floor tiles, so that they
+    // work as shortcuts, and never make a part of the map unreachable.
+    fn place_special_walls(&mut self, rng: &mut RandomNumberGenerator) {
+        let is_floor = |pt: Point| {
+            self.map
+                .try_idx(pt)
+                .map_or(false, |idx| self.map.tiles[idx] == TileType::Floor)
+        };
+
+        let mut candidates: Vec<usize> = self
+            .map
+            .tiles
+            .iter()
+            .enumerate()
+            .filter(|(idx, t)| {
+                let pt = self.map.index_to_point2d(*idx);
+                **t == TileType::Wall
+                    && pt.x > 0
+                    && pt.x < SCREEN_WIDTH - 1
+                    && pt.y > 0
+                    && pt.y < SCREEN_HEIGHT - 1
+                    && ((is_floor(pt + Point::new(-1, 0)) && is_floor(pt + Point::new(1, 0)))
+                        || (is_floor(pt + Point::new(0, -1)) && is_floor(pt + Point::new(0, 1))))
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+
+        for i in 0..(NUM_CRACKED_WALLS + NUM_SECRET_WALLS) {
+            if let Some(target_index) = rng.random_slice_index(&candidates) {
+                let idx = candidates.remove(target_index);
+                self.map.tiles[idx] = if i < NUM_CRACKED_WALLS {
+                    TileType::CrackedWall
+                } else {
+                    TileType::SecretWall
+                };
+            }
+        }
+    }
+
+    fn place_traps(&mut self, rng: &mut RandomNumberGenerator) {
+        let mut trappable_tiles: Vec<Point> = self
+            .map
+            .tiles
+            .iter()
+            .enumerate()
+            .filter(|(idx, t)| {
+                let pt = self.map.index_to_point2d(*idx);
+                **t == TileType::Floor
+                    && pt != self.amulet_start
+                    && !self.monster_spawns.contains(&pt)
+                    && DistanceAlg::Pythagoras.distance2d(self.player_start, pt) > 10.0
+            })
+            .map(|(idx, _)| self.map.index_to_point2d(idx))
+            .collect();
+
+        for _ in 0..NUM_TRAPS {
+            if let Some(target_index) = rng.random_slice_index(&trappable_tiles) {
+                let idx = self.map.point2d_to_index(trappable_tiles[target_index]);
+                self.map.tiles[idx] = TileType::HiddenTrap;
+                trappable_tiles.remove(target_index);
+            }
+        }
+    }
+
+    fn spawn_monsters(&self, start: &Point, rng: &mut RandomNumberGenerator) -> Vec<Point> {
+        const NUM_MONSTERS: usize = 50;
+        let mut spawnable_tiles: Vec<Point> = self
+            .map
+            .tiles
+            .iter()
+            .enumerate()
+            .filter(|(idx, t)| {
+                **t == TileType::Floor
+                    && DistanceAlg::Pythagoras.distance2d(*start, self.map.index_to_point2d(*idx))
+                        > 10.0
+            })
+            .map(|(idx, _)| self.map.index_to_point2d(idx))
+            .collect();
+
+        let mut spawns = Vec::new();
+        for _ in 0..NUM_MONSTERS {
+            let target_index = rng.random_slice_index(&spawnable_tiles).unwrap();
+            spawns.push(spawnable_tiles[target_index]);
+            spawnable_tiles.remove(target_index);
+        }
+        spawns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEEDS: [u64; 8] = [0, 1, 2, 3, 42, 1000, 31337, 123456789];
+
+    // The player and the amulet must be on floor tiles, and the amulet reachable from the player;
+    // the monsters must be on floor tiles, away from the player.
+    fn validate(mb: &MapBuilder) {
+        let is_floor = |pt: Point| mb.map.tiles[mb.map.point2d_to_index(pt)] == TileType::Floor;
+
+        assert!(is_floor(mb.player_start));
+        assert!(is_floor(mb.amulet_start));
+        assert_ne!(mb.player_start, mb.amulet_start);
+
+        let dijkstra_map = DijkstraMap::new(
+            SCREEN_WIDTH,
+            SCREEN_HEIGHT,
+            &[mb.map.point2d_to_index(mb.player_start)],
+            &mb.map,
+            1024.0,
+        );
+        assert!(dijkstra_map.map[mb.map.point2d_to_index(mb.amulet_start)] < f32::MAX);
+
+        assert!(!mb.monster_spawns.is_empty());
+        for spawn in &mb.monster_spawns {
+            assert!(is_floor(*spawn));
+            assert_ne!(*spawn, mb.player_start);
+        }
+    }
+
+    fn build_and_validate(mut architect: impl MapArchitect) {
+        for seed in SEEDS {
+            let mut rng = RandomNumberGenerator::seeded(seed);
+            validate(&architect.build(&mut rng));
+        }
+    }
+
+    #[test]
+    fn rooms_architect() {
+        build_and_validate(RoomsArchitect {});
+    }
+
+    #[test]
+    fn drunkards_walk_architect() {
+        build_and_validate(DrunkardsWalkArchitect {});
+    }
+
+    #[test]
+    fn cellular_automata_architect() {
+        build_and_validate(CellularAutomataArchitect {});
+    }
+
+    // The special walls must separate two enterable tiles (floor, possibly trapped afterwards), and
+    // the doors and traps must not be placed on the player, the amulet or the monsters.
+    //
+    // The architect is chosen randomly, so more seeds are used, in order to include maps with doors
+    // (which only the rooms architect generates).
+    #[test]
+    fn interactive_tiles() {
+        let mut doors = 0;
+
+        for seed in 0..32 {
+            let mut rng = RandomNumberGenerator::seeded(seed);
+            let mb = MapBuilder::with_interactive_tiles(&mut rng);
+
+            validate(&mb);
+
+            for (idx, tile) in mb.map.tiles.iter().enumerate() {
+                let pt = mb.map.index_to_point2d(idx);
+
+                if *tile == TileType::DoorClosed {
+                    doors += 1;
+                }
+
+                if matches!(tile, TileType::DoorClosed | TileType::HiddenTrap) {
+                    assert_ne!(pt, mb.player_start);
+                    assert_ne!(pt, mb.amulet_start);
+                    assert!(!mb.monster_spawns.contains(&pt));
+                }
+
+                if matches!(tile, TileType::CrackedWall | TileType::SecretWall) {
+                    let enterable = |delta: Point| mb.map.can_enter_tile(pt + delta);
+                    assert!(
+                        (enterable(Point::new(-1, 0)) && enterable(Point::new(1, 0)))
+                            || (enterable(Point::new(0, -1)) && enterable(Point::new(0, 1)))
+                    );
+                }
+            }
+        }
+
+        assert!(doors > 0);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::prelude::*;
+use bracket_pathfinding::prelude::*;
+use bracket_random::prelude::RandomNumberGenerator;
 
 const FORTRESS: (&str, i32, i32) = (
     "
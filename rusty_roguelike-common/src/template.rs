@@ -0,0 +1,61 @@
+use bracket_pathfinding::prelude::Point;
+use bracket_random::prelude::RandomNumberGenerator;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+// The glyph is the cp437 character; the Bevy port renders it via bracket-lib, while the Macroquad
+// port uses it as sprite index, since its tileset follows the same layout.
+#[derive(Clone, Deserialize, Debug)]
+pub struct Template {
+    pub entity_type: EntityType,
+    pub levels: HashSet<usize>,
+    pub frequency: i32,
+    pub name: String,
+    pub glyph: char,
+    pub provides: Option<Vec<(String, i32)>>,
+    pub hp: Option<i32>,
+    pub base_damage: Option<i32>,
+}
+
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+pub enum EntityType {
+    Enemy,
+    Item,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct Templates {
+    pub entities: Vec<Template>,
+}
+
+impl Templates {
+    // Loading the file is up to the ports, since on the web it needs to be performed asynchronously.
+    pub fn from_ron(data: &str) -> Result<Self, ron::Error> {
+        ron::from_str(data)
+    }
+
+    // Chooses the template for each spawn point, weighted by frequency; the ports create the entities.
+    pub fn choose_spawns<'a>(
+        &'a self,
+        rng: &mut RandomNumberGenerator,
+        level: usize,
+        spawn_points: &[Point],
+    ) -> Vec<(Point, &'a Template)> {
+        let mut available_entities = Vec::new();
+        for t in self.entities.iter() {
+            if t.levels.contains(&level) {
+                for _ in 0..t.frequency {
+                    available_entities.push(t);
+                }
+            }
+        }
+
+        spawn_points
+            .iter()
+            .filter_map(|pt| {
+                rng.random_slice_entry(&available_entities)
+                    .map(|template| (*pt, *template))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,211 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+
+// Render-only state; the game logic (and TurnState) is unaffected, since the systems only notify
+// this resource of what happened, and the renderers use it in order to smooth the presentation.
+//
+// The idle, attack and hurt animations play the frames of the tileset (see
+// TileSet::animation_frames()); the attacks also lunge towards the victim.
+
+const MOVE_DURATION: f64 = 0.12;
+const ACTION_DURATION: f64 = 0.25;
+const FLOATING_TEXT_DURATION: f64 = 0.8;
+
+const IDLE_FRAME_DURATION: f64 = 0.5;
+const ATTACK_LUNGE: f32 = 0.3;
+const FLOATING_TEXT_RISE: f32 = 0.8;
+
+#[derive(Clone, Copy)]
+enum Action {
+    Attack { direction: Vec2 },
+    Hurt,
+}
+
+struct EntityAnimation {
+    from: Vec2,
+    to: Vec2,
+    move_started: f64,
+    action: Option<(Action, f64)>,
+}
+
+struct FloatingText {
+    pos: Vec2,
+    text: String,
+    color: Color,
+    started: f64,
+}
+
+#[derive(Default)]
+pub struct Animations {
+    entities: HashMap<Entity, EntityAnimation>,
+    camera_from: Vec2,
+    camera_to: Vec2,
+    camera_started: f64,
+    floating_texts: Vec<FloatingText>,
+}
+
+fn to_vec2(point: Point) -> Vec2 {
+    vec2(point.x as f32, point.y as f32)
+}
+
+// Returns the progress (0.0 to 1.0) of an animation started at the given time.
+fn progress(started: f64, duration: f64) -> f32 {
+    ((get_time() - started) / duration).clamp(0., 1.) as f32
+}
+
+impl Animations {
+    pub fn on_move(&mut self, entity: Entity, from: Point, to: Point) {
+        let from = self.entity_position(entity, from);
+        let animation = self.entities.entry(entity).or_insert(EntityAnimation {
+            from,
+            to: from,
+            move_started: 0.,
+            action: None,
+        });
+        animation.from = from;
+        animation.to = to_vec2(to);
+        animation.move_started = get_time();
+    }
+
+    pub fn on_camera_move(&mut self, from: &CameraView, to: &CameraView) {
+        self.camera_from = self.camera_offset(from);
+        self.camera_to = vec2(to.left_x as f32, to.top_y as f32);
+        self.camera_started = get_time();
+    }
+
+    pub fn on_attack(&mut self, attacker: Entity, attacker_pos: Point, victim_pos: Point) {
+        let direction = to_vec2(victim_pos - attacker_pos).normalize_or_zero();
+        self.start_action(attacker, attacker_pos, Action::Attack { direction });
+    }
+
+    pub fn on_hurt(&mut self, victim: Entity, victim_pos: Point, damage: i32) {
+        self.start_action(victim, victim_pos, Action::Hurt);
+        self.floating_texts.push(FloatingText {
+            pos: to_vec2(victim_pos),
+            text: format!("-{}", damage),
+            color: RED,
+            started: get_time(),
+        });
+    }
+
+    fn start_action(&mut self, entity: Entity, pos: Point, action: Action) {
+        let animation = self.entities.entry(entity).or_insert(EntityAnimation {
+            from: to_vec2(pos),
+            to: to_vec2(pos),
+            move_started: 0.,
+            action: None,
+        });
+        animation.action = Some((action, get_time()));
+    }
+
+    // Position of the entity in (fractional) map tiles.
+    pub fn entity_position(&self, entity: Entity, pos: Point) -> Vec2 {
+        let pos = to_vec2(pos);
+
+        let animation = match self.entities.get(&entity) {
+            Some(animation) => animation,
+            None => return pos,
+        };
+
+        // The logical position is the reference; if it changed without a movement (e.g. when the
+        // player changes level), the animation is stale.
+        let mut animated_pos = if animation.to == pos {
+            animation.from.lerp(
+                animation.to,
+                progress(animation.move_started, MOVE_DURATION),
+            )
+        } else {
+            pos
+        };
+
+        if let Some((Action::Attack { direction }, started)) = animation.action {
+            let t = progress(started, ACTION_DURATION);
+            if t < 1. {
+                // The lunge goes forth and back.
+                animated_pos += direction * ATTACK_LUNGE * (t * std::f32::consts::PI).sin();
+            }
+        }
+
+        animated_pos
+    }
+
+    // The current frame of the entity animation; while idle, the phase depends on the position, so
+    // that the entities don't animate in lockstep.
+    pub fn entity_sprite(
+        &self,
+        entity: Entity,
+        pos: Point,
+        sprite: Sprite,
+        tileset: &TileSet,
+    ) -> Sprite {
+        let action = self
+            .entities
+            .get(&entity)
+            .and_then(|animation| animation.action)
+            .filter(|(_, started)| progress(*started, ACTION_DURATION) < 1.);
+
+        let (frames, frame_index) = match action {
+            Some((action, started)) => {
+                let animation = match action {
+                    Action::Attack { .. } => SpriteAnimation::Attack,
+                    Action::Hurt => SpriteAnimation::Hurt,
+                };
+                let frames = tileset.animation_frames(sprite, animation);
+                let frame_index =
+                    (progress(started, ACTION_DURATION) * frames.len() as f32) as usize;
+                (frames, frame_index)
+            }
+            None => {
+                let frames = tileset.animation_frames(sprite, SpriteAnimation::Idle);
+                let phase = (pos.x + pos.y) as usize;
+                let frame_index = (get_time() / IDLE_FRAME_DURATION) as usize + phase;
+                (frames, frame_index)
+            }
+        };
+
+        frames[frame_index % frames.len()]
+    }
+
+    pub fn camera_offset(&self, camera: &CameraView) -> Vec2 {
+        let camera_pos = vec2(camera.left_x as f32, camera.top_y as f32);
+
+        if camera_pos == self.camera_to {
+            self.camera_from
+                .lerp(self.camera_to, progress(self.camera_started, MOVE_DURATION))
+        } else {
+            camera_pos
+        }
+    }
+
+    pub fn draw_floating_texts(&self, camera_offset: Vec2, tile_size: f32) {
+        for floating_text in &self.floating_texts {
+            let t = progress(floating_text.started, FLOATING_TEXT_DURATION);
+            let pos = floating_text.pos - camera_offset - vec2(0., t * FLOATING_TEXT_RISE);
+            let mut color = floating_text.color;
+            color.a = 1. - t;
+            draw_text_ex(
+                &floating_text.text,
+                (pos.x + 0.3) * tile_size,
+                pos.y * tile_size,
+                TextParams {
+                    color,
+                    font_size: (tile_size * 0.6) as u16,
+                    ..TextParams::default()
+                },
+            );
+        }
+    }
+
+    // Removes the animations that ended; this also takes care of the removed entities.
+    pub fn remove_expired(&mut self) {
+        let now = get_time();
+        self.entities.retain(|_, animation| {
+            now - animation.move_started < MOVE_DURATION
+                || animation
+                    .action
+                    .map_or(false, |(_, started)| now - started < ACTION_DURATION)
+        });
+        self.floating_texts
+            .retain(|floating_text| now - floating_text.started < FLOATING_TEXT_DURATION);
+    }
+}
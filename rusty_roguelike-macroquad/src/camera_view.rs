@@ -1,26 +1,72 @@
 use crate::prelude::*;
+use nanoserde::{DeRon, SerRon};
 
+// The zoom is an integer scale of the tileset sprites, which keeps them crisp (with nearest
+// filtering), and the tiles square, whatever the window size.
+const MIN_ZOOM: i32 = 1;
+const MAX_ZOOM: i32 = 3;
+const DEFAULT_ZOOM: i32 = 1;
+
+// The view size depends on the window size, which can change at any time, so it's recomputed on
+// every frame (see `refresh()`).
+#[derive(Clone, SerRon, DeRon)]
 pub struct CameraView {
     pub left_x: i32,
     pub right_x: i32,
     pub top_y: i32,
     pub bottom_y: i32,
+    center_x: i32,
+    center_y: i32,
+    zoom: i32,
 }
 
 impl CameraView {
     pub fn new(player_position: Point) -> Self {
-        Self {
-            left_x: player_position.x - DISPLAY_WIDTH / 2,
-            right_x: player_position.x + DISPLAY_WIDTH / 2,
-            top_y: player_position.y - DISPLAY_HEIGHT / 2,
-            bottom_y: player_position.y + DISPLAY_HEIGHT / 2,
-        }
+        let mut camera = Self {
+            left_x: 0,
+            right_x: 0,
+            top_y: 0,
+            bottom_y: 0,
+            center_x: player_position.x,
+            center_y: player_position.y,
+            zoom: DEFAULT_ZOOM,
+        };
+        camera.refresh();
+        camera
     }
 
     pub fn on_player_move(&mut self, player_position: Point) {
-        self.left_x = player_position.x - DISPLAY_WIDTH / 2;
-        self.right_x = player_position.x + DISPLAY_WIDTH / 2;
-        self.top_y = player_position.y - DISPLAY_HEIGHT / 2;
-        self.bottom_y = player_position.y + DISPLAY_HEIGHT / 2;
+        self.center_x = player_position.x;
+        self.center_y = player_position.y;
+        self.refresh();
+    }
+
+    // Positive steps zoom in.
+    pub fn zoom_by(&mut self, steps: i32) {
+        self.zoom = (self.zoom + steps).clamp(MIN_ZOOM, MAX_ZOOM);
+        self.refresh();
+    }
+
+    pub fn refresh(&mut self) {
+        let tile_size = self.tile_size();
+        let width = (screen_width() / tile_size).ceil() as i32;
+        let height = (screen_height() / tile_size).ceil() as i32;
+
+        self.left_x = self.center_x - width / 2;
+        self.right_x = self.left_x + width;
+        self.top_y = self.center_y - height / 2;
+        self.bottom_y = self.top_y + height;
+    }
+
+    // Size of a tile, in pixels.
+    pub fn tile_size(&self) -> f32 {
+        (self.zoom * TileSet::TILE_SIZE) as f32
+    }
+
+    // Position of the mouse, in tiles, relative to the top left of the view.
+    pub fn mouse_tile_position(&self) -> Point {
+        let (x, y) = mouse_position();
+        let tile_size = self.tile_size();
+        Point::new((x / tile_size) as i32, (y / tile_size) as i32)
     }
 }
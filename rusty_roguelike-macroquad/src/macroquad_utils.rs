@@ -1,40 +1,63 @@
 use crate::prelude::*;
 use macroquad::math::Rect;
 
-pub fn tile_size() -> Vec2 {
-    vec2(tile_width(), tile_height())
-}
+// The text uses its own grid, independent of the camera zoom, so that it stays legible at any window
+// size; the lines are sized relative to the window height, within limits.
+const TEXT_LINES: f32 = 40.;
+const MIN_TEXT_HEIGHT: f32 = 16.;
+const MAX_TEXT_HEIGHT: f32 = 32.;
 
-pub fn tile_width() -> f32 {
-    screen_width() / DISPLAY_WIDTH as f32
+pub fn text_height() -> f32 {
+    (screen_height() / TEXT_LINES)
+        .clamp(MIN_TEXT_HEIGHT, MAX_TEXT_HEIGHT)
+        .floor()
 }
 
-pub fn tile_height() -> f32 {
-    screen_height() / DISPLAY_HEIGHT as f32
+// Approximate; it's used only for positioning (the text is measured when aligning it).
+pub fn text_width() -> f32 {
+    text_height() / 2.
 }
 
-pub fn tile_pos_x(x: i32) -> f32 {
-    x as f32 * tile_width()
+pub fn text_columns() -> i32 {
+    (screen_width() / text_width()) as i32
 }
 
-pub fn tile_pos_y(y: i32) -> f32 {
-    y as f32 * tile_height()
+pub fn text_pos_x(x: i32) -> f32 {
+    x as f32 * text_width()
 }
 
-pub fn text_pos_x(x: i32) -> f32 {
-    x as f32 * screen_width() / SCREEN_WIDTH as f32
+pub fn text_pos_y(y: i32) -> f32 {
+    y as f32 * text_height()
 }
 
-pub fn mouse_tile_position() -> (i32, i32) {
-    let pos = mouse_position();
-    (
-        (pos.0 / tile_width()) as i32,
-        (pos.1 / tile_height()) as i32,
-    )
+pub fn mouse_text_position() -> Point {
+    let (x, y) = mouse_position();
+    Point::new((x / text_width()) as i32, (y / text_height()) as i32)
 }
 
 pub type Sprite = u16;
 
+// The creatures' sprites have extra animation frames, in the rows after the cp437 glyphs: one row per
+// animation, with the frames in the same order as ANIMATED_SPRITES.
+const ANIMATED_SPRITES: [Sprite; 6] = [
+    TileSet::SPRITE_PLAYER,
+    'b' as Sprite,
+    'g' as Sprite,
+    'o' as Sprite,
+    'O' as Sprite,
+    'E' as Sprite,
+];
+const IDLE_FRAMES_ROW: Sprite = 16;
+const ATTACK_FRAMES_ROW: Sprite = 17;
+const HURT_FRAMES_ROW: Sprite = 18;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpriteAnimation {
+    Idle,
+    Attack,
+    Hurt,
+}
+
 #[derive(Debug)]
 pub struct TileSet {
     pub texture: Texture2D,
@@ -44,6 +67,9 @@ pub struct TileSet {
 }
 
 impl TileSet {
+    // Width and height of the sprites, in pixels.
+    pub const TILE_SIZE: i32 = 32;
+
     pub const SPRITE_PLAYER: Sprite = 64;
     pub const SPRITE_WALL: Sprite = 35;
     pub const SPRITE_GROUND: Sprite = 59;
@@ -59,15 +85,32 @@ impl TileSet {
         Rect::new(sx, sy, sw, sh)
     }
 
-    pub fn draw_tile(&self, sprite: Sprite, color: Color, x: i32, y: i32) {
+    // The frames of the animation, which are played in sequence; sprites without animation frames
+    // (e.g. the items) have only the base one.
+    pub fn animation_frames(&self, sprite: Sprite, animation: SpriteAnimation) -> Vec<Sprite> {
+        let column = match ANIMATED_SPRITES.iter().position(|s| *s == sprite) {
+            Some(column) => column as Sprite,
+            None => return vec![sprite],
+        };
+        let frame = |row: Sprite| row * self.columns + column;
+
+        match animation {
+            SpriteAnimation::Idle => vec![sprite, frame(IDLE_FRAMES_ROW)],
+            SpriteAnimation::Attack => vec![frame(ATTACK_FRAMES_ROW)],
+            SpriteAnimation::Hurt => vec![frame(HURT_FRAMES_ROW), sprite, frame(HURT_FRAMES_ROW)],
+        }
+    }
+
+    // The position is in (fractional) tiles, so that the animations can draw between tiles.
+    pub fn draw_tile(&self, sprite: Sprite, color: Color, pos: Vec2, tile_size: f32) {
         let spr_rect = self.sprite_rect(sprite);
         draw_texture_ex(
             self.texture,
-            tile_pos_x(x),
-            tile_pos_y(y),
+            pos.x * tile_size,
+            pos.y * tile_size,
             color,
             DrawTextureParams {
-                dest_size: Some(tile_size()),
+                dest_size: Some(vec2(tile_size, tile_size)),
                 source: Some(spr_rect),
                 ..Default::default()
             },
@@ -86,16 +129,18 @@ pub fn print_color_centered<S>(line: i32, text: S, text_color: Color)
 where
     S: ToString,
 {
-    let x = SCREEN_WIDTH / 2 - (text.to_string().len() / 2) as i32;
-    print_color_pos(Point::new(x, line), text, text_color);
+    let text = text.to_string();
+    let x = (screen_width() - measure_line(&text).width) / 2.;
+    draw_line_at(x, line, &text, text_color);
 }
 
 pub fn print_color_right<S>(pos: Point, text: S, text_color: Color)
 where
     S: ToString,
 {
-    let offset = Point::new(text.to_string().len() as i32, 0);
-    print_color_pos(pos - offset, text, text_color);
+    let text = text.to_string();
+    let x = text_pos_x(pos.x) - measure_line(&text).width;
+    draw_line_at(x, pos.y, &text, text_color);
 }
 
 pub fn print_pos<S>(pos: Point, text: S)
@@ -109,21 +154,23 @@ pub fn print_color_pos<S>(pos: Point, text: S, text_color: Color)
 where
     S: ToString,
 {
+    draw_line_at(text_pos_x(pos.x), pos.y, &text.to_string(), text_color);
+}
+
+fn measure_line(text: &str) -> TextDimensions {
+    measure_text(text, Some(Font::default()), text_height() as u16, 1.0)
+}
+
+fn draw_line_at(x: f32, line: i32, text: &str, text_color: Color) {
     let text_params = TextParams {
         color: text_color,
-        font_size: tile_height() as u16,
+        font_size: text_height() as u16,
         ..TextParams::default()
     };
-    let dimensions = measure_text(
-        &text.to_string(),
-        Some(Font::default()),
-        text_params.font_size,
-        text_params.font_scale,
-    );
-    let x = text_pos_x(pos.x);
+    let dimensions = measure_line(text);
     let fudge = (dimensions.height - dimensions.offset_y) / 2.;
-    let y = tile_pos_y(pos.y) + fudge + dimensions.offset_y;
-    draw_text_ex(&text.to_string(), x, y, text_params);
+    let y = text_pos_y(line) + fudge + dimensions.offset_y;
+    draw_text_ex(text, x, y, text_params);
 }
 
 pub fn bar_horizontal(
@@ -134,23 +181,16 @@ pub fn bar_horizontal(
     color: Color,
     background: Color,
 ) {
-    let x = tile_pos_x(pos.x);
-    let y = tile_pos_y(pos.y);
-    let bar_width = tile_pos_x(width);
+    let x = text_pos_x(pos.x);
+    let y = text_pos_y(pos.y);
+    let bar_width = text_pos_x(width);
     let current_width = current as f32 / max as f32 * bar_width;
-    draw_rectangle(x, y, bar_width, tile_height(), background);
-    draw_rectangle(x, y, current_width, tile_height(), color);
-}
-
-pub fn random_slice_index<T>(slice: &[T]) -> Option<usize> {
-    if slice.is_empty() {
-        None
-    } else {
-        let size = slice.len();
-        if size == 1 {
-            Some(0)
-        } else {
-            Some(rand::gen_range(1, size as i32) as usize - 1)
-        }
-    }
+    draw_rectangle(x, y, bar_width, text_height(), background);
+    draw_rectangle(x, y, current_width, text_height(), color);
+}
+
+// The shared dungeon generation uses bracket-lib's RNG; it's seeded from the Macroquad one, which is
+// seeded on startup.
+pub fn new_rng() -> RandomNumberGenerator {
+    RandomNumberGenerator::seeded(rand::rand() as u64)
 }
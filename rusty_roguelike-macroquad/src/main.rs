@@ -1,13 +1,16 @@
 #![allow(clippy::all)]
 #![deny(clippy::correctness)]
 
+mod animation;
 mod camera_view;
 mod components;
 mod macroquad_utils;
-mod map;
-mod map_builder;
+mod map_overview;
+mod save_game;
 mod spawner;
 mod systems;
+mod themes;
+mod travel_plan;
 mod turn_state;
 
 #[macro_use]
@@ -25,25 +28,26 @@ fn gen_random_tiles(lower: usize, upper: usize) -> Vec<Sprite> {
 }
 
 mod prelude {
-    pub use bracket_pathfinding::prelude::*;
-    pub use legion::systems::CommandBuffer;
-    pub use legion::world::SubWorld;
-    pub use legion::*;
-    pub use macroquad::prelude::*;
-    pub const SCREEN_WIDTH: i32 = 80;
-    pub const SCREEN_HEIGHT: i32 = 50;
-    pub const DISPLAY_WIDTH: i32 = SCREEN_WIDTH / 2;
-    pub const DISPLAY_HEIGHT: i32 = SCREEN_HEIGHT / 2;
+    pub use crate::animation::*;
     pub use crate::camera_view::*;
     pub use crate::components::*;
     pub use crate::macroquad_utils::*;
-    pub use crate::map::*;
-    pub use crate::map_builder::*;
+    pub use crate::map_overview::*;
+    pub use crate::save_game::*;
     pub use crate::spawner::*;
     pub use crate::systems::*;
+    pub use crate::themes::*;
+    pub use crate::travel_plan::*;
     pub use crate::turn_state::*;
     pub use crate::RANDOM_FLOOR_TILES;
     pub use crate::RANDOM_TREE_TILES;
+    pub use bracket_pathfinding::prelude::*;
+    pub use bracket_random::prelude::RandomNumberGenerator;
+    pub use legion::systems::CommandBuffer;
+    pub use legion::world::SubWorld;
+    pub use legion::*;
+    pub use macroquad::prelude::*;
+    pub use rusty_roguelike_common::prelude::*;
 }
 
 use prelude::*;
@@ -62,17 +66,21 @@ impl State {
         rand::srand(miniquad::date::now() as u64);
         let mut ecs = World::default();
         let mut resources = Resources::default();
-        let mut map_builder = MapBuilder::new();
+        let mut rng = new_rng();
+        let mut map_builder = MapBuilder::new(&mut rng);
         let tileset = Self::tileset(texture);
         spawn_player(&mut ecs, map_builder.player_start);
         let exit_idx = map_builder.map.point2d_to_index(map_builder.amulet_start);
         map_builder.map.tiles[exit_idx] = TileType::Exit;
-        spawn_level(&mut ecs, 0, &map_builder.monster_spawns).await;
+        spawn_level(&mut ecs, &mut rng, 0, &map_builder.monster_spawns).await;
         resources.insert(map_builder.map);
         resources.insert(CameraView::new(map_builder.player_start));
         resources.insert(tileset);
         resources.insert(TurnState::AwaitingInput);
-        resources.insert(map_builder.theme);
+        resources.insert(theme_for(map_builder.theme));
+        resources.insert(TravelPlan::default());
+        resources.insert(Animations::default());
+        resources.insert(MapOverview::default());
         Self {
             ecs,
             resources,
@@ -86,8 +94,8 @@ impl State {
     fn tileset(texture: Texture2D) -> TileSet {
         TileSet {
             texture: texture,
-            tile_width: 32,
-            tile_height: 32,
+            tile_width: TileSet::TILE_SIZE,
+            tile_height: TileSet::TILE_SIZE,
             columns: 16,
         }
     }
@@ -96,8 +104,19 @@ impl State {
         clear_background(BLACK);
         self.resources.insert(get_last_key_pressed());
         self.resources
-            .insert(Point::from_tuple(mouse_tile_position()));
+            .insert(is_mouse_button_pressed(MouseButton::Left).then(|| MouseButton::Left));
+        self.update_camera();
+        let mouse_pos = self
+            .resources
+            .get::<CameraView>()
+            .unwrap()
+            .mouse_tile_position();
+        self.resources.insert(mouse_pos);
         let current_state = self.resources.get::<TurnState>().unwrap().clone();
+        if current_state == TurnState::AwaitingInput {
+            self.save_or_restore();
+            self.toggle_map_overview();
+        }
         match current_state {
             TurnState::AwaitingInput => self
                 .input_systems
@@ -114,6 +133,48 @@ impl State {
         }
     }
 
+    // The window can be resized at any time, so the view is recomputed on every frame. The zoom is
+    // not part of the game state, so it's handled regardless of the turn.
+    fn update_camera(&mut self) {
+        let mut camera = self.resources.get_mut::<CameraView>().unwrap();
+        let (_, wheel_y) = mouse_wheel();
+        if wheel_y > 0. {
+            camera.zoom_by(1);
+        } else if wheel_y < 0. {
+            camera.zoom_by(-1);
+        } else {
+            camera.refresh();
+        }
+    }
+
+    // Showing the overview doesn't take a turn.
+    fn toggle_map_overview(&mut self) {
+        if *self.resources.get::<Option<KeyCode>>().unwrap() == Some(KeyCode::M) {
+            self.resources.get_mut::<MapOverview>().unwrap().toggle();
+            self.resources.insert(None::<KeyCode>);
+        }
+    }
+
+    // Saving and restoring need access to the whole world, so they can't be performed by a system.
+    fn save_or_restore(&mut self) {
+        let key = *self.resources.get::<Option<KeyCode>>().unwrap();
+        match key {
+            Some(KeyCode::S) => {
+                let save_game = SaveGame::from_world(&self.ecs, &self.resources);
+                if let Err(error) = save_game.save(&mut DefaultStorage::default()) {
+                    warn!("Error while saving the game: {}", error);
+                }
+            }
+            Some(KeyCode::R) => match SaveGame::load(&DefaultStorage::default()) {
+                Ok(save_game) => save_game.restore(&mut self.ecs, &mut self.resources),
+                Err(error) => warn!("Error while restoring the game: {}", error),
+            },
+            _ => return,
+        }
+        // Consume the key, otherwise, the player input system would consider it a turn.
+        self.resources.insert(None::<KeyCode>);
+    }
+
     async fn game_over(&mut self) {
         print_color_centered(2, "Your quest has ended.", RED);
         print_color_centered(
@@ -191,7 +252,8 @@ impl State {
             .iter_mut(&mut self.ecs)
             .for_each(|fov| fov.is_dirty = true);
 
-        let mut map_builder = MapBuilder::new();
+        let mut rng = new_rng();
+        let mut map_builder = MapBuilder::new(&mut rng);
         let mut map_level = 0;
         <(&mut Player, &mut Point)>::query()
             .iter_mut(&mut self.ecs)
@@ -211,41 +273,55 @@ impl State {
 
         spawn_level(
             &mut self.ecs,
+            &mut rng,
             map_level as usize,
             &map_builder.monster_spawns,
         )
         .await;
         self.resources.insert(map_builder.map);
+        // The camera is kept, in order to preserve the zoom.
         self.resources
-            .insert(CameraView::new(map_builder.player_start));
+            .get_mut::<CameraView>()
+            .unwrap()
+            .on_player_move(map_builder.player_start);
         let tileset = Self::tileset(self.texture);
         self.resources.insert(tileset);
         self.resources.insert(TurnState::AwaitingInput);
-        self.resources.insert(map_builder.theme);
+        self.resources.insert(theme_for(map_builder.theme));
+        self.resources.insert(TravelPlan::default());
+        self.resources.insert(Animations::default());
     }
 
     async fn reset_game_state(&mut self) {
+        // The game is over, so the save game can't be restored anymore.
+        DefaultStorage::default().delete();
         self.ecs = World::default();
         self.resources = Resources::default();
-        let mut map_builder = MapBuilder::new();
+        let mut rng = new_rng();
+        let mut map_builder = MapBuilder::new(&mut rng);
         let tileset = Self::tileset(self.texture);
         spawn_player(&mut self.ecs, map_builder.player_start);
         let exit_idx = map_builder.map.point2d_to_index(map_builder.amulet_start);
         map_builder.map.tiles[exit_idx] = TileType::Exit;
-        spawn_level(&mut self.ecs, 0, &map_builder.monster_spawns).await;
+        spawn_level(&mut self.ecs, &mut rng, 0, &map_builder.monster_spawns).await;
         self.resources.insert(map_builder.map);
         self.resources
             .insert(CameraView::new(map_builder.player_start));
         self.resources.insert(tileset);
         self.resources.insert(TurnState::AwaitingInput);
-        self.resources.insert(map_builder.theme);
+        self.resources.insert(theme_for(map_builder.theme));
+        self.resources.insert(TravelPlan::default());
+        self.resources.insert(Animations::default());
+        self.resources.insert(MapOverview::default());
     }
 }
 
 fn window_conf() -> Conf {
     Conf {
         window_title: "Rusty Dungeon".to_owned(),
-        fullscreen: true,
+        window_width: 1280,
+        window_height: 800,
+        window_resizable: true,
         ..Default::default()
     }
 }
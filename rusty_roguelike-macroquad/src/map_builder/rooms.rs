@@ -1,28 +0,0 @@
-use super::MapArchitect;
-use crate::prelude::*;
-
-pub struct RoomsArchitect {}
-
-impl MapArchitect for RoomsArchitect {
-    fn new(&mut self) -> MapBuilder {
-        let mut mb = MapBuilder {
-            map: Map::new(),
-            rooms: Vec::new(),
-            monster_spawns: Vec::new(),
-            player_start: Point::zero(),
-            amulet_start: Point::zero(),
-            theme: super::themes::DungeonTheme::new(),
-        };
-
-        mb.fill(TileType::Wall);
-        mb.build_random_rooms();
-        mb.build_corridors();
-        mb.player_start = mb.rooms[0].center();
-        mb.amulet_start = mb.find_most_distant();
-        for room in mb.rooms.iter().skip(1) {
-            mb.monster_spawns.push(room.center());
-        }
-
-        mb
-    }
-}
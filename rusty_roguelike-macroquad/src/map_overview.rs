@@ -0,0 +1,11 @@
+// Whether the full-screen map overview is shown; when it isn't, the corner minimap is shown instead.
+#[derive(Default)]
+pub struct MapOverview {
+    pub visible: bool,
+}
+
+impl MapOverview {
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+}
@@ -0,0 +1,386 @@
+mod storage;
+
+use crate::prelude::*;
+use nanoserde::{DeRon, SerRon};
+pub use storage::*;
+
+// Legion's serialization requires serde, so the world is converted to a plain structure, which is then
+// serialized via nanoserde.
+//
+// Only the components and resources that define the game state are stored; the others (e.g. the
+// tileset) are recreated on load, and the messages are not stored at all, since the game is saved
+// only while awaiting input, when there aren't any.
+
+#[derive(SerRon, DeRon)]
+pub struct SaveGame {
+    entities: Vec<SavedEntity>,
+    map: Map,
+    camera: CameraView,
+    turn_state: TurnState,
+    theme: String,
+}
+
+#[derive(SerRon, DeRon)]
+struct SavedRender {
+    r: f32,
+    g: f32,
+    b: f32,
+    a: f32,
+    sprite: Sprite,
+}
+
+#[derive(SerRon, DeRon)]
+struct SavedEntity {
+    pos: Option<(i32, i32)>,
+    render: Option<SavedRender>,
+    player_map_level: Option<u32>,
+    enemy: bool,
+    moving_randomly: bool,
+    chasing_player: bool,
+    item: bool,
+    amulet_of_yala: bool,
+    weapon: bool,
+    provides_dungeon_map: bool,
+    health: Option<(i32, i32)>,
+    name: Option<String>,
+    fov_radius: Option<i32>,
+    provides_healing: Option<i32>,
+    damage: Option<i32>,
+    // Index of the carrier in the saved entities list.
+    carried_by: Option<usize>,
+}
+
+impl SaveGame {
+    pub fn from_world(ecs: &World, resources: &Resources) -> Self {
+        let all_entities: Vec<Entity> = <Entity>::query().iter(ecs).copied().collect();
+        let entity_index = |entity: Entity| all_entities.iter().position(|e| *e == entity);
+
+        let entities = all_entities
+            .iter()
+            .map(|entity| {
+                let entry = ecs.entry_ref(*entity).unwrap();
+
+                SavedEntity {
+                    pos: entry
+                        .get_component::<Point>()
+                        .ok()
+                        .map(|pos| (pos.x, pos.y)),
+                    render: entry
+                        .get_component::<Render>()
+                        .ok()
+                        .map(|render| SavedRender {
+                            r: render.color.r,
+                            g: render.color.g,
+                            b: render.color.b,
+                            a: render.color.a,
+                            sprite: render.sprite,
+                        }),
+                    player_map_level: entry
+                        .get_component::<Player>()
+                        .ok()
+                        .map(|player| player.map_level),
+                    enemy: entry.get_component::<Enemy>().is_ok(),
+                    moving_randomly: entry.get_component::<MovingRandomly>().is_ok(),
+                    chasing_player: entry.get_component::<ChasingPlayer>().is_ok(),
+                    item: entry.get_component::<Item>().is_ok(),
+                    amulet_of_yala: entry.get_component::<AmuletOfYala>().is_ok(),
+                    weapon: entry.get_component::<Weapon>().is_ok(),
+                    provides_dungeon_map: entry.get_component::<ProvidesDungeonMap>().is_ok(),
+                    health: entry
+                        .get_component::<Health>()
+                        .ok()
+                        .map(|health| (health.current, health.max)),
+                    name: entry
+                        .get_component::<Name>()
+                        .ok()
+                        .map(|name| name.0.clone()),
+                    fov_radius: entry
+                        .get_component::<FieldOfView>()
+                        .ok()
+                        .map(|fov| fov.radius),
+                    provides_healing: entry
+                        .get_component::<ProvidesHealing>()
+                        .ok()
+                        .map(|healing| healing.amount),
+                    damage: entry.get_component::<Damage>().ok().map(|damage| damage.0),
+                    carried_by: entry
+                        .get_component::<Carried>()
+                        .ok()
+                        .and_then(|carried| entity_index(carried.0)),
+                }
+            })
+            .collect();
+
+        let map = resources.get::<Map>().unwrap();
+        let camera = resources.get::<CameraView>().unwrap();
+
+        Self {
+            entities,
+            map: map.clone(),
+            camera: camera.clone(),
+            turn_state: *resources.get::<TurnState>().unwrap(),
+            theme: resources
+                .get::<Box<dyn MapTheme>>()
+                .unwrap()
+                .name()
+                .to_string(),
+        }
+    }
+
+    // Replaces the world and the game state resources with the saved ones.
+    pub fn restore(self, ecs: &mut World, resources: &mut Resources) {
+        *ecs = World::default();
+        let mut commands = CommandBuffer::new(ecs);
+
+        let entities: Vec<Entity> = self
+            .entities
+            .iter()
+            .map(|saved| {
+                let entity = commands.push(((),));
+
+                if let Some((x, y)) = saved.pos {
+                    commands.add_component(entity, Point::new(x, y));
+                }
+                if let Some(render) = &saved.render {
+                    commands.add_component(
+                        entity,
+                        Render {
+                            color: Color::new(render.r, render.g, render.b, render.a),
+                            sprite: render.sprite,
+                        },
+                    );
+                }
+                if let Some(map_level) = saved.player_map_level {
+                    commands.add_component(entity, Player { map_level });
+                }
+                if saved.enemy {
+                    commands.add_component(entity, Enemy);
+                }
+                if saved.moving_randomly {
+                    commands.add_component(entity, MovingRandomly);
+                }
+                if saved.chasing_player {
+                    commands.add_component(entity, ChasingPlayer);
+                }
+                if saved.item {
+                    commands.add_component(entity, Item);
+                }
+                if saved.amulet_of_yala {
+                    commands.add_component(entity, AmuletOfYala);
+                }
+                if saved.weapon {
+                    commands.add_component(entity, Weapon);
+                }
+                if saved.provides_dungeon_map {
+                    commands.add_component(entity, ProvidesDungeonMap);
+                }
+                if let Some((current, max)) = saved.health {
+                    commands.add_component(entity, Health { current, max });
+                }
+                if let Some(name) = &saved.name {
+                    commands.add_component(entity, Name(name.clone()));
+                }
+                // The visible tiles are not stored; they're recomputed on the next frame.
+                if let Some(radius) = saved.fov_radius {
+                    commands.add_component(entity, FieldOfView::new(radius));
+                }
+                if let Some(amount) = saved.provides_healing {
+                    commands.add_component(entity, ProvidesHealing { amount });
+                }
+                if let Some(damage) = saved.damage {
+                    commands.add_component(entity, Damage(damage));
+                }
+
+                entity
+            })
+            .collect();
+
+        // The carriers must all exist before their references can be restored.
+        self.entities
+            .iter()
+            .zip(entities.iter())
+            .for_each(|(saved, entity)| {
+                if let Some(carrier_index) = saved.carried_by {
+                    commands.add_component(*entity, Carried(entities[carrier_index]));
+                }
+            });
+
+        commands.flush(ecs);
+
+        resources.insert(self.map);
+        resources.insert(self.camera);
+        resources.insert(self.turn_state);
+        // The theme is validated on load.
+        resources.insert(theme_from_name(&self.theme).unwrap());
+        resources.insert(TravelPlan::default());
+        resources.insert(Animations::default());
+    }
+
+    pub fn save(&self, storage: &mut impl SaveStorage) -> Result<(), String> {
+        storage.write(&self.serialize_ron())
+    }
+
+    pub fn load(storage: &impl SaveStorage) -> Result<Self, String> {
+        let data = storage.read().ok_or("No save game found")?;
+        let save_game: Self =
+            DeRon::deserialize_ron(&data).map_err(|error| format!("{:?}", error))?;
+
+        if theme_from_name(&save_game.theme).is_none() {
+            return Err(format!("Unknown theme: {}", save_game.theme));
+        }
+
+        Ok(save_game)
+    }
+}
+
+// The web storage requires a browser, so only the file storage is tested.
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_save_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rusty-dungeon-{}-{}.ron", name, std::process::id()))
+    }
+
+    fn test_game() -> (World, Resources) {
+        let mut ecs = World::default();
+        let mut resources = Resources::default();
+
+        let mut map = Map::new();
+        map.tiles[0] = TileType::Wall;
+        map.tiles[1] = TileType::Exit;
+        map.revealed_tiles[2] = true;
+
+        let player = ecs.push((
+            Player { map_level: 1 },
+            Point::new(3, 4),
+            Health {
+                current: 7,
+                max: 10,
+            },
+            FieldOfView::new(8),
+        ));
+        ecs.push((
+            Enemy,
+            ChasingPlayer,
+            Point::new(5, 6),
+            Health { current: 2, max: 2 },
+            Name("Orc".to_string()),
+            Damage(2),
+        ));
+        ecs.push((
+            Item,
+            Weapon,
+            Name("Rusty Sword".to_string()),
+            Damage(1),
+            Carried(player),
+        ));
+
+        resources.insert(map);
+        // CameraView::new() requires a window, to compute the view size.
+        let camera: CameraView = DeRon::deserialize_ron(
+            "(left_x:-5,right_x:11,top_y:-3,bottom_y:11,center_x:3,center_y:4,zoom:1)",
+        )
+        .unwrap();
+        resources.insert(camera);
+        resources.insert(TurnState::AwaitingInput);
+        resources.insert(ForestTheme::new());
+
+        (ecs, resources)
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let (ecs, resources) = test_game();
+        let path = temp_save_path("round-trip");
+        let mut storage = DefaultStorage::new(&path);
+
+        SaveGame::from_world(&ecs, &resources)
+            .save(&mut storage)
+            .unwrap();
+        let save_game = SaveGame::load(&storage).unwrap();
+        storage.delete();
+
+        let (mut restored_ecs, mut restored_resources) = (World::default(), Resources::default());
+        save_game.restore(&mut restored_ecs, &mut restored_resources);
+
+        let (map, restored_map) = (
+            resources.get::<Map>().unwrap(),
+            restored_resources.get::<Map>().unwrap(),
+        );
+        assert_eq!(map.tiles, restored_map.tiles);
+        assert_eq!(map.revealed_tiles, restored_map.revealed_tiles);
+
+        let camera = restored_resources.get::<CameraView>().unwrap();
+        assert_eq!((camera.left_x, camera.top_y), (-5, -3));
+
+        let (player, player_data) = <(Entity, &Player, &Point, &Health, &FieldOfView)>::query()
+            .iter(&restored_ecs)
+            .next()
+            .map(|(entity, player, pos, health, fov)| {
+                (*entity, (*player, *pos, *health, fov.radius))
+            })
+            .unwrap();
+        assert_eq!(
+            player_data,
+            (
+                Player { map_level: 1 },
+                Point::new(3, 4),
+                Health {
+                    current: 7,
+                    max: 10
+                },
+                8
+            )
+        );
+
+        let enemies: Vec<_> = <(&Name, &Point, &Health)>::query()
+            .filter(component::<Enemy>() & component::<ChasingPlayer>())
+            .iter(&restored_ecs)
+            .map(|(name, pos, health)| (name.0.clone(), *pos, *health))
+            .collect();
+        assert_eq!(
+            enemies,
+            vec![(
+                "Orc".to_string(),
+                Point::new(5, 6),
+                Health { current: 2, max: 2 }
+            )]
+        );
+
+        let carried: Vec<_> = <(&Name, &Carried, &Damage)>::query()
+            .filter(component::<Item>() & component::<Weapon>())
+            .iter(&restored_ecs)
+            .map(|(name, carried, damage)| (name.0.clone(), carried.0, *damage))
+            .collect();
+        assert_eq!(
+            carried,
+            vec![("Rusty Sword".to_string(), player, Damage(1))]
+        );
+
+        assert_eq!(
+            restored_resources
+                .get::<Box<dyn MapTheme>>()
+                .unwrap()
+                .name(),
+            ForestTheme::NAME
+        );
+    }
+
+    #[test]
+    fn load_rejects_unknown_theme() {
+        let (ecs, resources) = test_game();
+        let path = temp_save_path("unknown-theme");
+        let mut storage = DefaultStorage::new(&path);
+
+        let mut save_game = SaveGame::from_world(&ecs, &resources);
+        save_game.theme = "swamp".to_string();
+        save_game.save(&mut storage).unwrap();
+
+        let result = SaveGame::load(&storage);
+        storage.delete();
+
+        assert_eq!(result.err().unwrap(), "Unknown theme: swamp");
+    }
+}
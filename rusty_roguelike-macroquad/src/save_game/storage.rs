@@ -0,0 +1,79 @@
+// Where the save game is physically stored; on native platforms, it's a file, while on the web, it's
+// the browser local storage.
+
+pub trait SaveStorage {
+    fn write(&mut self, data: &str) -> Result<(), String>;
+    fn read(&self) -> Option<String>;
+    fn delete(&mut self);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use file_storage::FileStorage as DefaultStorage;
+#[cfg(target_arch = "wasm32")]
+pub use local_storage::LocalStorage as DefaultStorage;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod file_storage {
+    use super::SaveStorage;
+    use std::fs;
+    use std::path::PathBuf;
+
+    const SAVE_FILE: &str = "savegame.ron";
+
+    pub struct FileStorage {
+        path: PathBuf,
+    }
+
+    impl FileStorage {
+        pub fn new(path: impl Into<PathBuf>) -> Self {
+            Self { path: path.into() }
+        }
+    }
+
+    impl Default for FileStorage {
+        fn default() -> Self {
+            Self::new(SAVE_FILE)
+        }
+    }
+
+    impl SaveStorage for FileStorage {
+        fn write(&mut self, data: &str) -> Result<(), String> {
+            fs::write(&self.path, data).map_err(|error| error.to_string())
+        }
+
+        fn read(&self) -> Option<String> {
+            fs::read_to_string(&self.path).ok()
+        }
+
+        fn delete(&mut self) {
+            // If the file doesn't exist, there's nothing to do.
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod local_storage {
+    use super::SaveStorage;
+
+    const SAVE_KEY: &str = "rusty-dungeon-savegame";
+
+    // The storage requires the `quad-storage` JS plugin to be loaded by the page (see the README).
+    #[derive(Default)]
+    pub struct LocalStorage;
+
+    impl SaveStorage for LocalStorage {
+        fn write(&mut self, data: &str) -> Result<(), String> {
+            quad_storage::STORAGE.lock().unwrap().set(SAVE_KEY, data);
+            Ok(())
+        }
+
+        fn read(&self) -> Option<String> {
+            quad_storage::STORAGE.lock().unwrap().get(SAVE_KEY)
+        }
+
+        fn delete(&mut self) {
+            quad_storage::STORAGE.lock().unwrap().remove(SAVE_KEY);
+        }
+    }
+}
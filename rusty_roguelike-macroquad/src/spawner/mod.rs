@@ -1,7 +1,7 @@
 mod template;
 
 use crate::prelude::*;
-use template::Templates;
+use template::*;
 
 pub fn spawn_player(ecs: &mut World, pos: Point) {
     ecs.push((
@@ -33,7 +33,12 @@ pub fn spawn_amulet_of_yala(ecs: &mut World, pos: Point) {
     ));
 }
 
-pub async fn spawn_level(ecs: &mut World, level: usize, spawn_points: &[Point]) {
-    let template = Templates::load().await;
-    template.spawn_entities(ecs, level, spawn_points);
+pub async fn spawn_level(
+    ecs: &mut World,
+    rng: &mut RandomNumberGenerator,
+    level: usize,
+    spawn_points: &[Point],
+) {
+    let templates = load_templates().await;
+    spawn_entities(&templates, ecs, rng, level, spawn_points);
 }
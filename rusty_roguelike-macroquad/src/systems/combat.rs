@@ -6,7 +6,12 @@ use crate::prelude::*;
 #[write_component(Health)]
 #[read_component(Damage)]
 #[read_component(Carried)]
-pub fn combat(ecs: &mut SubWorld, commands: &mut CommandBuffer) {
+#[read_component(Point)]
+pub fn combat(
+    ecs: &mut SubWorld,
+    commands: &mut CommandBuffer,
+    #[resource] animations: &mut Animations,
+) {
     let mut attackers = <(Entity, &WantsToAttack)>::query();
 
     let victims: Vec<(Entity, Entity, Entity)> = attackers
@@ -36,6 +41,19 @@ pub fn combat(ecs: &mut SubWorld, commands: &mut CommandBuffer) {
             .map(|(_, dmg)| dmg.0)
             .sum();
         let final_damage = base_damage + weapon_damage;
+
+        let attacker_pos = ecs
+            .entry_ref(*attacker)
+            .ok()
+            .and_then(|e| e.get_component::<Point>().ok().copied());
+        let victim_pos = ecs
+            .entry_ref(*victim)
+            .ok()
+            .and_then(|e| e.get_component::<Point>().ok().copied());
+        if let (Some(attacker_pos), Some(victim_pos)) = (attacker_pos, victim_pos) {
+            animations.on_attack(*attacker, attacker_pos, victim_pos);
+            animations.on_hurt(*victim, victim_pos, final_damage);
+        }
         debug!(
             "final_damage: {}, base_damage: {}, weapon_damage: {}",
             final_damage, base_damage, weapon_damage
@@ -0,0 +1,7 @@
+use crate::prelude::*;
+
+#[system]
+pub fn effects_render(#[resource] camera: &CameraView, #[resource] animations: &mut Animations) {
+    animations.remove_expired();
+    animations.draw_floating_texts(animations.camera_offset(camera), camera.tile_size());
+}
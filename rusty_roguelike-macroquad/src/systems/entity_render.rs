@@ -9,17 +9,20 @@ pub fn entity_render(
     ecs: &SubWorld,
     #[resource] camera: &CameraView,
     #[resource] tileset: &TileSet,
+    #[resource] animations: &Animations,
 ) {
-    let mut renderables = <(&Point, &Render)>::query();
+    let mut renderables = <(Entity, &Point, &Render)>::query();
     let mut fov = <&FieldOfView>::query().filter(component::<Player>());
-    let offset = Point::new(camera.left_x, camera.top_y);
+    let offset = animations.camera_offset(camera);
+    let tile_size = camera.tile_size();
     let player_fov = fov.iter(ecs).nth(0).unwrap();
 
     renderables
         .iter(ecs)
-        .filter(|(pos, _)| player_fov.visible_tiles.contains(&pos))
-        .for_each(|(pos, render)| {
-            let local_pos = *pos - offset;
-            tileset.draw_tile(render.sprite, render.color, local_pos.x, local_pos.y);
+        .filter(|(_, pos, _)| player_fov.visible_tiles.contains(&pos))
+        .for_each(|(entity, pos, render)| {
+            let local_pos = animations.entity_position(*entity, *pos) - offset;
+            let sprite = animations.entity_sprite(*entity, *pos, render.sprite, tileset);
+            tileset.draw_tile(sprite, render.color, local_pos, tile_size);
         });
 }
@@ -10,10 +10,13 @@ pub fn hud(ecs: &SubWorld) {
     let mut health_query = <&Health>::query().filter(component::<Player>());
     let player_health = health_query.iter(ecs).nth(0).unwrap();
 
-    print_centered(1, "Cursor keys to move. G to pickup items.");
+    print_centered(
+        1,
+        "Cursor keys or mouse click to move. G to pickup items. S/R to save/restore. M for map. Wheel to zoom.",
+    );
     bar_horizontal(
         Point::zero(),
-        DISPLAY_WIDTH,
+        text_columns(),
         player_health.current,
         player_health.max,
         RED,
@@ -30,7 +33,7 @@ pub fn hud(ecs: &SubWorld) {
         .find_map(|(entity, player)| Some((*entity, player.map_level)))
         .unwrap();
     print_color_right(
-        Point::new(SCREEN_WIDTH, 1),
+        Point::new(text_columns(), 1),
         format!("Dungeon Level: {}", map_level + 1),
         YELLOW,
     );
@@ -9,14 +9,17 @@ pub fn map_render(
     #[resource] camera: &CameraView,
     #[resource] tileset: &TileSet,
     #[resource] theme: &Box<dyn MapTheme>,
+    #[resource] animations: &Animations,
 ) {
     let mut fov = <&FieldOfView>::query().filter(component::<Player>());
     let player_fov = fov.iter(ecs).nth(0).unwrap();
+    let offset = animations.camera_offset(camera);
+    let tile_size = camera.tile_size();
 
-    for y in camera.top_y..=camera.bottom_y {
-        for x in camera.left_x..camera.right_x {
+    // While the camera scrolls, the tiles just outside the view become partially visible.
+    for y in camera.top_y - 1..=camera.bottom_y + 1 {
+        for x in camera.left_x - 1..=camera.right_x {
             let pt = Point::new(x, y);
-            let offset = Point::new(camera.left_x, camera.top_y);
             let idx = map_idx(x, y);
             if map.in_bounds(pt)
                 && (player_fov.visible_tiles.contains(&pt) | map.revealed_tiles[idx])
@@ -27,8 +30,7 @@ pub fn map_render(
                     DARKGRAY
                 };
                 let sprite = theme.tile_to_render(map.tiles[idx], idx);
-                let pos = pt - offset;
-                tileset.draw_tile(sprite, tint, pos.x, pos.y);
+                tileset.draw_tile(sprite, tint, vec2(x as f32, y as f32) - offset, tile_size);
             }
         }
     }
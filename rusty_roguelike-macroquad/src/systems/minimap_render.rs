@@ -0,0 +1,99 @@
+use crate::prelude::*;
+
+// Size of a map tile, in pixels, on the corner minimap.
+const MINIMAP_CELL: f32 = 3.;
+const MARGIN: f32 = 8.;
+// Fraction of the screen taken by the overview.
+const OVERVIEW_SCREEN_FRACTION: f32 = 0.9;
+
+const WALL_COLOR: Color = Color::new(0.45, 0.35, 0.25, 1.);
+const FLOOR_COLOR: Color = Color::new(0.25, 0.25, 0.25, 1.);
+const BACKGROUND_COLOR: Color = Color::new(0., 0., 0., 0.75);
+
+// Only the revealed tiles are shown; the items are shown if their tile has been revealed, while the
+// monsters only if they're currently visible, since they move.
+#[system]
+#[read_component(Point)]
+#[read_component(Player)]
+#[read_component(Enemy)]
+#[read_component(Item)]
+#[read_component(FieldOfView)]
+pub fn minimap_render(ecs: &SubWorld, #[resource] map: &Map, #[resource] overview: &MapOverview) {
+    let (player_pos, player_fov) = <(&Point, &FieldOfView)>::query()
+        .filter(component::<Player>())
+        .iter(ecs)
+        .find_map(|(pos, fov)| Some((*pos, fov)))
+        .unwrap();
+
+    let (cell, origin) = if overview.visible {
+        let cell = (screen_width() * OVERVIEW_SCREEN_FRACTION / SCREEN_WIDTH as f32)
+            .min(screen_height() * OVERVIEW_SCREEN_FRACTION / SCREEN_HEIGHT as f32)
+            .floor()
+            .max(1.);
+        let origin = vec2(
+            (screen_width() - cell * SCREEN_WIDTH as f32) / 2.,
+            (screen_height() - cell * SCREEN_HEIGHT as f32) / 2.,
+        );
+        draw_rectangle(0., 0., screen_width(), screen_height(), BACKGROUND_COLOR);
+        (cell, origin)
+    } else {
+        let origin = vec2(
+            screen_width() - MINIMAP_CELL * SCREEN_WIDTH as f32 - MARGIN,
+            text_pos_y(2) + MARGIN,
+        );
+        draw_rectangle(
+            origin.x,
+            origin.y,
+            MINIMAP_CELL * SCREEN_WIDTH as f32,
+            MINIMAP_CELL * SCREEN_HEIGHT as f32,
+            BACKGROUND_COLOR,
+        );
+        (MINIMAP_CELL, origin)
+    };
+
+    let draw_cell = |pos: Point, color: Color| {
+        draw_rectangle(
+            origin.x + pos.x as f32 * cell,
+            origin.y + pos.y as f32 * cell,
+            cell,
+            cell,
+            color,
+        );
+    };
+
+    for (idx, tile) in map.tiles.iter().enumerate() {
+        if map.revealed_tiles[idx] {
+            let color = match tile {
+                TileType::Exit => YELLOW,
+                _ if map.is_opaque(idx) => WALL_COLOR,
+                _ => FLOOR_COLOR,
+            };
+            draw_cell(map.index_to_point2d(idx), color);
+        }
+    }
+
+    <&Point>::query()
+        .filter(component::<Item>())
+        .iter(ecs)
+        .filter(|pos| {
+            map.try_idx(**pos)
+                .map_or(false, |idx| map.revealed_tiles[idx])
+        })
+        .for_each(|pos| draw_cell(*pos, SKYBLUE));
+
+    <&Point>::query()
+        .filter(component::<Enemy>())
+        .iter(ecs)
+        .filter(|pos| player_fov.visible_tiles.contains(pos))
+        .for_each(|pos| draw_cell(*pos, RED));
+
+    draw_cell(player_pos, WHITE);
+
+    if overview.visible {
+        print_color_centered(
+            ((origin.y + cell * SCREEN_HEIGHT as f32) / text_height()) as i32,
+            "M to close the map",
+            YELLOW,
+        );
+    }
+}
@@ -2,25 +2,31 @@ use crate::prelude::*;
 
 mod chasing;
 mod combat;
+mod effects_render;
 mod end_turn;
 mod entity_render;
 mod fov;
 mod hud;
 mod map_render;
+mod minimap_render;
 mod movement;
 mod player_input;
 mod random_move;
 mod tooltips;
+mod travel;
 mod use_items;
 
 pub fn build_input_scheduler() -> Schedule {
     Schedule::builder()
         .add_system(player_input::player_input_system())
+        .add_system(travel::travel_system())
         .add_system(fov::fov_system())
         .flush()
         .add_system(map_render::map_render_system())
         .add_system(entity_render::entity_render_system())
+        .add_system(effects_render::effects_render_system())
         .add_system(hud::hud_system())
+        .add_system(minimap_render::minimap_render_system())
         .add_system(tooltips::tooltips_system())
         .build()
 }
@@ -36,7 +42,9 @@ pub fn build_player_scheduler() -> Schedule {
         .flush()
         .add_system(map_render::map_render_system())
         .add_system(entity_render::entity_render_system())
+        .add_system(effects_render::effects_render_system())
         .add_system(hud::hud_system())
+        .add_system(minimap_render::minimap_render_system())
         .add_system(tooltips::tooltips_system())
         .add_system(end_turn::end_turn_system())
         .build()
@@ -56,7 +64,9 @@ pub fn build_monster_scheduler() -> Schedule {
         .flush()
         .add_system(map_render::map_render_system())
         .add_system(entity_render::entity_render_system())
+        .add_system(effects_render::effects_render_system())
         .add_system(hud::hud_system())
+        .add_system(minimap_render::minimap_render_system())
         .add_system(tooltips::tooltips_system())
         .add_system(end_turn::end_turn_system())
         .build()
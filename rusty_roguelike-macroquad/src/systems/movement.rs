@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 #[system(for_each)]
+#[read_component(Point)]
 #[read_component(Player)]
 #[read_component(FieldOfView)]
 pub fn movement(
@@ -8,6 +9,7 @@ pub fn movement(
     want_move: &WantsToMove,
     #[resource] map: &mut Map,
     #[resource] camera: &mut CameraView,
+    #[resource] animations: &mut Animations,
     ecs: &mut SubWorld,
     commands: &mut CommandBuffer,
 ) {
@@ -15,11 +17,17 @@ pub fn movement(
         commands.add_component(want_move.entity, want_move.destination);
 
         if let Ok(entry) = ecs.entry_ref(want_move.entity) {
+            if let Ok(pos) = entry.get_component::<Point>() {
+                animations.on_move(want_move.entity, *pos, want_move.destination);
+            }
+
             if let Ok(fov) = entry.get_component::<FieldOfView>() {
                 commands.add_component(want_move.entity, fov.clone_dirty());
 
                 if entry.get_component::<Player>().is_ok() {
+                    let previous_camera = camera.clone();
                     camera.on_player_move(want_move.destination);
+                    animations.on_camera_move(&previous_camera, camera);
                     fov.visible_tiles.iter().for_each(|pos| {
                         map.revealed_tiles[map_idx(pos.x, pos.y)] = true;
                     });
@@ -17,7 +17,8 @@ pub fn tooltips(ecs: &SubWorld, #[resource] mouse_pos: &Point, #[resource] camer
         .iter(ecs)
         .filter(|(_, pos, _)| **pos == map_pos && player_fov.visible_tiles.contains(&pos))
         .for_each(|(entity, _, name)| {
-            let screen_pos = *mouse_pos;
+            // The text grid is independent of the map one.
+            let screen_pos = mouse_text_position();
             let display =
                 if let Ok(health) = ecs.entry_ref(*entity).unwrap().get_component::<Health>() {
                     format!("{} : {} hp", &name.0, health.current)
@@ -0,0 +1,120 @@
+use crate::prelude::*;
+use std::collections::HashSet;
+
+// Delay between automatic steps, so that the player can follow the movement.
+const AUTO_STEP_DELAY: f64 = 0.1;
+
+#[system]
+#[read_component(Point)]
+#[read_component(Player)]
+#[read_component(Enemy)]
+#[read_component(FieldOfView)]
+pub fn travel(
+    ecs: &SubWorld,
+    commands: &mut CommandBuffer,
+    #[resource] key: &Option<KeyCode>,
+    #[resource] mouse_button: &Option<MouseButton>,
+    #[resource] mouse_pos: &Point,
+    #[resource] camera: &CameraView,
+    #[resource] map: &Map,
+    #[resource] travel_plan: &mut TravelPlan,
+    #[resource] turn_state: &mut TurnState,
+) {
+    // Keyboard input always takes precedence; it's handled by the player input system.
+    if key.is_some() {
+        travel_plan.cancel();
+        return;
+    }
+
+    let (player_entity, player_pos, player_fov) = <(Entity, &Point, &FieldOfView)>::query()
+        .filter(component::<Player>())
+        .iter(ecs)
+        .next()
+        .map(|(entity, pos, fov)| (*entity, *pos, fov))
+        .unwrap();
+
+    let mut enemies = <(Entity, &Point)>::query().filter(component::<Enemy>());
+    let visible_enemies: HashSet<Entity> = enemies
+        .iter(ecs)
+        .filter(|(_, pos)| player_fov.visible_tiles.contains(pos))
+        .map(|(entity, _)| *entity)
+        .collect();
+
+    if let Some(MouseButton::Left) = mouse_button {
+        let target = *mouse_pos + Point::new(camera.left_x, camera.top_y);
+        let target_enemy = enemies
+            .iter(ecs)
+            .find(|(entity, pos)| **pos == target && visible_enemies.contains(entity))
+            .map(|(entity, _)| *entity);
+
+        if let Some(victim) = target_enemy {
+            if DistanceAlg::Manhattan.distance2d(player_pos, target) == 1. {
+                travel_plan.cancel();
+                commands.push((
+                    (),
+                    WantsToAttack {
+                        attacker: player_entity,
+                        victim,
+                    },
+                ));
+                *turn_state = TurnState::PlayerTurn;
+                return;
+            }
+        }
+
+        travel_plan.plan(
+            player_pos,
+            target,
+            map,
+            &player_fov.visible_tiles,
+            visible_enemies.clone(),
+        );
+    }
+
+    if !travel_plan.is_active() {
+        return;
+    }
+
+    if visible_enemies
+        .iter()
+        .any(|entity| !travel_plan.known_enemies.contains(entity))
+    {
+        travel_plan.cancel();
+        return;
+    }
+
+    let now = get_time();
+    if now - travel_plan.last_step_time < AUTO_STEP_DELAY {
+        return;
+    }
+    travel_plan.last_step_time = now;
+
+    if let Some(destination) = travel_plan.next_step(player_pos) {
+        // Like with the keyboard, moving into an enemy attacks it; in this case, the travel ends.
+        let victim = enemies
+            .iter(ecs)
+            .find(|(_, pos)| **pos == destination)
+            .map(|(entity, _)| *entity);
+
+        if let Some(victim) = victim {
+            travel_plan.cancel();
+            commands.push((
+                (),
+                WantsToAttack {
+                    attacker: player_entity,
+                    victim,
+                },
+            ));
+        } else {
+            commands.push((
+                (),
+                WantsToMove {
+                    entity: player_entity,
+                    destination,
+                },
+            ));
+        }
+
+        *turn_state = TurnState::PlayerTurn;
+    }
+}
@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+pub trait MapTheme: Sync + Send {
+    fn tile_to_render(&self, tile_type: TileType, idx: usize) -> Sprite;
+    // Used to identify the theme in the save games.
+    fn name(&self) -> &'static str;
+}
+
+pub fn theme_for(kind: ThemeKind) -> Box<dyn MapTheme> {
+    match kind {
+        ThemeKind::Dungeon => DungeonTheme::new(),
+        ThemeKind::Forest => ForestTheme::new(),
+    }
+}
+
+// None if there's no theme with the given name, e.g. in a corrupted save game.
+pub fn theme_from_name(name: &str) -> Option<Box<dyn MapTheme>> {
+    match name {
+        DungeonTheme::NAME => Some(DungeonTheme::new()),
+        ForestTheme::NAME => Some(ForestTheme::new()),
+        _ => None,
+    }
+}
+
+// This port doesn't generate the interactive tiles (doors, traps...), so they don't have dedicated
+// sprites; the tileset has the ASCII glyphs in the cp437 positions, which are used instead.
+fn interactive_tile_sprite(tile_type: TileType) -> Sprite {
+    let glyph = match tile_type {
+        TileType::UpStairs => '<',
+        TileType::DoorClosed => '+',
+        TileType::DoorOpen => '\'',
+        TileType::Trap => '^',
+        _ => '%',
+    };
+    glyph as Sprite
+}
+
+pub struct DungeonTheme {}
+
+impl DungeonTheme {
+    pub const NAME: &'static str = "dungeon";
+
+    pub fn new() -> Box<dyn MapTheme> {
+        Box::new(Self {})
+    }
+}
+
+impl MapTheme for DungeonTheme {
+    fn tile_to_render(&self, tile_type: TileType, idx: usize) -> Sprite {
+        match tile_type {
+            TileType::Floor | TileType::HiddenTrap => RANDOM_FLOOR_TILES[idx],
+            TileType::Wall | TileType::SecretWall => TileSet::SPRITE_WALL,
+            TileType::Exit => TileSet::SPRITE_STAIRS,
+            _ => interactive_tile_sprite(tile_type),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+}
+
+pub struct ForestTheme {}
+
+impl MapTheme for ForestTheme {
+    fn tile_to_render(&self, tile_type: TileType, idx: usize) -> Sprite {
+        match tile_type {
+            TileType::Floor | TileType::HiddenTrap => TileSet::SPRITE_GROUND,
+            TileType::Wall | TileType::SecretWall => RANDOM_TREE_TILES[idx],
+            TileType::Exit => TileSet::SPRITE_STAIRS,
+            _ => interactive_tile_sprite(tile_type),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+}
+
+impl ForestTheme {
+    pub const NAME: &'static str = "forest";
+
+    pub fn new() -> Box<dyn MapTheme> {
+        Box::new(Self {})
+    }
+}
@@ -0,0 +1,166 @@
+use crate::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+// Path planned by clicking on a map tile; the player follows it one step per turn.
+#[derive(Default)]
+pub struct TravelPlan {
+    steps: VecDeque<Point>,
+    // Enemies already visible when the plan was made; the travel is interrupted only when an enemy
+    // not in this set comes into view.
+    pub known_enemies: HashSet<Entity>,
+    pub last_step_time: f64,
+}
+
+// The map as known to the player: the revealed tiles, plus the ones currently in view. The path
+// is searched on it, so that it doesn't go through (and give away) unexplored areas.
+struct KnownMap<'a> {
+    map: &'a Map,
+    visible_tiles: &'a HashSet<Point>,
+}
+
+impl KnownMap<'_> {
+    fn is_known(&self, point: Point) -> bool {
+        self.map.try_idx(point).map_or(false, |idx| {
+            self.map.revealed_tiles[idx] || self.visible_tiles.contains(&point)
+        })
+    }
+}
+
+impl Algorithm2D for KnownMap<'_> {
+    fn dimensions(&self) -> Point {
+        self.map.dimensions()
+    }
+
+    fn in_bounds(&self, point: Point) -> bool {
+        self.map.in_bounds(point)
+    }
+}
+
+impl BaseMap for KnownMap<'_> {
+    fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+        self.map
+            .get_available_exits(idx)
+            .into_iter()
+            .filter(|(exit_idx, _)| self.is_known(self.map.index_to_point2d(*exit_idx)))
+            .collect()
+    }
+
+    fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+        self.map.get_pathing_distance(idx1, idx2)
+    }
+}
+
+impl TravelPlan {
+    // The target must be known to the player, and reachable through known tiles.
+    pub fn plan(
+        &mut self,
+        start: Point,
+        target: Point,
+        map: &Map,
+        visible_tiles: &HashSet<Point>,
+        known_enemies: HashSet<Entity>,
+    ) {
+        self.cancel();
+
+        let known_map = KnownMap { map, visible_tiles };
+
+        if start == target || !map.can_enter_tile(target) || !known_map.is_known(target) {
+            return;
+        }
+
+        let path = a_star_search(
+            map.point2d_to_index(start),
+            map.point2d_to_index(target),
+            &known_map,
+        );
+
+        if path.success {
+            // The first step is the start position.
+            self.steps = path
+                .steps
+                .iter()
+                .skip(1)
+                .map(|idx| map.index_to_point2d(*idx))
+                .collect();
+            self.known_enemies = known_enemies;
+        }
+    }
+
+    pub fn cancel(&mut self) {
+        self.steps.clear();
+        self.known_enemies.clear();
+        self.last_step_time = 0.;
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.steps.is_empty()
+    }
+
+    // If the player didn't end up where planned (e.g. the way was blocked), the plan is invalid.
+    pub fn next_step(&mut self, player_pos: Point) -> Option<Point> {
+        let step = self.steps.pop_front()?;
+
+        if DistanceAlg::Manhattan.distance2d(player_pos, step) == 1. {
+            Some(step)
+        } else {
+            self.cancel();
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A corridor from (1, 1) to (5, 1), surrounded by walls.
+    fn corridor_map() -> Map {
+        let mut map = Map::new();
+        map.tiles.iter_mut().for_each(|t| *t = TileType::Wall);
+        for x in 1..=5 {
+            map.tiles[map_idx(x, 1)] = TileType::Floor;
+        }
+        map
+    }
+
+    fn plan(map: &Map, visible_tiles: &HashSet<Point>) -> TravelPlan {
+        let mut travel_plan = TravelPlan::default();
+        travel_plan.plan(
+            Point::new(1, 1),
+            Point::new(5, 1),
+            map,
+            visible_tiles,
+            HashSet::new(),
+        );
+        travel_plan
+    }
+
+    #[test]
+    fn travels_through_known_tiles() {
+        let mut map = corridor_map();
+        for x in 1..=4 {
+            map.revealed_tiles[map_idx(x, 1)] = true;
+        }
+        let visible_tiles = HashSet::from([Point::new(5, 1)]);
+
+        let mut travel_plan = plan(&map, &visible_tiles);
+
+        assert!(travel_plan.is_active());
+        assert_eq!(
+            travel_plan.next_step(Point::new(1, 1)),
+            Some(Point::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn doesnt_travel_through_unrevealed_tiles() {
+        let mut map = corridor_map();
+        for x in [1, 2, 4, 5] {
+            map.revealed_tiles[map_idx(x, 1)] = true;
+        }
+
+        let travel_plan = plan(&map, &HashSet::new());
+
+        assert!(!travel_plan.is_active());
+    }
+}
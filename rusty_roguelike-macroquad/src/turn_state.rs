@@ -1,4 +1,6 @@
-#[derive(Copy, Clone, Debug, PartialEq)]
+use nanoserde::{DeRon, SerRon};
+
+#[derive(Copy, Clone, Debug, PartialEq, SerRon, DeRon)]
 pub enum TurnState {
     AwaitingInput,
     PlayerTurn,
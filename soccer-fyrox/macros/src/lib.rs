@@ -2,8 +2,9 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
     self,
-    parse::{self, Parser},
-    Data, DataStruct, DeriveInput, Fields,
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    Data, DataStruct, DeriveInput, Expr, Fields, Ident, Token,
 };
 
 type TokenStream2 = proc_macro2::TokenStream;
@@ -18,6 +19,22 @@ macro_rules! bail {
     };
 }
 
+// The fields added to the struct; the names can't be used by the struct fields.
+//
+const ADDED_FIELDS: [&str; 4] = ["img_base", "img_indexes", "vpos", "anchor"];
+
+const ARG_NAMES: [&str; 3] = ["anchor", "img_base", "z_layer"];
+
+// Adds the actor fields, and implements MyActor.
+//
+// The optional arguments are:
+//
+// - `anchor = <Anchor expr>`: defines the `DEFAULT_ANCHOR` associated const;
+// - `img_base = <&'static str expr>`: defines the `DEFAULT_IMG_BASE` associated const;
+// - `z_layer = <f32 expr>`: the fixed drawing depth (see `MyActor::z_layer()`).
+//
+// e.g. `#[my_actor_based(img_base = "ball", anchor = Anchor::Center)]`.
+//
 #[proc_macro_attribute]
 pub fn my_actor_based(args: TokenStream, input: TokenStream) -> TokenStream {
     let my_actor_based_impl = impl_my_actor_based(args, input);
@@ -27,54 +44,147 @@ pub fn my_actor_based(args: TokenStream, input: TokenStream) -> TokenStream {
         .into()
 }
 
+#[derive(Default)]
+struct Args {
+    anchor: Option<Expr>,
+    img_base: Option<Expr>,
+    z_layer: Option<Expr>,
+}
+
+struct Arg {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> ::syn::Result<Self> {
+        let mut args = Self::default();
+
+        for Arg { name, value } in Punctuated::<Arg, Token![,]>::parse_terminated(input)? {
+            let arg = match name.to_string().as_str() {
+                "anchor" => &mut args.anchor,
+                "img_base" => &mut args.img_base,
+                "z_layer" => &mut args.z_layer,
+                _ => bail!(
+                    format!(
+                        "unknown argument `{}`; expected one of: {}",
+                        name,
+                        ARG_NAMES.map(|n| format!("`{}`", n)).join(", ")
+                    ) => name
+                ),
+            };
+
+            if arg.is_some() {
+                bail!(format!("duplicate argument `{}`", name) => name);
+            }
+
+            *arg = Some(value);
+        }
+
+        Ok(args)
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> ::syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        // Without this check, the error would point to the whole attribute.
+        //
+        if !input.peek(Token![=]) {
+            bail!(format!("expected `= <value>` after `{}`", name) => name);
+        }
+
+        let _: Token![=] = input.parse()?;
+        let value = input.parse()?;
+
+        Ok(Self { name, value })
+    }
+}
+
 fn impl_my_actor_based(
     args: impl Into<TokenStream2>,
     input: impl Into<TokenStream2>,
 ) -> ::syn::Result<TokenStream2> {
     let mut ast: DeriveInput = ::syn::parse2(input.into())?;
-    let _: parse::Nothing = ::syn::parse2(args.into())?;
+    let args: Args = ::syn::parse2(args.into())?;
 
     add_fields(&mut ast)?;
 
-    let trait_impl = impl_trait(&ast)?;
+    let trait_impl = impl_trait(&ast, &args)?;
+    let defaults_impl = impl_defaults(&ast, &args);
 
     Ok(quote!(
         #ast
 
         #trait_impl
+
+        #defaults_impl
     ))
 }
 
 fn add_fields(ast: &'_ mut DeriveInput) -> ::syn::Result<()> {
-    if let Data::Struct(DataStruct {
-        fields: Fields::Named(fields),
-        ..
-    }) = &mut ast.data
-    {
-        let fields_tokens = vec![
-            quote! { pub img_base: &'static str },
-            quote! { pub img_indexes: Vec<u8> },
-            quote! { pub vpos: Vector2<f32> },
-            quote! { anchor: Anchor },
-            quote! { rectangle_h: Handle<Node> },
-        ];
-
-        for field_tokens in fields_tokens {
-            let field = syn::Field::parse_named.parse2(field_tokens).unwrap();
-            fields.named.push(field);
+    let fields = match &mut ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields,
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) => {
+            bail!("my_actor_based requires named fields; tuple structs are not supported" => fields)
+        }
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        }) => {
+            bail!("my_actor_based requires named fields; use `{}` for an empty struct" => ast.ident)
+        }
+        Data::Enum(data) => {
+            bail!("my_actor_based can only be applied to structs" => data.enum_token)
         }
+        Data::Union(data) => {
+            bail!("my_actor_based can only be applied to structs" => data.union_token)
+        }
+    };
 
-        Ok(())
-    } else {
-        bail!("Unexpected input (missing curly braces?)")
+    for field in &fields.named {
+        if let Some(ident) = &field.ident {
+            if ADDED_FIELDS.iter().any(|added| ident == added) {
+                bail!(format!("field `{}` is added by my_actor_based, and can't be declared", ident) => ident);
+            }
+        }
     }
+
+    let fields_tokens = vec![
+        quote! { pub img_base: &'static str },
+        quote! { pub img_indexes: Vec<u8> },
+        quote! { pub vpos: Vector2<f32> },
+        quote! { anchor: Anchor },
+    ];
+
+    for field_tokens in fields_tokens {
+        let field = syn::Field::parse_named.parse2(field_tokens).unwrap();
+        fields.named.push(field);
+    }
+
+    Ok(())
 }
 
-fn impl_trait(ast: &'_ DeriveInput) -> ::syn::Result<TokenStream2> {
+fn impl_trait(ast: &'_ DeriveInput, args: &Args) -> ::syn::Result<TokenStream2> {
     #[allow(non_snake_case)]
     let TyName = &ast.ident;
     let (intro_generics, forward_generics, maybe_where_clause) = ast.generics.split_for_impl();
 
+    let z_layer_impl = args.z_layer.as_ref().map(|z_layer| {
+        quote!(
+            fn z_layer(&self) -> Option<f32> {
+                Some(#z_layer)
+            }
+        )
+    });
+
     Ok(quote!(
         impl #intro_generics
             crate::my_actor::MyActor
@@ -102,9 +212,37 @@ fn impl_trait(ast: &'_ DeriveInput) -> ::syn::Result<TokenStream2> {
                 self.anchor
             }
 
-            fn rectangle_h(&self) -> Handle<Node> {
-                self.rectangle_h
-            }
+            #z_layer_impl
+        }
+    ))
+}
+
+// The associated consts of the default values, if any; they're meant to be used by the constructors.
+//
+fn impl_defaults(ast: &'_ DeriveInput, args: &Args) -> Option<TokenStream2> {
+    if args.anchor.is_none() && args.img_base.is_none() {
+        return None;
+    }
+
+    #[allow(non_snake_case)]
+    let TyName = &ast.ident;
+    let (intro_generics, forward_generics, maybe_where_clause) = ast.generics.split_for_impl();
+
+    let anchor_const = args.anchor.as_ref().map(|anchor| {
+        quote!(
+            pub const DEFAULT_ANCHOR: Anchor = #anchor;
+        )
+    });
+    let img_base_const = args.img_base.as_ref().map(|img_base| {
+        quote!(
+            pub const DEFAULT_IMG_BASE: &'static str = #img_base;
+        )
+    });
+
+    Some(quote!(
+        impl #intro_generics #TyName #forward_generics #maybe_where_clause {
+            #anchor_const
+            #img_base_const
         }
     ))
 }
@@ -0,0 +1,10 @@
+// Compile tests of the macro, for both valid and invalid usages; the expected diagnostics are in the
+// `.stderr` files, which are regenerated via `TRYBUILD=overwrite cargo test`.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+
+    cases.pass("tests/ui/pass/*.rs");
+    cases.compile_fail("tests/ui/fail/*.rs");
+}
@@ -0,0 +1,10 @@
+include!("../support.rs");
+
+use soccer_macros_fyrox::my_actor_based;
+
+#[my_actor_based]
+pub enum Ball {
+    Rolling,
+}
+
+fn main() {}
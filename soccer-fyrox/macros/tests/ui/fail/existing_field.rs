@@ -0,0 +1,11 @@
+include!("../support.rs");
+
+use soccer_macros_fyrox::my_actor_based;
+
+#[my_actor_based]
+pub struct Ball {
+    pub height: f32,
+    pub vpos: Vector2<f32>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+include!("../support.rs");
+
+use soccer_macros_fyrox::my_actor_based;
+
+#[my_actor_based]
+pub struct Ball;
+
+fn main() {}
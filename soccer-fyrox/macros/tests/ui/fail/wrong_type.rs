@@ -0,0 +1,8 @@
+include!("../support.rs");
+
+use soccer_macros_fyrox::my_actor_based;
+
+#[my_actor_based(img_base = 1)]
+pub struct Ball {}
+
+fn main() {}
@@ -0,0 +1,34 @@
+include!("../support.rs");
+
+use my_actor::MyActor;
+use soccer_macros_fyrox::my_actor_based;
+
+const ANCHOR: Vector2<f32> = Vector2 { x: 25., y: 37. };
+const DRAW_Z: f32 = 9.;
+
+#[my_actor_based(img_base = "player", anchor = Anchor::Custom(ANCHOR), z_layer = DRAW_Z)]
+pub struct Player {
+    pub team: u8,
+}
+
+// The arguments are optional, and can be in any order.
+//
+#[my_actor_based(anchor = Anchor::Center,)]
+pub struct Ball {}
+
+fn main() {
+    let player = Player {
+        img_base: Player::DEFAULT_IMG_BASE,
+        img_indexes: vec![],
+        vpos: Vector2 { x: 0., y: 0. },
+        anchor: Player::DEFAULT_ANCHOR,
+        team: 1,
+    };
+
+    assert_eq!(player.img_base(), "player");
+    assert_eq!(player.anchor(), Anchor::Custom(ANCHOR));
+    assert_eq!(player.z_layer(), Some(DRAW_Z));
+    assert_eq!(player.team, 1);
+
+    assert_eq!(Ball::DEFAULT_ANCHOR, Anchor::Center);
+}
@@ -0,0 +1,25 @@
+include!("../support.rs");
+
+use my_actor::MyActor;
+use soccer_macros_fyrox::my_actor_based;
+
+#[my_actor_based(img_base = "goal")]
+pub struct Holder<T>
+where
+    T: Copy,
+{
+    pub item: T,
+}
+
+fn main() {
+    let holder = Holder {
+        img_base: Holder::<u8>::DEFAULT_IMG_BASE,
+        img_indexes: vec![],
+        vpos: Vector2 { x: 0., y: 0. },
+        anchor: Anchor::Center,
+        item: 1_u8,
+    };
+
+    assert_eq!(holder.img_base(), "goal");
+    assert_eq!(holder.item, 1);
+}
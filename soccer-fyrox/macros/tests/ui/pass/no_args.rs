@@ -0,0 +1,24 @@
+include!("../support.rs");
+
+use my_actor::MyActor;
+use soccer_macros_fyrox::my_actor_based;
+
+#[my_actor_based]
+pub struct BareActor {}
+
+fn main() {
+    let mut actor = BareActor {
+        img_base: "arrow",
+        img_indexes: vec![1],
+        vpos: Vector2 { x: 1., y: 2. },
+        anchor: Anchor::Center,
+    };
+
+    actor.vpos_mut().x = 3.;
+
+    assert_eq!(actor.vpos(), Vector2 { x: 3., y: 2. });
+    assert_eq!(actor.img_base(), "arrow");
+    assert_eq!(actor.img_indexes(), &[1]);
+    assert_eq!(actor.anchor(), Anchor::Center);
+    assert_eq!(actor.z_layer(), None);
+}
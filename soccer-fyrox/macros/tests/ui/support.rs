@@ -0,0 +1,29 @@
+// The items referenced by the generated code; in the game, they come from the prelude.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Anchor {
+    Center,
+    Custom(Vector2<f32>),
+}
+
+pub mod my_actor {
+    use super::*;
+
+    pub trait MyActor {
+        fn vpos(&self) -> Vector2<f32>;
+        fn vpos_mut(&mut self) -> &mut Vector2<f32>;
+        fn img_base(&self) -> &'static str;
+        fn img_indexes(&self) -> &[u8];
+        fn anchor(&self) -> Anchor;
+
+        fn z_layer(&self) -> Option<f32> {
+            None
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+// Rust: The arrow over the player under control of a human team; in the source project, it's a plain
+// Actor, created on each frame. The arrows are always drawn above the players.
+//
+#[my_actor_based(img_base = "arrow", anchor = Anchor::TopLeft, z_layer = DRAW_ARROWS_Z)]
+pub struct Arrow {}
+
+impl Arrow {
+    pub fn new(team: u8, player_vpos: Vector2<f32>) -> Self {
+        let vpos = player_vpos - Vector2::new(11., 45.);
+
+        Self {
+            img_base: Self::DEFAULT_IMG_BASE,
+            img_indexes: vec![team],
+            vpos,
+            anchor: Self::DEFAULT_ANCHOR,
+        }
+    }
+}
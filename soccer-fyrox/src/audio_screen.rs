@@ -0,0 +1,116 @@
+use fyrox::{
+    core::color::Color,
+    gui::{border::BorderBuilder, brush::Brush, widget::WidgetBuilder},
+};
+
+use crate::prelude::*;
+
+// Rust: The screen for the music and effects levels (see AudioSettings).
+
+const BACKGROUND_COLOR: Color = Color::from_rgba(0, 0, 0, 200);
+const FONT_SIZE: f32 = 20.;
+const TEXT_Y: f32 = 120.;
+
+pub struct AudioScreen {
+    background_h: Handle<UiNode>,
+    text_h: Handle<UiNode>,
+    // Index of the selected SoundCategory.
+    selected: usize,
+}
+
+impl AudioScreen {
+    // Doesn't display the screen or perform any instantiation.
+    //
+    pub fn new() -> Self {
+        Self {
+            background_h: Handle::NONE,
+            text_h: Handle::NONE,
+            selected: 0,
+        }
+    }
+
+    pub fn display(&mut self, settings: &AudioSettings, user_interface: &mut UserInterface) {
+        self.selected = 0;
+
+        self.text_h = add_text_widget_node(0., TEXT_Y, WIDTH, FONT_SIZE, user_interface);
+
+        self.background_h = BorderBuilder::new(
+            WidgetBuilder::new()
+                .with_width(WIDTH)
+                .with_height(HEIGHT)
+                .with_background(Brush::Solid(BACKGROUND_COLOR))
+                .with_foreground(Brush::Solid(Color::TRANSPARENT))
+                .with_child(self.text_h),
+        )
+        .build(&mut user_interface.build_ctx());
+
+        self.refresh(settings, user_interface);
+    }
+
+    // Returns true when the player leaves the screen; the settings are saved on each change.
+    //
+    pub fn update(
+        &mut self,
+        input: &InputController,
+        settings: &mut AudioSettings,
+        user_interface: &mut UserInterface,
+    ) -> bool {
+        use VirtualKeyCode::*;
+
+        let entries_count = SoundCategory::ALL.len();
+        let category = SoundCategory::ALL[self.selected];
+        let volume = settings.volume(category);
+
+        if input.is_key_just_pressed(Escape) {
+            return true;
+        } else if input.is_key_just_pressed(Right) {
+            settings.set_volume(category, volume + 1);
+            settings.save();
+        } else if input.is_key_just_pressed(Left) {
+            settings.set_volume(category, volume.saturating_sub(1));
+            settings.save();
+        } else if input.is_key_just_pressed(Down) {
+            self.selected = (self.selected + 1) % entries_count;
+        } else if input.is_key_just_pressed(Up) {
+            self.selected = (self.selected + entries_count - 1) % entries_count;
+        } else {
+            return false;
+        }
+
+        self.refresh(settings, user_interface);
+
+        false
+    }
+
+    pub fn clear(&mut self, user_interface: &mut UserInterface) {
+        // The text is a child of the background, so it's removed with it.
+        //
+        self.background_h = remove_widget_node(self.background_h, user_interface);
+        self.text_h = Handle::NONE;
+    }
+
+    fn refresh(&self, settings: &AudioSettings, user_interface: &mut UserInterface) {
+        let mut text = String::from("AUDIO\n\n");
+
+        for (category_i, category) in SoundCategory::ALL.into_iter().enumerate() {
+            let volume = settings.volume(category) as usize;
+            let bar = format!(
+                "{}{}",
+                "|".repeat(volume),
+                ".".repeat(MAX_VOLUME as usize - volume)
+            );
+
+            let line = format!("{}: {}", category.name().to_uppercase(), bar);
+
+            if self.selected == category_i {
+                text.push_str(&format!("> {line} <\n"));
+            } else {
+                text.push_str(&format!("{line}\n"));
+            }
+        }
+
+        text.push_str("\nUP/DOWN: SELECT - LEFT/RIGHT: CHANGE - ESC: BACK");
+
+        update_widget_text(self.text_h, &text, user_interface);
+    }
+}
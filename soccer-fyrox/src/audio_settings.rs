@@ -0,0 +1,103 @@
+use crate::storage;
+
+// Rust: In the source project, the sounds play at full volume.
+
+const AUDIO_FILE: &str = "audio.txt";
+
+// The volumes are set in steps, from 0 (muted) to MAX_VOLUME (full volume).
+pub const MAX_VOLUME: u8 = 10;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SoundCategory {
+    // The theme.
+    Music,
+    // The crowd loop.
+    Crowd,
+    // The one-off sounds.
+    Effects,
+}
+
+impl SoundCategory {
+    pub const ALL: [Self; 3] = [Self::Music, Self::Crowd, Self::Effects];
+
+    // Used both in the config file and on the audio screen.
+    //
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Music => "music",
+            Self::Crowd => "crowd",
+            Self::Effects => "effects",
+        }
+    }
+
+    // The category of a looping sound.
+    //
+    pub fn of_looping_sound(name: &str) -> Self {
+        match name {
+            "theme" => Self::Music,
+            "crowd" => Self::Crowd,
+            _ => Self::Effects,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct AudioSettings {
+    // Indexed by SoundCategory.
+    volumes: [u8; 3],
+}
+
+impl AudioSettings {
+    // Missing or invalid entries keep the full volume.
+    //
+    pub fn load() -> Self {
+        let mut settings = Self::default();
+
+        if let Some(config) = storage::read(AUDIO_FILE) {
+            for line in config.lines() {
+                if let Some((name, volume)) = line.split_once('=') {
+                    let category = SoundCategory::ALL
+                        .into_iter()
+                        .find(|category| category.name() == name.trim());
+
+                    if let (Some(category), Ok(volume)) = (category, volume.trim().parse()) {
+                        settings.set_volume(category, volume);
+                    }
+                }
+            }
+        }
+
+        settings
+    }
+
+    pub fn save(&self) {
+        let config = SoundCategory::ALL
+            .into_iter()
+            .map(|category| format!("{} = {}\n", category.name(), self.volume(category)))
+            .collect::<String>();
+
+        storage::write(AUDIO_FILE, &config);
+    }
+
+    pub fn volume(&self, category: SoundCategory) -> u8 {
+        self.volumes[category as usize]
+    }
+
+    pub fn set_volume(&mut self, category: SoundCategory, volume: u8) {
+        self.volumes[category as usize] = volume.min(MAX_VOLUME);
+    }
+
+    // The volume as a gain, from 0 to 1.
+    //
+    pub fn gain(&self, category: SoundCategory) -> f32 {
+        self.volume(category) as f32 / MAX_VOLUME as f32
+    }
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            volumes: [MAX_VOLUME; 3],
+        }
+    }
+}
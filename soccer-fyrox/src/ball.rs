@@ -1,27 +1,6 @@
 use crate::prelude::*;
 
-const PITCH_BOUNDS_X: (f32, f32) = (HALF_LEVEL_W - HALF_PITCH_W, HALF_LEVEL_W + HALF_PITCH_W);
-const PITCH_BOUNDS_Y: (f32, f32) = (HALF_LEVEL_H - HALF_PITCH_H, HALF_LEVEL_H + HALF_PITCH_H);
-
-const GOAL_BOUNDS_X: (f32, f32) = (HALF_LEVEL_W - HALF_GOAL_W, HALF_LEVEL_W + HALF_GOAL_W);
-const GOAL_BOUNDS_Y: (f32, f32) = (
-    HALF_LEVEL_H - HALF_PITCH_H - GOAL_DEPTH,
-    HALF_LEVEL_H + HALF_PITCH_H + GOAL_DEPTH,
-);
-
-const PITCH_RECT: Rect = Rect::new(
-    PITCH_BOUNDS_X.0,
-    PITCH_BOUNDS_Y.0,
-    HALF_PITCH_W * 2.,
-    HALF_PITCH_H * 2.,
-);
-const GOAL_0_RECT: Rect = Rect::new(GOAL_BOUNDS_X.0, GOAL_BOUNDS_Y.0, GOAL_WIDTH, GOAL_DEPTH);
-const GOAL_1_RECT: Rect = Rect::new(
-    GOAL_BOUNDS_X.0,
-    GOAL_BOUNDS_Y.1 - GOAL_DEPTH,
-    GOAL_WIDTH,
-    GOAL_DEPTH,
-);
+// Rust: The pitch and goal bounds and rects depend on the pitch size; see Pitch.
 
 //# ball physics for one axis
 fn ball_physics(mut pos: f32, mut vel: f32, bounds: (f32, f32)) -> (f32, f32) {
@@ -37,10 +16,17 @@ fn ball_physics(mut pos: f32, mut vel: f32, bounds: (f32, f32)) -> (f32, f32) {
     (pos, vel * DRAG)
 }
 
+// Rust: Lobbed balls physics (not in the source project); the ball can't be acquired while it's higher
+// than the catch height.
+const GRAVITY: f32 = 0.15;
+const CATCH_HEIGHT: f32 = 8.;
+const MAX_LOB_FRAMES: u16 = 60;
+
 //# Work out number of physics steps for ball to travel given distance
-fn steps(mut distance: f32) -> u16 {
+// Rust: The initial velocity depends on the kick power.
+fn steps(mut distance: f32, strength: f32) -> u16 {
     //# Initialize step count and initial velocity
-    let (mut steps, mut vel) = (0, KICK_STRENGTH);
+    let (mut steps, mut vel) = (0, strength);
 
     //# Run physics until distance reached or ball is nearly stopped
     while distance > 0. && vel > 0.25 {
@@ -64,24 +50,10 @@ fn targetable(
 
     //# If source player is on a computer-controlled team, avoid passes which are likely to be intercepted
     //# (If source is player-controlled, that's the player's job)
-    if !teams[source.team as usize].human() {
-        //# For each player p
-        for p in players_pool.iter() {
-            //# Find normalised vector v1 and distance d1 from source to p
-            let (v1, d1) = safe_normalise(&(p.vpos - source.vpos));
-
-            //# If p is on the other team, and between source and target, and at a similiar
-            //# angular position, target is not a good target
-            //# Multiplying two vectors together invokes an operation known as dot product. It is calculated by
-            //# multiplying the X components of each vector, then multiplying the Y components, then adding the two
-            //# resulting numbers. When each of the input vectors is a unit vector (i.e. with a length of 1, as returned
-            //# from the safe_normalise function), the result of which is a number between -1 and 1. In this case we use
-            //# the result to determine whether player 'p' (vector v1) is in roughly the same direction as player 'target'
-            //# (vector v0), from the point of view of player 'source'.
-            if p.team != target.team() && d1 > 0. && d1 < d0 && v0.dot(&v1) > 0.8 {
-                return false;
-            }
-        }
+    if !teams[source.team as usize].human()
+        && opponent_in_the_way(target.vpos(), target.team(), source, players_pool)
+    {
+        return false;
     }
 
     //# If target is on the same team, and ahead of source, and not too far away, and source is facing
@@ -93,6 +65,33 @@ fn targetable(
     target.team() == source.team && d0 > 0. && d0 < 300. && v0.dot(&angle_to_vec(source.dir)) > 0.8
 }
 
+// Rust: Extracted from targetable(), since it's also used to decide whether to lob a pass.
+//
+fn opponent_in_the_way(
+    target_vpos: Vector2<f32>,
+    target_team: u8,
+    source: &Player,
+    players_pool: &Pool<Player>,
+) -> bool {
+    let (v0, d0) = safe_normalise(&(target_vpos - source.vpos));
+
+    //# For each player p
+    players_pool.iter().any(|p| {
+        //# Find normalised vector v1 and distance d1 from source to p
+        let (v1, d1) = safe_normalise(&(p.vpos - source.vpos));
+
+        //# If p is on the other team, and between source and target, and at a similiar
+        //# angular position, target is not a good target
+        //# Multiplying two vectors together invokes an operation known as dot product. It is calculated by
+        //# multiplying the X components of each vector, then multiplying the Y components, then adding the two
+        //# resulting numbers. When each of the input vectors is a unit vector (i.e. with a length of 1, as returned
+        //# from the safe_normalise function), the result of which is a number between -1 and 1. In this case we use
+        //# the result to determine whether player 'p' (vector v1) is in roughly the same direction as player 'target'
+        //# (vector v0), from the point of view of player 'source'.
+        p.team != target_team && d1 > 0. && d1 < d0 && v0.dot(&v1) > 0.8
+    })
+}
+
 //# Get average of two numbers; if the difference between the two is less than 1,
 //# snap to the second number. Used in Ball.update()
 fn avg(a: f32, b: f32) -> f32 {
@@ -103,24 +102,29 @@ fn avg(a: f32, b: f32) -> f32 {
     }
 }
 
-fn on_pitch(x: f32, y: f32) -> bool {
+fn on_pitch(x: f32, y: f32, pitch: &Pitch) -> bool {
     //# Only used when dribbling
-    PITCH_RECT.collidepoint(x, y)
-        || GOAL_0_RECT.collidepoint(x, y)
-        || GOAL_1_RECT.collidepoint(x, y)
+    let [goal_0_rect, goal_1_rect] = pitch.goal_rects();
+
+    pitch.rect().collidepoint(x, y)
+        || goal_0_rect.collidepoint(x, y)
+        || goal_1_rect.collidepoint(x, y)
 }
 
-#[my_actor_based]
+#[my_actor_based(img_base = "ball", anchor = Anchor::Center)]
 pub struct Ball {
     pub vel: Vector2<f32>,
     pub owner: Option<Handle<Player>>,
     timer: i32,
     pub shadow: BareActor,
+    // Rust: Height from the ground, and vertical velocity, of lobbed balls.
+    pub height: f32,
+    vz: f32,
 }
 
 impl Ball {
-    pub fn new(graph: &mut Graph) -> Self {
-        let img_base = "ball";
+    pub fn new(pitch: &Pitch) -> Self {
+        let img_base = Self::DEFAULT_IMG_BASE;
         let img_indexes = vec![];
 
         // Placeholders - reset below.
@@ -130,44 +134,48 @@ impl Ball {
         let owner = None;
         let timer = 0;
 
-        let shadow = BareActor::new("balls", None, Anchor::Center, graph);
-        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+        let shadow = BareActor::new("balls", None, Anchor::Center);
 
         let mut instance = Self {
             img_base,
             img_indexes,
             vpos,
-            anchor: Anchor::Center,
+            anchor: Self::DEFAULT_ANCHOR,
             vel,
             owner,
             timer,
             shadow,
-            rectangle_h,
+            height: 0.,
+            vz: 0.,
         };
 
-        instance.reset();
+        instance.reset(pitch);
 
         instance
     }
 
-    pub fn reset(&mut self) {
-        self.vpos = Vector2::new(HALF_LEVEL_W, HALF_LEVEL_H);
+    pub fn reset(&mut self, pitch: &Pitch) {
+        self.vpos = Vector2::new(pitch.half_level_w, pitch.half_level_h);
         self.vel = Vector2::new(0.0, 0.0);
         self.owner = None;
         self.timer = 0;
+        self.height = 0.;
+        self.vz = 0.;
     }
 
     //# Check for collision with player p
     fn collide(&self, p: &Player) -> bool {
         //# The ball collides with p if p's hold-off timer has expired
         //# and it is DRIBBLE_DIST_X or fewer pixels away
-        p.timer < 0 && (p.vpos - self.vpos).norm() <= DRIBBLE_DIST_X
+        // Rust: Lobbed balls can't be acquired until they're low enough.
+        p.timer < 0 && self.height < CATCH_HEIGHT && (p.vpos - self.vpos).norm() <= DRIBBLE_DIST_X
     }
 
     // We can't pass `&mut game.ball` and `&mut game` at the same time, so we just just make this a
     // function, and call it a day :)
-    pub fn update(game: &mut Game, input: &InputController, scene: &mut Scene, media: &Media) {
+    pub fn update(game: &mut Game) {
         let ball = &mut game.ball;
+        let pitch = &game.pitch;
         ball.timer -= 1;
 
         //# If the ball has an owner, it's being dribbled, so its position is
@@ -184,7 +192,7 @@ impl Ball {
             let new_x = avg(ball.vpos.x, owner.vpos.x + DRIBBLE_DIST_X * sin(owner.dir));
             let new_y = avg(ball.vpos.y, owner.vpos.y - DRIBBLE_DIST_Y * cos(owner.dir));
 
-            if on_pitch(new_x, new_y) {
+            if on_pitch(new_x, new_y, pitch) {
                 //# New position is on the pitch, so update
                 ball.vpos = Vector2::new(new_x, new_y);
             } else {
@@ -204,23 +212,32 @@ impl Ball {
             //# If ball is vertically inside the goal, it can only go as far as the
             //# sides of the goal - otherwise it can go all the way to the sides of
             //# the pitch
-            let bounds_x = if (ball.vpos.y - HALF_LEVEL_H).abs() > HALF_PITCH_H {
-                GOAL_BOUNDS_X
+            let bounds_x = if (ball.vpos.y - pitch.half_level_h).abs() > pitch.half_pitch_h {
+                pitch.goal_bounds_x()
             } else {
-                PITCH_BOUNDS_X
+                pitch.bounds_x()
             };
 
             //# If ball is horizontally inside the goal, it can go all the way to
             //# the back of the net - otherwise it can only go up to the end of
             //# the pitch
-            let bounds_y = if (ball.vpos.x - HALF_LEVEL_W).abs() < HALF_GOAL_W {
-                GOAL_BOUNDS_Y
+            let bounds_y = if (ball.vpos.x - pitch.half_level_w).abs() < HALF_GOAL_W {
+                pitch.goal_bounds_y()
             } else {
-                PITCH_BOUNDS_Y
+                pitch.bounds_y()
             };
 
             (ball.vpos.x, ball.vel.x) = ball_physics(ball.vpos.x, ball.vel.x, bounds_x);
             (ball.vpos.y, ball.vel.y) = ball_physics(ball.vpos.y, ball.vel.y, bounds_y);
+
+            if ball.height > 0. || ball.vz > 0. {
+                ball.vz -= GRAVITY;
+                ball.height = (ball.height + ball.vz).max(0.);
+
+                if ball.height == 0. {
+                    ball.vz = 0.;
+                }
+            }
         }
 
         //# Update shadow position to track ball
@@ -246,11 +263,16 @@ impl Ball {
 
                 //# Set hold-off timer (dependent on difficulty) to limit rate at which
                 //# computer-controlled players can pass the ball
-                ball.timer = game.difficulty.holdoff_timer as i32;
+                ball.timer = game.teams[target.team as usize].difficulty.holdoff_timer as i32;
 
                 //# Update owner, and controllable player for player's team, to player
                 ball.owner = Some(game.pools.players.handle_of(target));
                 game.teams[target.team as usize].active_control_player = ball.owner;
+
+                ball.height = 0.;
+                ball.vz = 0.;
+
+                game.stats.acquired(target.team);
             }
         }
 
@@ -263,6 +285,14 @@ impl Ball {
             let ball_owner = game.pools.players.borrow(owner_h);
             let team = &game.teams[ball_owner.team as usize];
 
+            // Rust: Human kicks (not in the source project) happen when the control is released; a shot
+            // targets only the goal, and a pass only the teammates.
+            let human_kick = if team.human() {
+                team.kick_released()
+            } else {
+                None
+            };
+
             //# Find the closest targetable player or goal (could be None)
             //# First we create a list of all players/goals which can be targeted
 
@@ -271,7 +301,8 @@ impl Ball {
                 .players
                 .iter()
                 .filter(|p| {
-                    p.team == ball_owner.team
+                    human_kick != Some(Kick::Shoot)
+                        && p.team == ball_owner.team
                         && targetable(*p, ball_owner, &game.teams, &game.pools.players)
                 })
                 .map(|p| TargetHandle::Player(game.pools.players.handle_of(p)))
@@ -282,7 +313,8 @@ impl Ball {
                     .goals
                     .iter()
                     .filter(|p| {
-                        p.team() == ball_owner.team
+                        human_kick != Some(Kick::Pass)
+                            && p.team() == ball_owner.team
                             && targetable(*p, ball_owner, &game.teams, &game.pools.players)
                     })
                     .map(|p| TargetHandle::Goal(game.pools.goals.handle_of(p))),
@@ -299,13 +331,14 @@ impl Ball {
                         ball_owner.vpos,
                     )
                 })
-                //game.debug_shoot_target = target.vpos
             } else {
                 None
             };
+            game.debug_shoot_target = target.map(|target| target.load(&game.pools).vpos());
+
             let do_shoot = if team.human() {
                 //# If the owner is player-controlled, we kick if the player hits their kick key
-                team.controls.as_ref().unwrap().shoot(input)
+                human_kick.is_some()
             } else {
                 //# If the owner is computer-controlled, we kick if the ball's hold-off timer has expired
                 //# and there is a targetable player or goal, and the targetable player or goal is in a more
@@ -314,9 +347,18 @@ impl Ball {
                     cost(
                         target.load(&game.pools).vpos(),
                         ball_owner.team,
+                        team.end,
                         0,
                         &game.pools.players,
-                    ) < cost(ball_owner.vpos, ball_owner.team, 0, &game.pools.players)
+                        pitch,
+                    ) < cost(
+                        ball_owner.vpos,
+                        ball_owner.team,
+                        team.end,
+                        0,
+                        &game.pools.players,
+                        pitch,
+                    )
                 );
 
                 ball.timer <= 0 && any_suitable_target
@@ -325,9 +367,15 @@ impl Ball {
             if do_shoot {
                 //# play a random kick effect
 
-                media.play_sound(scene, "goal", &[thread_rng().gen_range(0..2)]);
+                game.sounds.push(("goal", thread_rng().gen_range(0..2)));
                 // game.play_sound("kick", 4);
 
+                if let Some(TargetHandle::Player(_)) = target {
+                    game.stats.passed(ball_owner.team);
+                }
+
+                let strength = KICK_STRENGTH * team.kick_power();
+
                 // Initialize to a phony; the compiler (appropriately) thinks that can be left uninitialized.
                 let mut vek = Vector2::zero();
 
@@ -343,6 +391,7 @@ impl Ball {
                     //# so do not apply lead, as there are no keys being held down and goals don't move.
 
                     let mut r = 0.;
+                    let mut distance = 0.;
 
                     //# Decide how many times we're going to go through the loop - the more times, the more accurate
                     let iterations = if team.human() && target.is_player() {
@@ -360,11 +409,27 @@ impl Ball {
                         //# Get direction vector and distance between target pos and us
                         let (vek_copy, length) = safe_normalise(&(t - ball.vpos));
                         vek = vek_copy;
+                        distance = length;
 
                         //# The steps function works out the number of physics steps the ball will take to travel
                         //# the given distance
                         //# todo r
-                        r = HUMAN_PLAYER_WITHOUT_BALL_SPEED * steps(length) as f32
+                        r = HUMAN_PLAYER_WITHOUT_BALL_SPEED * steps(length, strength) as f32
+                    }
+
+                    // Rust: A human pass with an opponent in the way is lobbed, so that it comes down
+                    // around the target.
+                    let target_vpos = target.load(&game.pools).vpos();
+                    if human_kick == Some(Kick::Pass)
+                        && opponent_in_the_way(
+                            target_vpos,
+                            ball_owner.team,
+                            ball_owner,
+                            &game.pools.players,
+                        )
+                    {
+                        let flight_frames = steps(distance, strength).min(MAX_LOB_FRAMES);
+                        ball.vz = GRAVITY * flight_frames as f32 / 2.;
                     }
 
                     *target
@@ -398,7 +463,7 @@ impl Ball {
                 ball_owner.timer = 10; //# Owner can't regain the ball for at least 10 frames
 
                 //# Set velocity
-                ball.vel = vek * KICK_STRENGTH;
+                ball.vel = vek * strength;
 
                 //# We no longer have an owner
                 ball.owner = None
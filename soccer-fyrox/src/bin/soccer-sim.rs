@@ -0,0 +1,80 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+// Plays CPU-vs-CPU matches without the engine (no window, scene or audio), at each difficulty level,
+// and reports the averages. Useful for tuning the AI.
+//
+// Usage: `soccer-sim [matches per difficulty] [half length, in seconds] [pitch]`, where the pitch is
+// `standard` or `small` (see Pitch).
+
+use std::env;
+
+use soccer_fyrox::prelude::*;
+
+const DEFAULT_MATCHES: u32 = 10;
+const DEFAULT_HALF_LENGTH: u32 = 120;
+const DEFAULT_PITCH: &str = "standard";
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let matches = args
+        .next()
+        .map(|arg| arg.parse().expect("Invalid number of matches"))
+        .unwrap_or(DEFAULT_MATCHES);
+    let half_length = args
+        .next()
+        .map(|arg| arg.parse().expect("Invalid half length"))
+        .unwrap_or(DEFAULT_HALF_LENGTH);
+    let pitch_name = args.next().unwrap_or_else(|| String::from(DEFAULT_PITCH));
+    let pitch = Pitch::parse(&pitch_name).expect("Invalid pitch");
+
+    assert!(
+        matches > 0 && half_length > 0,
+        "The number of matches and the half length must be positive"
+    );
+
+    println!(
+        "{matches} matches per difficulty, halves of {half_length}\", {pitch_name} pitch ({} players per team)",
+        pitch.players_per_team
+    );
+
+    for difficulty in 0..DIFFICULTY.len() as u8 {
+        let mut goals = [0_u32; 2];
+        let mut totals = MatchStats::new();
+
+        for _ in 0..matches {
+            let (scores, stats) = play_match(difficulty, half_length, &pitch);
+
+            for team in 0..2 {
+                goals[team] += scores[team] as u32;
+            }
+            totals.accumulate(&stats);
+        }
+
+        println!();
+        println!("Difficulty {difficulty}:");
+
+        for team in 0..2 {
+            println!(
+                "  Team {team}: {:.2} goals/match, {:.1}% possession, {:.1}% passes completed ({:.1} passes/match)",
+                goals[team as usize] as f32 / matches as f32,
+                totals.possession_ratio(team) * 100.,
+                totals.pass_completion(team) * 100.,
+                totals.passes[team as usize] as f32 / matches as f32,
+            );
+        }
+    }
+}
+
+fn play_match(difficulty: u8, half_length: u32, pitch: &Pitch) -> ([u8; 2], MatchStats) {
+    let game = Game::play_cpu_match(
+        [difficulty; 2],
+        MatchClock::new(half_length, Overtime::None),
+        pitch.clone(),
+    );
+
+    let scores = [game.teams[0].score, game.teams[1].score];
+
+    (scores, game.stats)
+}
@@ -1,59 +1,280 @@
 use crate::prelude::*;
+use crate::storage;
 
+const CONTROLS_FILE: &str = "controls.txt";
+
+// The keys that can be bound; the others (e.g. the function keys, used by the debug overlay, and Escape,
+// used by the controls screen) are left to the system.
+//
+const BINDABLE_KEYS: [VirtualKeyCode; 59] = {
+    use VirtualKeyCode::*;
+
+    [
+        Key1,
+        Key2,
+        Key3,
+        Key4,
+        Key5,
+        Key6,
+        Key7,
+        Key8,
+        Key9,
+        Key0,
+        A,
+        B,
+        C,
+        D,
+        E,
+        F,
+        G,
+        H,
+        I,
+        J,
+        K,
+        L,
+        M,
+        N,
+        O,
+        P,
+        Q,
+        R,
+        S,
+        T,
+        U,
+        V,
+        W,
+        X,
+        Y,
+        Z,
+        Left,
+        Up,
+        Right,
+        Down,
+        Back,
+        Return,
+        Space,
+        Tab,
+        Numpad0,
+        Numpad1,
+        Numpad2,
+        Numpad3,
+        Numpad4,
+        Numpad5,
+        Numpad6,
+        Numpad7,
+        Numpad8,
+        Numpad9,
+        NumpadEnter,
+        LShift,
+        RShift,
+        LControl,
+        RControl,
+    ]
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ControlAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Shoot,
+    // Rust: The source project has a single kick control; this kicks towards a teammate only.
+    Pass,
+}
+
+impl ControlAction {
+    pub const ALL: [Self; 6] = [
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::Shoot,
+        Self::Pass,
+    ];
+
+    // Used both in the config file and on the controls screen.
+    //
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Up => "up",
+            Self::Down => "down",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Shoot => "shoot",
+            Self::Pass => "pass",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Binding {
+    pub key: VirtualKeyCode,
+    // On the gamepad, the movement is also always available via the d-pad and the left stick.
+    pub button: Option<GamepadButton>,
+}
+
+// The binding profile of a player. Each player uses the gamepad with the same number.
+//
+#[derive(Clone)]
 pub struct Controls {
-    key_up: VirtualKeyCode,
-    key_down: VirtualKeyCode,
-    key_left: VirtualKeyCode,
-    key_right: VirtualKeyCode,
-    key_shoot: VirtualKeyCode,
+    // Indexed by ControlAction.
+    bindings: [Binding; 6],
+    gamepad: usize,
 }
 
 impl Controls {
     pub fn new(player_num: u8) -> Self {
+        use GamepadButton::{East, South};
         use VirtualKeyCode::*;
 
-        if player_num == 0 {
-            Self {
-                key_up: Up,
-                key_down: Down,
-                key_left: Left,
-                key_right: Right,
-                key_shoot: Space,
-            }
+        let binding = |key, button| Binding { key, button };
+
+        let bindings = if player_num == 0 {
+            [
+                binding(Up, None),
+                binding(Down, None),
+                binding(Left, None),
+                binding(Right, None),
+                binding(Space, Some(East)),
+                binding(RShift, Some(South)),
+            ]
         } else {
-            Self {
-                key_up: W,
-                key_down: S,
-                key_left: A,
-                key_right: D,
-                key_shoot: LShift,
+            [
+                binding(W, None),
+                binding(S, None),
+                binding(A, None),
+                binding(D, None),
+                binding(LShift, Some(East)),
+                binding(LControl, Some(South)),
+            ]
+        };
+
+        Self {
+            bindings,
+            gamepad: player_num as usize,
+        }
+    }
+
+    // Loads both the profiles; missing or invalid entries keep the defaults.
+    //
+    // The format is one line per binding, `p<n>_<action> = <key> [; <button>]`, e.g. `p1_shoot = Space ; East`.
+    //
+    pub fn load_profiles() -> [Self; 2] {
+        let mut profiles = [Self::new(0), Self::new(1)];
+
+        if let Some(config) = storage::read(CONTROLS_FILE) {
+            for line in config.lines() {
+                if let Some((name, inputs)) = line.split_once('=') {
+                    let (key, button) = inputs.split_once(';').unwrap_or((inputs, ""));
+
+                    for (player_num, profile) in profiles.iter_mut().enumerate() {
+                        for action in ControlAction::ALL {
+                            if name.trim() == Self::config_name(player_num, action) {
+                                let binding = &mut profile.bindings[action as usize];
+
+                                if let Some(key) = Self::parse_key(key.trim()) {
+                                    binding.key = key;
+                                }
+                                binding.button = GamepadButton::parse(button.trim());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        profiles
+    }
+
+    pub fn save_profiles(profiles: &[Self; 2]) {
+        let mut config = String::new();
+
+        for (player_num, profile) in profiles.iter().enumerate() {
+            for action in ControlAction::ALL {
+                let binding = profile.binding(action);
+
+                config.push_str(&format!(
+                    "{} = {:?}",
+                    Self::config_name(player_num, action),
+                    binding.key
+                ));
+                if let Some(button) = binding.button {
+                    config.push_str(&format!(" ; {}", button.name()));
+                }
+                config.push('\n');
             }
         }
+
+        storage::write(CONTROLS_FILE, &config);
+    }
+
+    pub fn binding(&self, action: ControlAction) -> Binding {
+        self.bindings[action as usize]
+    }
+
+    // Returns false if the key can't be bound.
+    //
+    pub fn bind_key(&mut self, action: ControlAction, key: VirtualKeyCode) -> bool {
+        let bindable = BINDABLE_KEYS.contains(&key);
+
+        if bindable {
+            self.bindings[action as usize].key = key;
+        }
+
+        bindable
+    }
+
+    pub fn bind_button(&mut self, action: ControlAction, button: GamepadButton) {
+        self.bindings[action as usize].button = Some(button);
     }
 
     // We could, in theory, store an &input reference in the struct, however, that would pollute the
     // types with lifetimes.
     //
-    // Can't name `move`, which is a reserved keyword.
+    // Rust: In the source project, the controls are queried by the simulation (via `move()` and
+    // `shoot()`); here, they're sampled once per frame.
     //
-    pub fn move_player(&self, speed: f32, input: &InputController) -> Vector2<f32> {
+    pub fn team_input(&self, input: &InputController) -> TeamInput {
+        use ControlAction::*;
+
+        let key_pressed = |action| input.is_key_pressed(self.binding(action).key);
+        let button_pressed = |action| matches!(self.binding(action).button, Some(button) if input.is_button_pressed(self.gamepad, button));
+        let pressed = |action| key_pressed(action) || button_pressed(action);
+
         let (mut dx, mut dy) = (0., 0.);
 
-        if input.is_key_pressed(self.key_left) {
+        if pressed(Left) {
             dx = -1.;
-        } else if input.is_key_pressed(self.key_right) {
+        } else if pressed(Right) {
             dx = 1.;
         }
-        if input.is_key_pressed(self.key_up) {
+        if pressed(Up) {
             dy = -1.;
-        } else if input.is_key_pressed(self.key_down) {
+        } else if pressed(Down) {
             dy = 1.;
         }
 
-        Vector2::new(dx, dy) * speed
+        let mut movement = Vector2::new(dx, dy);
+
+        if movement == Vector2::zero() {
+            movement = input.gamepad_direction(self.gamepad);
+        }
+
+        TeamInput {
+            movement,
+            shoot: pressed(Shoot),
+            pass: pressed(Pass),
+        }
+    }
+
+    fn config_name(player_num: usize, action: ControlAction) -> String {
+        format!("p{}_{}", player_num + 1, action.name())
     }
 
-    pub fn shoot(&self, input: &InputController) -> bool {
-        input.is_key_just_pressed(self.key_shoot)
+    fn parse_key(name: &str) -> Option<VirtualKeyCode> {
+        BINDABLE_KEYS
+            .into_iter()
+            .find(|key| format!("{:?}", key) == name)
     }
 }
@@ -0,0 +1,144 @@
+use fyrox::{
+    core::color::Color,
+    gui::{border::BorderBuilder, brush::Brush, widget::WidgetBuilder},
+};
+
+use crate::prelude::*;
+
+// Rust: The screen for rebinding the controls, which are fixed in the source project.
+
+const BACKGROUND_COLOR: Color = Color::from_rgba(0, 0, 0, 200);
+const FONT_SIZE: f32 = 20.;
+const TEXT_Y: f32 = 40.;
+
+pub struct ControlsScreen {
+    background_h: Handle<UiNode>,
+    text_h: Handle<UiNode>,
+    // Index of the player * the number of actions, plus the action index.
+    selected: usize,
+    // Waiting for the key (or button) to bind to the selected action.
+    rebinding: bool,
+}
+
+impl ControlsScreen {
+    // Doesn't display the screen or perform any instantiation.
+    //
+    pub fn new() -> Self {
+        Self {
+            background_h: Handle::NONE,
+            text_h: Handle::NONE,
+            selected: 0,
+            rebinding: false,
+        }
+    }
+
+    pub fn display(&mut self, profiles: &[Controls; 2], user_interface: &mut UserInterface) {
+        self.selected = 0;
+        self.rebinding = false;
+
+        self.text_h = add_text_widget_node(0., TEXT_Y, WIDTH, FONT_SIZE, user_interface);
+
+        self.background_h = BorderBuilder::new(
+            WidgetBuilder::new()
+                .with_width(WIDTH)
+                .with_height(HEIGHT)
+                .with_background(Brush::Solid(BACKGROUND_COLOR))
+                .with_foreground(Brush::Solid(Color::TRANSPARENT))
+                .with_child(self.text_h),
+        )
+        .build(&mut user_interface.build_ctx());
+
+        self.refresh(profiles, user_interface);
+    }
+
+    // Returns true when the player leaves the screen; the bindings are saved on each change.
+    //
+    pub fn update(
+        &mut self,
+        input: &InputController,
+        profiles: &mut [Controls; 2],
+        user_interface: &mut UserInterface,
+    ) -> bool {
+        use VirtualKeyCode::*;
+
+        let entries_count = profiles.len() * ControlAction::ALL.len();
+
+        let (player_num, action) = (
+            self.selected / ControlAction::ALL.len(),
+            ControlAction::ALL[self.selected % ControlAction::ALL.len()],
+        );
+
+        if self.rebinding {
+            if input.is_key_just_pressed(Escape) {
+                self.rebinding = false;
+            } else if let Some(key) = input.just_pressed_key() {
+                // Keys that can't be bound are ignored.
+                //
+                if profiles[player_num].bind_key(action, key) {
+                    self.rebinding = false;
+                }
+            } else if let Some(button) = input.just_pressed_button() {
+                profiles[player_num].bind_button(action, button);
+                self.rebinding = false;
+            }
+
+            if !self.rebinding {
+                Controls::save_profiles(profiles);
+                self.refresh(profiles, user_interface);
+            }
+        } else if input.is_key_just_pressed(Escape) {
+            return true;
+        } else if input.is_key_just_pressed(Space) || input.is_key_just_pressed(Return) {
+            self.rebinding = true;
+            self.refresh(profiles, user_interface);
+        } else if input.is_key_just_pressed(Down) {
+            self.selected = (self.selected + 1) % entries_count;
+            self.refresh(profiles, user_interface);
+        } else if input.is_key_just_pressed(Up) {
+            self.selected = (self.selected + entries_count - 1) % entries_count;
+            self.refresh(profiles, user_interface);
+        }
+
+        false
+    }
+
+    pub fn clear(&mut self, user_interface: &mut UserInterface) {
+        // The text is a child of the background, so it's removed with it.
+        //
+        self.background_h = remove_widget_node(self.background_h, user_interface);
+        self.text_h = Handle::NONE;
+    }
+
+    fn refresh(&self, profiles: &[Controls; 2], user_interface: &mut UserInterface) {
+        let mut text = String::from("CONTROLS\n\n");
+
+        for (player_num, profile) in profiles.iter().enumerate() {
+            for (action_i, action) in ControlAction::ALL.into_iter().enumerate() {
+                let selected = self.selected == player_num * ControlAction::ALL.len() + action_i;
+
+                let inputs = if selected && self.rebinding {
+                    String::from("PRESS A KEY OR BUTTON")
+                } else {
+                    let binding = profile.binding(action);
+                    let button = binding
+                        .button
+                        .map_or_else(String::new, |button| format!(" / {}", button.name()));
+
+                    format!("{:?}{}", binding.key, button)
+                };
+
+                let line = format!("P{} {}: {}", player_num + 1, action.name(), inputs);
+
+                if selected {
+                    text.push_str(&format!("> {} <\n", line.to_uppercase()));
+                } else {
+                    text.push_str(&format!("{}\n", line.to_uppercase()));
+                }
+            }
+        }
+
+        text.push_str("\nUP/DOWN: SELECT - SPACE: CHANGE - ESC: BACK");
+
+        update_widget_text(self.text_h, &text, user_interface);
+    }
+}
@@ -0,0 +1,37 @@
+use std::{fs, path::PathBuf};
+
+// Reads the data files under `resources` that aren't media (e.g. the formations). They're made of
+// named sections: a `[name]` line, followed by the section lines; blank lines and `#` comments are
+// skipped.
+
+const RESOURCES_PATH: &str = "resources";
+
+// Returns None if the file can't be read, e.g. when running from another directory.
+//
+pub fn read_sections(paths: &[&str]) -> Option<Vec<(String, Vec<String>)>> {
+    let path = paths
+        .iter()
+        .fold(PathBuf::from(RESOURCES_PATH), |result, current| {
+            result.join(current)
+        });
+
+    let content = fs::read_to_string(&path).ok()?;
+
+    let mut sections = Vec::<(String, Vec<String>)>::new();
+
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((name.trim().to_string(), vec![]));
+        } else if let Some((_, lines)) = sections.last_mut() {
+            lines.push(line.to_string());
+        } else {
+            panic!("Line outside of a section in {}: {}", path.display(), line);
+        }
+    }
+
+    Some(sections)
+}
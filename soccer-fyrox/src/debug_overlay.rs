@@ -0,0 +1,163 @@
+use fyrox::{
+    core::color::Color,
+    scene::debug::{Line, SceneDrawingContext},
+};
+
+use crate::prelude::*;
+
+// Spacing of the cost function samples; same as the source project.
+const COSTS_STEP_X: usize = 60;
+const COSTS_STEP_Y: usize = 26;
+const COSTS_MARKER_SIZE: f32 = 4.;
+
+const LEADS_COLOR: Color = Color::opaque(0, 0, 0);
+const TARGETS_COLOR: Color = Color::opaque(255, 0, 0);
+const PEERS_COLOR: Color = Color::opaque(0, 0, 255);
+const SHOOT_TARGET_COLOR: Color = Color::opaque(255, 0, 255);
+const MARKS_COLOR: Color = Color::opaque(0, 255, 0);
+
+// In the source project, the DEBUG_SHOW_* diagnostics are constants, and drawn as lines/text on the
+// screen surface; here, they're toggled at runtime via the function keys, and drawn via the scene
+// drawing context (which only supports lines, so the costs are drawn as colored markers, from green
+// (lowest) to red (highest), rather than as numbers).
+//
+#[derive(Default)]
+pub struct DebugOverlay {
+    show_leads: bool,
+    show_targets: bool,
+    show_peers: bool,
+    show_shoot_target: bool,
+    show_costs: bool,
+    // Rust: Lines from the players to the opponents they mark; the source overlay has no equivalent.
+    show_marks: bool,
+}
+
+impl DebugOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, input: &InputController) {
+        use VirtualKeyCode::*;
+
+        for (key, flag) in [
+            (F1, &mut self.show_leads),
+            (F2, &mut self.show_targets),
+            (F3, &mut self.show_peers),
+            (F4, &mut self.show_shoot_target),
+            (F5, &mut self.show_costs),
+            (F6, &mut self.show_marks),
+        ] {
+            if input.is_key_just_pressed(key) {
+                *flag = !*flag;
+            }
+        }
+    }
+
+    pub fn draw(&self, game: &Game, drawing_context: &mut SceneDrawingContext) {
+        drawing_context.clear_lines();
+
+        let players = &game.pools.players;
+        let owner = game.ball.owner.map(|owner_h| players.borrow(owner_h));
+
+        if self.show_leads {
+            if let Some(owner) = owner {
+                for p in players.iter().filter(|p| p.lead.is_some()) {
+                    Self::add_line(drawing_context, owner.vpos, p.vpos, LEADS_COLOR);
+                }
+            }
+        }
+
+        if self.show_targets {
+            for p in players.iter() {
+                Self::add_line(drawing_context, p.debug_target, p.vpos, TARGETS_COLOR);
+            }
+        }
+
+        if self.show_peers {
+            for p in players.iter() {
+                let peer = players.borrow(p.peer);
+                Self::add_line(drawing_context, peer.vpos, p.vpos, PEERS_COLOR);
+            }
+        }
+
+        if self.show_shoot_target {
+            if let (Some(owner), Some(shoot_target)) = (owner, game.debug_shoot_target) {
+                Self::add_line(
+                    drawing_context,
+                    owner.vpos,
+                    shoot_target,
+                    SHOOT_TARGET_COLOR,
+                );
+            }
+        }
+
+        if self.show_marks {
+            for p in players.iter() {
+                if !matches!(p.mark, TargetHandle::None) {
+                    let mark_vpos = p.mark.load(&game.pools).vpos();
+                    Self::add_line(drawing_context, mark_vpos, p.vpos, MARKS_COLOR);
+                }
+            }
+        }
+
+        if self.show_costs {
+            if let Some(owner) = owner {
+                let end = game.teams[owner.team as usize].end;
+
+                let costs = (0..game.pitch.level_w as usize)
+                    .step_by(COSTS_STEP_X)
+                    .flat_map(|x| {
+                        (0..game.pitch.level_h as usize)
+                            .step_by(COSTS_STEP_Y)
+                            .map(move |y| Vector2::new(x as f32, y as f32))
+                    })
+                    .map(|pos| cost(pos, owner.team, end, 0, players, &game.pitch))
+                    .collect::<Vec<_>>();
+
+                let min_cost = costs.iter().map(|(c, _)| *c).fold(f32::INFINITY, f32::min);
+                let max_cost = costs
+                    .iter()
+                    .map(|(c, _)| *c)
+                    .fold(f32::NEG_INFINITY, f32::max);
+                let range = (max_cost - min_cost).max(f32::EPSILON);
+
+                for (c, pos) in costs {
+                    let ratio = (c - min_cost) / range;
+                    let color = Color::opaque((255. * ratio) as u8, (255. * (1. - ratio)) as u8, 0);
+                    Self::add_marker(drawing_context, pos, color);
+                }
+            }
+        }
+    }
+
+    fn add_line(
+        drawing_context: &mut SceneDrawingContext,
+        start: Vector2<f32>,
+        end: Vector2<f32>,
+        color: Color,
+    ) {
+        drawing_context.add_line(Line {
+            begin: Self::to_fyrox_coordinates(start),
+            end: Self::to_fyrox_coordinates(end),
+            color,
+        });
+    }
+
+    // A cross.
+    //
+    fn add_marker(drawing_context: &mut SceneDrawingContext, pos: Vector2<f32>, color: Color) {
+        let (dx, dy) = (
+            Vector2::new(COSTS_MARKER_SIZE, 0.),
+            Vector2::new(0., COSTS_MARKER_SIZE),
+        );
+        Self::add_line(drawing_context, pos - dx, pos + dx, color);
+        Self::add_line(drawing_context, pos - dy, pos + dy, color);
+    }
+
+    // Same transformation as to_fyrox_coordinates(), for points.
+    //
+    fn to_fyrox_coordinates(pos: Vector2<f32>) -> Vector3<f32> {
+        Vector3::new(WIDTH / 2. - pos.x, HEIGHT / 2. - pos.y, DRAW_DEBUG_Z)
+    }
+}
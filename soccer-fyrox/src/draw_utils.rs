@@ -1,9 +1,14 @@
 use fyrox::{
+    core::color::Color,
     gui::{
+        border::BorderBuilder,
+        brush::Brush,
         image::{Image, ImageBuilder},
         message::MessageDirection,
+        text::{TextBuilder, TextMessage},
+        ttf::{FontBuilder, SharedFont},
         widget::{WidgetBuilder, WidgetMessage},
-        {UiNode, UserInterface},
+        HorizontalAlignment, {UiNode, UserInterface},
     },
     resource::texture::{Texture, TextureKind},
     utils::into_gui_texture,
@@ -88,6 +93,76 @@ pub fn update_widget_texture(
     }
 }
 
+// There are no images for arbitrary text (e.g. the match clock), so the Fyrox built-in font is used.
+// The text is centered horizontally in the given width.
+//
+pub fn add_text_widget_node(
+    x: f32,
+    y: f32,
+    width: f32,
+    font_size: f32,
+    user_interface: &mut UserInterface,
+) -> Handle<UiNode> {
+    let font = FontBuilder::new()
+        .with_height(font_size)
+        .build_builtin()
+        .unwrap();
+
+    TextBuilder::new(
+        WidgetBuilder::new()
+            .with_desired_position(Vector2::new(x, y))
+            .with_width(width),
+    )
+    .with_font(SharedFont::new(font))
+    .with_horizontal_text_alignment(HorizontalAlignment::Center)
+    .build(&mut user_interface.build_ctx())
+}
+
+pub fn update_widget_text(
+    widget_h: Handle<UiNode>,
+    text: &str,
+    user_interface: &mut UserInterface,
+) {
+    user_interface.send_message(TextMessage::text(
+        widget_h,
+        MessageDirection::ToWidget,
+        text.to_string(),
+    ));
+}
+
+// A solid rectangle, e.g. the kick power meter; its width can be changed via update_widget_width().
+//
+pub fn add_bar_widget_node(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    color: Color,
+    user_interface: &mut UserInterface,
+) -> Handle<UiNode> {
+    BorderBuilder::new(
+        WidgetBuilder::new()
+            .with_desired_position(Vector2::new(x, y))
+            .with_width(width)
+            .with_height(height)
+            .with_background(Brush::Solid(color))
+            .with_foreground(Brush::Solid(Color::TRANSPARENT)),
+    )
+    .build(&mut user_interface.build_ctx())
+}
+
+pub fn update_widget_width(
+    widget_h: Handle<UiNode>,
+    width: f32,
+    user_interface: &mut UserInterface,
+) {
+    user_interface.send_message(WidgetMessage::width(
+        widget_h,
+        MessageDirection::ToWidget,
+        width,
+    ));
+}
+
 // For convenience, returns Handle::NONE.
 //
 pub fn remove_widget_node(
@@ -0,0 +1,25 @@
+// A league match; the teams are indexes in League::teams.
+//
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Fixture {
+    pub round: u8,
+    pub home: usize,
+    pub away: usize,
+    // Home and away scores, once played.
+    pub result: Option<(u8, u8)>,
+}
+
+impl Fixture {
+    pub fn new(round: u8, home: usize, away: usize) -> Self {
+        Self {
+            round,
+            home,
+            away,
+            result: None,
+        }
+    }
+
+    pub fn involves(&self, team: usize) -> bool {
+        self.home == team || self.away == team
+    }
+}
@@ -0,0 +1,97 @@
+use crate::data_file;
+use crate::prelude::*;
+
+// Rust: In the source project, the home positions are fixed (see PLAYER_START_POS).
+
+const FORMATIONS_FILE: &[&str] = &["tactics", "formations.txt"];
+
+// The default formation of each supported team size; the source one is the seven-a-side.
+const DEFAULT_FORMATIONS: [(&str, &[(f32, f32)]); 3] = [
+    (
+        "2-1-2",
+        &[
+            (350., 550.),
+            (650., 550.),
+            (500., 850.),
+            (350., 1200.),
+            (650., 1200.),
+        ],
+    ),
+    (
+        "2-2-2",
+        &[
+            (350., 550.),
+            (650., 550.),
+            (300., 850.),
+            (700., 850.),
+            (350., 1200.),
+            (650., 1200.),
+        ],
+    ),
+    ("2-3-2", &PLAYER_START_POS),
+];
+
+#[derive(Clone)]
+pub struct Formation {
+    pub name: String,
+    // Home positions on the standard pitch (see Pitch), for the team attacking the top goal; the
+    // first player kicks off.
+    pub positions: Vec<(f32, f32)>,
+}
+
+impl Formation {
+    // The formations in the data file for the given team size; if there are none, only the default
+    // one.
+    //
+    pub fn load_all(players_per_team: usize) -> Vec<Self> {
+        let formations = data_file::read_sections(FORMATIONS_FILE)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, lines)| Self::parse(name, &lines))
+            .filter(|formation| formation.positions.len() == players_per_team)
+            .collect::<Vec<_>>();
+
+        if formations.is_empty() {
+            vec![Self::default_for(players_per_team)]
+        } else {
+            formations
+        }
+    }
+
+    pub fn supported(players_per_team: usize) -> bool {
+        DEFAULT_FORMATIONS
+            .iter()
+            .any(|(_, positions)| positions.len() == players_per_team)
+    }
+
+    pub fn default_for(players_per_team: usize) -> Self {
+        let (name, positions) = DEFAULT_FORMATIONS
+            .iter()
+            .find(|(_, positions)| positions.len() == players_per_team)
+            .unwrap_or_else(|| {
+                panic!("Unsupported number of players per team: {players_per_team}")
+            });
+
+        Self {
+            name: name.to_string(),
+            positions: positions.to_vec(),
+        }
+    }
+
+    fn parse(name: String, lines: &[String]) -> Self {
+        let positions = lines
+            .iter()
+            .map(|line| {
+                line.split_once(',')
+                    .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)))
+                    .unwrap_or_else(|| panic!("Invalid position in formation {name}: {line}"))
+            })
+            .collect::<Vec<_>>();
+
+        if positions.is_empty() {
+            panic!("Formation {name} must have at least one position");
+        }
+
+        Self { name, positions }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 pub const DEFAULT_DIFFICULTY: u8 = 2;
+// Rust: The home positions of the default formation of the standard pitch; see Formation and Pitch.
 pub const PLAYER_START_POS: [(f32, f32); 7] = [
     (350., 550.),
     (650., 450.),
@@ -14,37 +15,40 @@ pub const PLAYER_START_POS: [(f32, f32); 7] = [
 pub const LEAD_DISTANCE_1: f32 = 10.;
 pub const LEAD_DISTANCE_2: f32 = 50.;
 
-//DEBUG_SHOW_LEADS = False
-//DEBUG_SHOW_TARGETS = False
-//DEBUG_SHOW_PEERS = False
-//DEBUG_SHOW_SHOOT_TARGET = False
-//DEBUG_SHOW_COSTS = False
-
 pub struct Game {
     pub teams: Vec<Team>,
-    pub difficulty: Difficulty,
     pub score_timer: i32,
     scoring_team: u8,
     players: Vec<Handle<Player>>,
-    goals: Vec<Handle<Goal>>,
+    pub goals: Vec<Handle<Goal>>,
     pub kickoff_player: Option<Handle<Player>>,
     pub ball: Ball,
-    arrows: Vec<Option<BareActor>>,
-    camera_focus: Vector2<f32>,
+    pub camera_focus: Vector2<f32>,
+    pub clock: MatchClock,
+    // Rust: The geometry and the team size are match settings.
+    pub pitch: Pitch,
+    pub stats: MatchStats,
+    pub replay: Replay,
+    // Rust: The simulation doesn't play sounds; the sounds of the last update, as (base, index), are
+    // played by GameGlobal.
+    pub sounds: Vec<(&'static str, u8)>,
+    // Used when the shoot target debug overlay is on.
+    pub debug_shoot_target: Option<Vector2<f32>>,
 
     pub pools: Pools,
 }
 
 impl Game {
+    // Rust: The scene objects (pitch, sprites and arrows) are handled by GameView.
+    //
     pub fn new(
-        p1_controls: Option<Controls>,
-        p2_controls: Option<Controls>,
+        p1_human: bool,
+        p2_human: bool,
         difficulty: u8,
-        scene: &mut Scene,
-        media: &mut Media,
+        clock: MatchClock,
+        pitch: Pitch,
     ) -> Self {
         let teams = vec![];
-        let placeholder_difficulty = DIFFICULTY[difficulty as usize];
 
         let score_timer = 0;
         let scoring_team = 1;
@@ -53,22 +57,18 @@ impl Game {
 
         // The players are reset below.
         // Watch out! The team *must* be set, preferrably here.
-        let players = PLAYER_START_POS
-            .iter()
-            .flat_map(|(_, _)| {
+        let players = (0..pitch.players_per_team)
+            .flat_map(|_| {
                 [
-                    pools
-                        .players
-                        .spawn(Player::new(0., 0., 0, &mut scene.graph)),
-                    pools
-                        .players
-                        .spawn(Player::new(0., 0., 1, &mut scene.graph)),
+                    pools.players.spawn(Player::new(0., 0., 0, &pitch)),
+                    pools.players.spawn(Player::new(0., 0., 1, &pitch)),
                 ]
             })
             .collect::<Vec<_>>();
         //# Players in the list are stored in an alternating fashion - a team 0 player, then a team 1 player, and so on.
         //# The peer for each player is the opposing team player at the opposite end of the list. As there are 14 players
         //# in total, the peers are 0 and 13, 1 and 12, 2 and 11, and so on.
+        // Rust: The number of players depends on the team size; the pairing is the same.
         for (a, b) in players.iter().zip(players.iter().rev()) {
             pools.players.borrow_mut(*a).peer = *b;
         }
@@ -76,78 +76,99 @@ impl Game {
         //# Create two goals
         let goals = (0..2)
             .into_iter()
-            .map(|i| pools.goals.spawn(Goal::new(i, &mut scene.graph)))
+            .map(|i| pools.goals.spawn(Goal::new(i, &pitch)))
             .collect();
 
         let kickoff_player = None;
 
         //# Create ball
-        let ball = Ball::new(&mut scene.graph);
-
-        let arrows = vec![None, None];
+        let ball = Ball::new(&pitch);
 
         //# Focus camera on ball - copy ball pos
         let camera_focus = ball.vpos;
 
         let mut instance = Self {
             teams,
-            difficulty: placeholder_difficulty,
             score_timer,
             scoring_team,
             players,
             goals,
             kickoff_player,
             ball,
-            arrows,
             camera_focus,
+            clock,
+            pitch,
+            stats: MatchStats::new(),
+            replay: Replay::new(),
+            sounds: vec![],
+            debug_shoot_target: None,
             pools,
         };
 
-        instance.reset_game(p1_controls, p2_controls, difficulty, scene, media);
-
-        // The pitch is always present, so we draw it only once.
-        add_image_node(
-            media,
-            scene,
-            "pitch",
-            &[],
-            0.,
-            0.,
-            DRAW_PITCH_Z,
-            Anchor::TopLeft,
-        );
+        let tactics = [0, 1].map(|_| TeamTactics::default_for(instance.pitch.players_per_team));
+        instance.reset_game(p1_human, p2_human, difficulty, tactics);
 
         instance
     }
 
+    // Plays a whole CPU-vs-CPU match, without the engine, and returns the game at full time. The clock
+    // must be timed, otherwise the match never ends.
+    //
+    pub fn play_cpu_match(difficulties: [u8; 2], clock: MatchClock, pitch: Pitch) -> Self {
+        let mut game = Self::new(false, false, difficulties[0], clock, pitch);
+
+        for (team, difficulty) in game.teams.iter_mut().zip(difficulties) {
+            team.difficulty = DIFFICULTY[difficulty as usize];
+        }
+
+        // The clock doesn't run by default without human players (see MatchClock::reset()).
+        //
+        game.clock.reset(true);
+
+        let inputs = [TeamInput::default(); 2];
+
+        while !game.clock.finished() {
+            game.update(&inputs);
+        }
+
+        game
+    }
+
+    // Rust: The music is handled by GameGlobal. The formations and tactics are not in the source project.
+    //
     pub fn reset_game(
         &mut self,
-        p1_controls: Option<Controls>,
-        p2_controls: Option<Controls>,
+        p1_human: bool,
+        p2_human: bool,
         difficulty: u8,
-        scene: &mut Scene,
-        media: &mut Media,
+        tactics: [TeamTactics; 2],
     ) {
-        self.teams = vec![Team::new(p1_controls), Team::new(p2_controls)];
-
-        self.difficulty = DIFFICULTY[difficulty as usize];
-
-        if self.teams[0].human() {
-            //# Beginning a game with at least 1 human player
-            //# music.fadeout(1); // WRITEME: Fyrox doesn't currently support fading out
-            media.stop_looping_sound(scene, "theme"); // ^^ remove once fadeout is implemented
-            media.play_looping_sound(scene, "crowd");
-            media.play_sound(scene, "start", &[]);
-        } else {
-            //# No players - we must be on the menu. Play title music.
-            media.stop_looping_sound(scene, "crowd");
-            media.play_looping_sound(scene, "theme");
+        assert!(
+            tactics
+                .iter()
+                .all(|t| t.formation.positions.len() == self.pitch.players_per_team),
+            "The formations must have {} positions",
+            self.pitch.players_per_team
+        );
+
+        let [p1_tactics, p2_tactics] = tactics;
+        let difficulty = DIFFICULTY[difficulty as usize];
+        self.teams = vec![
+            Team::new(p1_human, 0, p1_tactics, difficulty),
+            Team::new(p2_human, 1, p2_tactics, difficulty),
+        ];
+
+        for (i, goal_h) in self.goals.iter().enumerate() {
+            self.pools.goals.borrow_mut(*goal_h).team = i as u8;
         }
 
-        self.reset_field(&mut scene.graph);
+        self.clock.reset(self.teams[0].human());
+        self.stats = MatchStats::new();
+
+        self.reset_field();
     }
 
-    fn reset_field(&mut self, graph: &mut Graph) {
+    fn reset_field(&mut self) {
         //# Set up players list/positions
         //# The lambda function is used to give the player start positions a slight random offset so they're not
         //# perfectly aligned to their starting spots
@@ -163,21 +184,42 @@ impl Game {
         // Watch out! Python's randint() spec is different, as it's inclusive on both ends, so we use
         // 33 on the right end.
         let random_offset = |x| x + rand::thread_rng().gen_range(-32..33) as f32;
-        for (pos, (player0_h, player1_h)) in PLAYER_START_POS.iter().zip(player_couple_hs) {
+        // Rust: The positions are flipped based on the end, since the teams switch ends at half time;
+        // they're also scaled to the pitch size.
+        let pitch = &self.pitch;
+        let home_pos = |pos: &(f32, f32), end: u8| {
+            let (x, y) = pitch.scale_position(*pos);
+
+            if end == 0 {
+                (random_offset(x), random_offset(y))
+            } else {
+                (
+                    random_offset(pitch.level_w - x),
+                    random_offset(pitch.level_h - y),
+                )
+            }
+        };
+        let ends = [self.teams[0].end, self.teams[1].end];
+        // Rust: Each team has its own formation.
+        let formation_positions = self.teams[0]
+            .tactics
+            .formation
+            .positions
+            .iter()
+            .zip(self.teams[1].tactics.formation.positions.iter());
+
+        for ((pos0, pos1), (player0_h, player1_h)) in formation_positions.zip(player_couple_hs) {
             //# pos is a pair of coordinates in a tuple
             //# For each entry in pos, create one player for each team - positions are flipped (both horizontally and
             //# vertically) versions of each other
 
             let (player0, player1) = self.pools.players.borrow_two_mut((*player0_h, *player1_h));
 
-            player0.reset(random_offset(pos.0), random_offset(pos.1), 0, graph);
+            let (x0, y0) = home_pos(pos0, ends[0]);
+            player0.reset(x0, y0, ends[0], pitch);
 
-            player1.reset(
-                random_offset(LEVEL_W - pos.0),
-                random_offset(LEVEL_H - pos.1),
-                1,
-                graph,
-            );
+            let (x1, y1) = home_pos(pos1, ends[1]);
+            player1.reset(x1, y1, ends[1], pitch);
         }
 
         //# The current active player under control by each team, indicated by arrows over their heads
@@ -194,61 +236,125 @@ impl Game {
         self.kickoff_player = Some(self.players[other_team as usize]);
 
         //# Set pos of kickoff player. A team 0 player will stand to the left of the ball, team 1 on the right
+        let kickoff_end = self.teams[other_team as usize].end;
         self.pools
             .players
             .borrow_mut(self.kickoff_player.unwrap())
-            .vpos = Vector2::new(HALF_LEVEL_W - 30. + other_team as f32 * 60., HALF_LEVEL_H);
+            .vpos = Vector2::new(
+            self.pitch.half_level_w - 30. + kickoff_end as f32 * 60.,
+            self.pitch.half_level_h,
+        );
 
         //# Reset ball
-        self.ball.reset();
+        self.ball.reset(&self.pitch);
+        self.stats.play_stopped();
+        self.replay.clear();
 
-        self.arrows = self
-            .arrows
-            .iter()
-            .enumerate()
-            .map(|(i, arrow)| {
-                if let Some(arrow) = arrow {
-                    graph.remove_node(arrow.rectangle_h());
-                }
-
-                //# Only show arrow for human teams
-                self.teams[i]
-                    .human()
-                    .then(|| BareActor::new("arrow", Some(i as u8), Anchor::TopLeft, graph))
-            })
-            .collect();
+        // The arrows are drawn by GameView.
 
         //# Focus camera on ball - copy ball pos
         self.camera_focus = self.ball.vpos;
     }
 
-    pub fn update(&mut self, media: &Media, scene: &mut Scene, input: &InputController) {
+    // Switches the goal attacked by each team, and gives the kickoff to the team that didn't kick off
+    // the first half; it happens at half time, and between the halves of extra time.
+    //
+    fn switch_ends(&mut self) {
+        for team in &mut self.teams {
+            team.end = 1 - team.end;
+        }
+
+        for goal_h in &self.goals {
+            let goal = self.pools.goals.borrow_mut(*goal_h);
+            goal.team = 1 - goal.team;
+        }
+
+        self.scoring_team = 0;
+
+        self.reset_field();
+    }
+
+    // The inputs are ignored for computer teams.
+    //
+    pub fn update(&mut self, inputs: &[TeamInput; 2]) {
+        for (team, input) in self.teams.iter_mut().zip(inputs) {
+            team.previous_input = team.input;
+            team.input = if team.human() {
+                *input
+            } else {
+                TeamInput::default()
+            };
+        }
+
+        self.sounds.clear();
+
+        // Rust: During the goal replays, the match is frozen; the replay can be skipped via the shoot
+        // control.
+        if self.replay.playing() {
+            let skipped = self
+                .teams
+                .iter()
+                .any(|t| t.human() && t.input.shoot && !t.previous_input.shoot);
+
+            if skipped || !self.replay.advance() {
+                self.reset_field();
+            }
+
+            return;
+        }
+
         self.score_timer -= 1;
 
         if self.score_timer == 0 {
             //# Reset for new kick-off after goal scored
-            self.reset_field(&mut scene.graph);
-        } else if self.score_timer < 0 && (self.ball.vpos.y - HALF_LEVEL_H).abs() > HALF_PITCH_H {
-            media.play_sound(scene, "goal", &[thread_rng().gen_range(0..2)]);
+            // Rust: After the replay, if there are human players.
+            if !(self.humans_playing() && self.replay.start()) {
+                self.reset_field();
+            }
+        } else if self.score_timer < 0
+            && (self.ball.vpos.y - self.pitch.half_level_h).abs() > self.pitch.half_pitch_h
+        {
+            self.sounds.push(("goal", thread_rng().gen_range(0..2)));
 
-            self.scoring_team = if self.ball.vpos.y < HALF_LEVEL_H {
+            // Rust: The goal is mapped to the team attacking it, since the teams switch ends.
+            let scoring_end = if self.ball.vpos.y < self.pitch.half_level_h {
                 0
             } else {
                 1
             };
+            self.scoring_team = self
+                .teams
+                .iter()
+                .position(|t| t.end == scoring_end)
+                .unwrap() as u8;
             self.teams[self.scoring_team as usize].score += 1;
             self.score_timer = 60; //# Game goes into "scored a goal" state for 60 frames;
+
+            self.clock.goal_scored();
+        }
+
+        let ball_in_play = self.score_timer < 0 && self.kickoff_player.is_none();
+        let scores_level = self.teams[0].score == self.teams[1].score;
+
+        if let Some(Period::HalfTime | Period::ExtraTimeSecondHalf) =
+            self.clock.tick(ball_in_play, scores_level)
+        {
+            self.switch_ends();
+        }
+
+        if self.clock.stopped() {
+            return;
         }
 
         //# Each frame, reset mark and lead of each player
         for b in self.pools.players.iter_mut() {
             b.mark = TargetHandle::Player(b.peer);
             b.lead = None;
-            //b.debug_target = None
+            b.debug_target = b.vpos;
         }
 
         //# Reset debug shoot target
-        //self.debug_shoot_target = None
+        self.debug_shoot_target = None;
 
         if let Some(o) = &self.ball.owner {
             // This part requires considerable BCK gymnastics, because of the multiple borrows; several
@@ -263,9 +369,11 @@ impl Game {
             // Bug here, fixed (was: `other_team = 1 if team == 0 else 1`)
             let other_team = if team == 0 { 1 } else { 0 };
 
-            if self.difficulty.goalie_enabled {
+            if self.teams[other_team].difficulty.goalie_enabled {
+                let owners_target_end = self.teams[team as usize].end;
+
                 let previous_nearest_mark = {
-                    let owners_target_goal_h = self.goals[team as usize];
+                    let owners_target_goal_h = self.goals[owners_target_end as usize];
                     let owners_target_goal_vpos =
                         self.pools.goals.borrow(owners_target_goal_h).vpos;
 
@@ -282,7 +390,7 @@ impl Game {
                     // project, this statement was after).
                     std::mem::replace(
                         &mut nearest.mark,
-                        TargetHandle::Goal(self.goals[team as usize]),
+                        TargetHandle::Goal(self.goals[owners_target_end as usize]),
                     )
                 };
 
@@ -322,7 +430,7 @@ impl Game {
             // The direct translation of the source logic is not trivial in Rust, due to Player
             // not supporting equality, but luckily, the partition() API will do even better :)
             let (a, b): (Vec<_>, Vec<_>) = l.into_iter().partition(|(_, p_vpos)| {
-                if team == 0 {
+                if self.teams[team as usize].end == 0 {
                     p_vpos.y > pos.y
                 } else {
                     p_vpos.y < pos.y
@@ -347,9 +455,11 @@ impl Game {
 
             //# Either one or two players (depending on difficulty settings) follow the ball owner, one from up-field and
             //# one from down-field of the owner
-            self.pools.players.borrow_mut(*zipped[0].0).lead = Some(LEAD_DISTANCE_1);
-            if self.difficulty.second_lead_enabled {
-                self.pools.players.borrow_mut(*zipped[1].0).lead = Some(LEAD_DISTANCE_2);
+            // Rust: The distances depend on the tactic of the chasing team.
+            let lead_distances = self.teams[other_team].tactics.tactic.lead_distances;
+            self.pools.players.borrow_mut(*zipped[0].0).lead = Some(lead_distances[0]);
+            if self.teams[other_team].difficulty.second_lead_enabled {
+                self.pools.players.borrow_mut(*zipped[1].0).lead = Some(lead_distances[1]);
             }
 
             //# If the ball has an owner, kick-off must have taken place, so unset the kickoff player
@@ -360,17 +470,34 @@ impl Game {
 
         //# Update all players and ball
         for obj_h in &self.players.clone() {
-            Player::update(*obj_h, self, input);
+            Player::update(*obj_h, self);
         }
-        Ball::update(self, input, scene, media);
+        Ball::update(self);
 
         let owner = self.ball.owner;
 
+        let owner_team = owner.map(|owner_h| self.pools.players.borrow(owner_h).team);
+
+        if let Some(owner_team) = owner_team {
+            self.stats.owned(owner_team);
+        }
+
+        // Rust: The kicks are charged while the control is held and the team owns the ball; the charge
+        // of a released kick has been used above.
+        for (team_num, team) in self.teams.iter_mut().enumerate() {
+            if team.kick_charging() && owner_team == Some(team_num as u8) {
+                team.kick_charge += 1;
+            } else {
+                team.kick_charge = 0;
+            }
+        }
+
         for team_num in 0..2 {
             let team_obj = &mut self.teams[team_num];
 
             //# Manual player switching when space is pressed
-            if team_obj.human() && team_obj.controls.as_ref().unwrap().shoot(input) {
+            // Rust: Or, the pass control.
+            if team_obj.human() && team_obj.kick_pressed() {
                 //# Find nearest player to the ball on our team
                 //# If the ball has an owner (who must be on the other team because if not, control would have
                 //# automatically switched to the ball owner and we wouldn't need to manually switch), we weight the
@@ -383,7 +510,7 @@ impl Game {
                     let dist_to_ball = (p_vpos - self.ball.vpos).norm();
                     //# Thonny gives a warning about the following line, relating to closures (an advanced topic), but
                     //# in this case there is not actually a problem as the closure is only called within the loop
-                    let goal_dir = 2. * team_num as f32 - 1.;
+                    let goal_dir = 2. * self.teams[team_num].end as f32 - 1.;
                     if owner.is_some() && (p_vpos.y - self.ball.vpos.y) * goal_dir < 0. {
                         dist_to_ball / 2.0
                     } else {
@@ -405,18 +532,6 @@ impl Game {
             }
         }
 
-        for (arrow, team) in self.arrows.iter_mut().zip(self.teams.iter()) {
-            if let Some(arrow) = arrow {
-                let arrow_pos = self
-                    .pools
-                    .players
-                    .borrow(team.active_control_player.unwrap())
-                    .vpos()
-                    - Vector2::new(11., 45.);
-                *arrow.vpos_mut() = arrow_pos;
-            }
-        }
-
         //# Get vector between current camera pos and ball pos
         let (camera_ball_vec, distance) = safe_normalise(&(self.camera_focus - self.ball.vpos));
         if distance > 0.0 {
@@ -424,133 +539,16 @@ impl Game {
             let camera_shift = camera_ball_vec * distance.min(8.0);
             self.camera_focus -= camera_shift;
         }
-    }
-
-    // Returns the camera offset; hopefully, it can be removed if Image widgets support transparency.
-    //
-    pub fn prepare_draw(
-        &self,
-        scene: &mut Scene,
-        camera_h: Handle<Node>,
-        media: &mut Media,
-    ) -> Vector2<f32> {
-        let cam_offset = Vector2::new(
-            -(self.camera_focus.x - WIDTH / 2.).clamp(0., LEVEL_W - WIDTH),
-            -(self.camera_focus.y - HEIGHT / 2.).clamp(0., LEVEL_H - HEIGHT),
-        );
 
-        let camera = scene.graph[camera_h].as_camera_mut();
-        camera.set_local_transform(
-            TransformBuilder::new()
-                .with_local_position(Vector3::new(cam_offset.x, cam_offset.y, 0.))
-                .build(),
-        );
-
-        //# Prepare to draw all objects
-        //# 1. Create a list of all players and the ball, sorted based on their Y positions
-        //# 2. Add object shadows to the list
-        //# 3. Add the two goals at each end of the list
-        //# (note - technically we're not adding items to the list in steps two and three, we're creating a new list
-        //# which consists of the old list plus the new items)
-
-        // We deviate from the source project here, by taking advantage of the z-depth, which considerably
-        // simplifies the port.
-
-        // TODO: Goals don't need a draw prepare, as the textures and their positions are fixed; they
-        // are better prepared at the beginning of the Play game state.
-        self.pools
-            .goals
-            .borrow(self.goals[0])
-            .prepare_draw(scene, media, DRAW_GOAL_0_Z);
-
-        // Min/max also include the ball.
-        let min_player_y = self
-            .pools
-            .players
-            .iter()
-            .map(|p| p.vpos.y)
-            .min_by(|y1, y2| y1.partial_cmp(y2).unwrap())
-            .unwrap()
-            .min(self.ball.vpos.y);
-        let max_player_y = self
-            .pools
-            .players
-            .iter()
-            .map(|p| p.vpos.y)
-            .max_by(|y1, y2| y1.partial_cmp(y2).unwrap())
-            .unwrap()
-            .max(self.ball.vpos.y);
-
-        // This crashes if all the players, and the ball, are on the exact same y coordinate :)
-        let players_z_unit = (DRAW_PLAYERS_Z.1 - DRAW_PLAYERS_Z.0) / (max_player_y - min_player_y);
-
-        for player in self.pools.players.iter() {
-            let player_z = DRAW_PLAYERS_Z.0 + (player.vpos.y - min_player_y) * players_z_unit;
-            player.prepare_draw(scene, media, player_z);
-
-            let player_shadow_z =
-                DRAW_SHADOWS_Z.0 + (player.shadow.vpos.y - min_player_y) * players_z_unit;
-            player.shadow.prepare_draw(scene, media, player_shadow_z);
-        }
-
-        let ball_z = DRAW_PLAYERS_Z.0 + (self.ball.vpos.y - min_player_y) * players_z_unit;
-        self.ball.prepare_draw(scene, media, ball_z);
-
-        let ball_shadow_z =
-            DRAW_PLAYERS_Z.0 + (self.ball.shadow.vpos.y - min_player_y) * players_z_unit;
-        self.ball.shadow.prepare_draw(scene, media, ball_shadow_z);
-
-        self.pools
-            .goals
-            .borrow(self.goals[0])
-            .prepare_draw(scene, media, DRAW_GOAL_0_Z);
-        self.pools
-            .goals
-            .borrow(self.goals[1])
-            .prepare_draw(scene, media, DRAW_GOAL_1_Z);
-
-        //# Show active players
-        #[allow(clippy::manual_flatten)]
-        for arrow in &self.arrows {
-            if let Some(arrow) = arrow {
-                arrow.prepare_draw(scene, media, DRAW_ARROWS_Z);
-            }
+        // The recording stops when a goal is scored, so that the replay ends with it. Matches without
+        // human players (the menu background, and the simulations) are not recorded.
+        //
+        if self.score_timer < 0 && self.humans_playing() {
+            self.replay.record(Snapshot::capture(self));
         }
+    }
 
-        //if DEBUG_SHOW_LEADS:
-        //    for p in self.players:
-        //        if game.ball.owner and p.lead:
-        //            line_start = game.ball.owner.vpos - offset
-        //            line_end = p.vpos - offset
-        //            pygame.draw.line(screen.surface, (0,0,0), line_start, line_end)
-        //
-        //if DEBUG_SHOW_TARGETS:
-        //    for p in self.players:
-        //        line_start = p.debug_target - offset
-        //        line_end = p.vpos - offset
-        //        pygame.draw.line(screen.surface, (255,0,0), line_start, line_end)
-        //
-        //if DEBUG_SHOW_PEERS:
-        //    for p in self.players:
-        //        line_start = p.peer.vpos - offset
-        //        line_end = p.vpos - offset
-        //        pygame.draw.line(screen.surface, (0,0,255), line_start, line_end)
-        //
-        //if DEBUG_SHOW_SHOOT_TARGET:
-        //    if self.debug_shoot_target and self.ball.owner:
-        //        line_start = self.ball.owner.vpos - offset
-        //        line_end = self.debug_shoot_target - offset
-        //        pygame.draw.line(screen.surface, (255,0,255), line_start, line_end)
-        //
-        //if DEBUG_SHOW_COSTS and self.ball.owner:
-        //    for x in range(0,LEVEL_W,60):
-        //        for y in range(0, LEVEL_H, 26):
-        //            c = cost(Vector2(x,y), self.ball.owner.team)[0]
-        //            screen_pos = Vector2(x,y)-offset
-        //            screen_pos = (screen_pos.x,screen_pos.y)    # draw.text can't reliably take a Vector2
-        //            screen.draw.text("{0:.0f}".format(c), center=screen_pos)
-
-        // By inverting it, we make it easier to use (it is added the objects coordinates).
-        cam_offset * -1.
+    fn humans_playing(&self) -> bool {
+        self.teams.iter().any(Team::human)
     }
 }
@@ -12,6 +12,14 @@ use fyrox::{
 use crate::prelude::*;
 
 const DEFAULT_WIN_SCORE: &str = "9";
+const DEFAULT_HALF_LENGTH: &str = "120";
+const DEFAULT_OVERTIME: &str = "none";
+const DEFAULT_PITCH: &str = "standard";
+
+const REPLAY_BANNER: &str = "REPLAY - SHOOT TO SKIP";
+
+// As in the source project (`music.fadeout(1)`); the game runs at 60 frames per second.
+const MUSIC_FADE_FRAMES: u32 = 60;
 
 pub struct GameGlobal {
     media: Media,
@@ -19,13 +27,28 @@ pub struct GameGlobal {
     camera: Handle<Node>,
     input: InputController,
     game: Game,
+    game_view: GameView,
+    // Rust: In the source project, the controls are stored in the teams; see TeamInput.
+    controls: [Option<Controls>; 2],
+    // The binding profiles of the players, edited via the controls screen.
+    control_profiles: [Controls; 2],
     game_hud: GameHud,
     state: State,
     menu_screen: MenuScreen,
     menu_state: Option<MenuState>,
     menu_num_players: u8,
     menu_difficulty: u8,
+    controls_screen: ControlsScreen,
+    audio_screen: AudioScreen,
+    // The volume of each sound category, edited via the audio screen.
+    audio_settings: AudioSettings,
+    tactics_screen: TacticsScreen,
+    league: League,
+    league_screen: LeagueScreen,
+    // True while playing (and on the game over screen of) a league match.
+    league_match: bool,
     game_over_screen: GameOverScreen,
+    debug_overlay: DebugOverlay,
 
     // For debugging; can be set via env var `SOCCER_WIN_SCORE`.
     win_score: u8,
@@ -39,11 +62,41 @@ impl GameState for GameGlobal {
 
         let camera = Self::add_camera(&mut scene);
 
-        let mut media = Media::new(&engine.resource_manager);
+        let audio_settings = AudioSettings::load();
+        let mut media = Media::new(&engine.resource_manager, audio_settings);
 
         let input = InputController::new();
 
-        let game = Game::new(None, None, DEFAULT_DIFFICULTY, &mut scene, &mut media);
+        // The half length is in seconds (0 disables the clock); the overtime, played when the scores
+        // are level at the end of the second half, is one of `none`, `extra_time` and `golden_goal`.
+        //
+        let half_length = env::var("SOCCER_HALF_LENGTH")
+            .unwrap_or_else(|_| String::from(DEFAULT_HALF_LENGTH))
+            .parse()
+            .unwrap();
+        let overtime = Overtime::parse(
+            &env::var("SOCCER_OVERTIME").unwrap_or_else(|_| String::from(DEFAULT_OVERTIME)),
+        )
+        .expect("Invalid SOCCER_OVERTIME value");
+        let clock = MatchClock::new(half_length, overtime);
+
+        // The pitch is one of `standard` (seven-a-side) and `small` (five-a-side); the team size can
+        // be changed independently.
+        //
+        let mut pitch =
+            Pitch::parse(&env::var("SOCCER_PITCH").unwrap_or_else(|_| String::from(DEFAULT_PITCH)))
+                .expect("Invalid SOCCER_PITCH value");
+        if let Ok(team_size) = env::var("SOCCER_TEAM_SIZE") {
+            pitch = pitch.with_players_per_team(team_size.parse().unwrap());
+        }
+
+        let tactics_screen = TacticsScreen::new(pitch.players_per_team);
+        let game_view = GameView::new(&mut scene, &media, &pitch);
+        let game = Game::new(false, false, DEFAULT_DIFFICULTY, clock, pitch);
+
+        //# No players - we must be on the menu. Play title music.
+        media.play_looping_sound(&mut scene, "theme");
+
         let game_hud = GameHud::new();
 
         let state = State::Menu;
@@ -65,20 +118,37 @@ impl GameState for GameGlobal {
             camera,
             input,
             game,
+            game_view,
+            controls: [None, None],
+            control_profiles: Controls::load_profiles(),
             game_hud,
             state,
             menu_screen,
             menu_state,
             menu_num_players: 1,
             menu_difficulty: 0,
+            controls_screen: ControlsScreen::new(),
+            audio_screen: AudioScreen::new(),
+            audio_settings,
+            tactics_screen,
+            league: League::load().unwrap_or_else(League::new),
+            league_screen: LeagueScreen::new(),
+            league_match: false,
             game_over_screen,
+            debug_overlay: DebugOverlay::new(),
             win_score,
         }
     }
 
     fn on_tick(&mut self, engine: &mut Engine, _dt: f32, _control_flow: &mut ControlFlow) {
+        self.input.poll_gamepads();
+
+        self.debug_overlay.update(&self.input);
+
         self.update(engine);
 
+        self.media.update(&mut engine.scenes[self.scene]);
+
         self.prepare_draw(engine, self.camera);
 
         self.input.flush_event_received_state();
@@ -125,7 +195,104 @@ impl GameGlobal {
 
         match &self.state {
             Menu => {
-                if self.input.is_key_just_pressed(Space) {
+                if let Some(MenuState::Controls) = self.menu_state {
+                    let done = self.controls_screen.update(
+                        &self.input,
+                        &mut self.control_profiles,
+                        user_interface,
+                    );
+
+                    if done {
+                        self.controls_screen.clear(user_interface);
+                        self.display_main_menu(user_interface);
+                    }
+                } else if let Some(MenuState::Audio) = self.menu_state {
+                    let done = self.audio_screen.update(
+                        &self.input,
+                        &mut self.audio_settings,
+                        user_interface,
+                    );
+                    self.media.set_audio_settings(self.audio_settings);
+
+                    if done {
+                        self.audio_screen.clear(user_interface);
+                        self.display_main_menu(user_interface);
+                    }
+                } else if let Some(MenuState::Tactics) = self.menu_state {
+                    match self.tactics_screen.update(&self.input, user_interface) {
+                        Some(true) => {
+                            self.tactics_screen.clear(user_interface);
+                            self.game_hud.display(&self.media, user_interface);
+
+                            self.state = State::Play;
+                            self.menu_state = None;
+
+                            let (p2_controls, difficulty) = if self.league_match {
+                                let opponent = self.league.next_opponent().unwrap();
+                                (None, opponent.difficulty.unwrap())
+                            } else if self.menu_num_players == 2 {
+                                (Some(self.control_profiles[1].clone()), DEFAULT_DIFFICULTY)
+                            } else {
+                                (None, self.menu_difficulty)
+                            };
+                            self.reset_game(
+                                Some(self.control_profiles[0].clone()),
+                                p2_controls,
+                                difficulty,
+                                self.tactics_screen.team_tactics(),
+                                scene,
+                            );
+                        }
+                        Some(false) => {
+                            self.tactics_screen.clear(user_interface);
+
+                            if self.league_match {
+                                self.league_match = false;
+
+                                self.menu_state = Some(MenuState::League);
+                                self.league_screen.display(&self.league, user_interface);
+                            } else {
+                                self.display_main_menu(user_interface);
+                            }
+                        }
+                        None => {}
+                    }
+                } else if let Some(MenuState::League) = self.menu_state {
+                    match self
+                        .league_screen
+                        .update(&self.input, &mut self.league, user_interface)
+                    {
+                        Some(true) => {
+                            self.league_screen.clear(user_interface);
+                            self.league_match = true;
+
+                            self.menu_state = Some(MenuState::Tactics);
+                            self.tactics_screen.display([true, false], user_interface);
+                        }
+                        Some(false) => {
+                            self.league_screen.clear(user_interface);
+                            self.display_main_menu(user_interface);
+                        }
+                        None => {}
+                    }
+                } else if self.menu_state == Some(NumPlayers) && self.input.is_key_just_pressed(L) {
+                    self.menu_screen.clear(user_interface);
+
+                    self.menu_state = Some(MenuState::League);
+                    self.league_screen.display(&self.league, user_interface);
+                } else if self.menu_state == Some(NumPlayers) && self.input.is_key_just_pressed(C) {
+                    self.menu_screen.clear(user_interface);
+
+                    self.menu_state = Some(MenuState::Controls);
+                    self.controls_screen
+                        .display(&self.control_profiles, user_interface);
+                } else if self.menu_state == Some(NumPlayers) && self.input.is_key_just_pressed(A) {
+                    self.menu_screen.clear(user_interface);
+
+                    self.menu_state = Some(MenuState::Audio);
+                    self.audio_screen
+                        .display(&self.audio_settings, user_interface);
+                } else if self.input.is_key_just_pressed(Space) {
                     if let Some(NumPlayers) = self.menu_state {
                         //# If we're doing a 2 player game, skip difficulty selection
                         if self.menu_num_players == 1 {
@@ -140,33 +307,19 @@ impl GameGlobal {
                             );
                         } else {
                             //# Start 2P game
+                            // Rust: After choosing the tactics.
                             self.menu_screen.clear(user_interface);
-                            self.game_hud.display(&self.media, user_interface);
 
-                            self.state = State::Play;
-                            self.menu_state = None;
-                            self.game.reset_game(
-                                Some(Controls::new(0)),
-                                Some(Controls::new(1)),
-                                DEFAULT_DIFFICULTY,
-                                scene,
-                                &mut self.media,
-                            )
+                            self.menu_state = Some(MenuState::Tactics);
+                            self.tactics_screen.display([true, true], user_interface);
                         }
                     } else {
                         //# Start 1P game
+                        // Rust: After choosing the tactics.
                         self.menu_screen.clear(user_interface);
-                        self.game_hud.display(&self.media, user_interface);
 
-                        self.state = State::Play;
-                        self.menu_state = None;
-                        self.game.reset_game(
-                            Some(Controls::new(0)),
-                            None,
-                            self.menu_difficulty,
-                            scene,
-                            &mut self.media,
-                        );
+                        self.menu_state = Some(MenuState::Tactics);
+                        self.tactics_screen.display([true, false], user_interface);
                     }
                 } else {
                     //# Detect + act on up/down arrow keys
@@ -196,17 +349,33 @@ impl GameGlobal {
                     }
                 }
 
-                self.game.update(&self.media, scene, &self.input)
+                self.update_game(scene);
             }
             Play => {
                 //# First player to 9 wins
                 let max_score = self.game.teams.iter().map(|t| t.score).max().unwrap();
 
+                // With a golden goal, the match ends after the celebration, like with the win score.
+                //
+                let full_time = self.game.clock.finished() && self.game.score_timer <= 1;
+
                 if self.win_score == 0
                     || (max_score == self.win_score && self.game.score_timer == 1)
+                    || full_time
                 {
                     self.state = State::GameOver;
 
+                    // The other matches of the round are simulated here.
+                    //
+                    if self.league_match {
+                        self.league.record_result(
+                            self.game.teams[0].score,
+                            self.game.teams[1].score,
+                            &self.game.pitch,
+                        );
+                        self.league.save();
+                    }
+
                     let background_index =
                         (self.game.teams[1].score > self.game.teams[0].score) as u8;
                     let team_scores = self
@@ -224,7 +393,7 @@ impl GameGlobal {
                         &mut engine.user_interface,
                     );
                 } else {
-                    self.game.update(&self.media, scene, &self.input);
+                    self.update_game(scene);
                 }
             }
             GameOver => {
@@ -233,17 +402,101 @@ impl GameGlobal {
 
                     //# Switch to menu state, and create a new game object without a player
                     self.state = State::Menu;
-                    self.menu_state = Some(MenuState::NumPlayers);
-                    self.game
-                        .reset_game(None, None, DEFAULT_DIFFICULTY, scene, &mut self.media);
+                    let tactics =
+                        [0, 1].map(|_| TeamTactics::default_for(self.game.pitch.players_per_team));
+                    self.reset_game(None, None, DEFAULT_DIFFICULTY, tactics, scene);
+
+                    // Rust: After a league match, back to the standings.
+                    if self.league_match {
+                        self.league_match = false;
 
-                    self.menu_screen
-                        .display(&self.media, &mut engine.user_interface);
+                        self.menu_state = Some(MenuState::League);
+                        self.league_screen.display(&self.league, user_interface);
+                    } else {
+                        self.menu_state = Some(MenuState::NumPlayers);
+                        self.menu_screen
+                            .display(&self.media, &mut engine.user_interface);
+                    }
                 }
             }
         }
     }
 
+    // Back from the screens not in the source project (controls, tactics, league and audio).
+    //
+    fn display_main_menu(&mut self, user_interface: &mut UserInterface) {
+        self.menu_state = Some(MenuState::NumPlayers);
+        self.menu_screen.display(&self.media, user_interface);
+        self.menu_screen.update_selection(
+            MenuState::NumPlayers,
+            self.menu_num_players,
+            self.menu_difficulty,
+            &self.media,
+            user_interface,
+        );
+    }
+
+    // Rust: In the source project, the music is handled by Game.reset_game(); the simulation doesn't
+    // play sounds.
+    //
+    fn reset_game(
+        &mut self,
+        p1_controls: Option<Controls>,
+        p2_controls: Option<Controls>,
+        difficulty: u8,
+        tactics: [TeamTactics; 2],
+        scene: &mut Scene,
+    ) {
+        self.game.reset_game(
+            p1_controls.is_some(),
+            p2_controls.is_some(),
+            difficulty,
+            tactics,
+        );
+        self.controls = [p1_controls, p2_controls];
+
+        // Rust: The crowd fades in while the music fades out, and vice versa.
+        //
+        if self.game.teams[0].human() {
+            //# Beginning a game with at least 1 human player
+            //# music.fadeout(1)
+            self.media
+                .crossfade_looping_sounds(scene, "theme", "crowd", MUSIC_FADE_FRAMES);
+            self.media.play_sound(scene, "start", &[]);
+        } else {
+            //# No players - we must be on the menu. Play title music.
+            self.media
+                .crossfade_looping_sounds(scene, "crowd", "theme", MUSIC_FADE_FRAMES);
+        }
+    }
+
+    // Samples the controls, updates the simulation, and plays the sounds it emitted.
+    //
+    fn update_game(&mut self, scene: &mut Scene) {
+        let inputs = [0, 1].map(|i| {
+            self.controls[i]
+                .as_ref()
+                .map_or_else(TeamInput::default, |controls| {
+                    controls.team_input(&self.input)
+                })
+        });
+
+        self.game.update(&inputs);
+
+        for (base, index) in &self.game.sounds {
+            self.media.play_sound(scene, base, &[*index]);
+        }
+
+        // The crowd swells as the ball nears a goal; during the replays, it follows the replayed ball.
+        //
+        let ball_vpos = match self.game.replay.current() {
+            Some(snapshot) => snapshot.ball_shadow.vpos,
+            None => self.game.ball.vpos,
+        };
+        self.media
+            .set_crowd_excitement(self.game.pitch.goal_proximity(ball_vpos));
+    }
+
     // This stage sets:
     //
     // - the camera location
@@ -254,7 +507,11 @@ impl GameGlobal {
     fn prepare_draw(&mut self, engine: &mut Engine, camera: Handle<Node>) {
         let scene = &mut engine.scenes[self.scene];
 
-        self.game.prepare_draw(scene, camera, &mut self.media);
+        self.game_view
+            .prepare_draw(&self.game, scene, camera, &self.media);
+
+        self.debug_overlay
+            .draw(&self.game, &mut scene.drawing_context);
 
         if let State::Play = &self.state {
             let team_scores = self
@@ -264,10 +521,26 @@ impl GameGlobal {
                 .map(|team| team.score)
                 .collect::<Vec<_>>();
             let display_goal = self.game.score_timer > 0;
+            let banner_text = if self.game.replay.playing() {
+                Some(REPLAY_BANNER)
+            } else {
+                self.game.clock.banner()
+            };
+            let kick_charges = self
+                .game
+                .teams
+                .iter()
+                .map(|team| {
+                    (team.human() && team.kick_charge > 0).then(|| team.kick_charge_ratio())
+                })
+                .collect::<Vec<_>>();
 
             self.game_hud.update(
                 &team_scores,
                 display_goal,
+                self.game.clock.text(),
+                banner_text,
+                &kick_charges,
                 &self.media,
                 &mut engine.user_interface,
             );
@@ -1,14 +1,33 @@
+use fyrox::core::color::Color;
+
 use crate::prelude::*;
 
 const BAR_IMG_BASE: &str = "bar";
 const SCORE_IMG_BASE: &str = "s";
 const GOAL_IMG_BASE: &str = "goal";
 
+const BAR_W: f32 = 352.;
+const CLOCK_FONT_SIZE: f32 = 24.;
+const BANNER_FONT_SIZE: f32 = 40.;
+
+const METER_W: f32 = 150.;
+const METER_H: f32 = 10.;
+const METER_MARGIN: f32 = 20.;
+const METER_COLOR: Color = Color::opaque(255, 200, 0);
+
 pub struct GameHud {
     bar_h: Handle<UiNode>,
     score_hs: Vec<Handle<UiNode>>,
     goal_h: Handle<UiNode>,
     goal_displayed: bool,
+    clock_h: Handle<UiNode>,
+    banner_h: Handle<UiNode>,
+    // As with the goal, the texts are sent only on change.
+    clock_text: Option<String>,
+    banner_text: Option<&'static str>,
+    // Kick power meters, one per team; hidden when the team is not charging a kick.
+    meter_hs: Vec<Handle<UiNode>>,
+    meter_charges: Vec<Option<f32>>,
 }
 
 impl GameHud {
@@ -19,17 +38,27 @@ impl GameHud {
         let score_hs = vec![Handle::NONE, Handle::NONE];
         let goal_h = Handle::NONE;
         let goal_displayed = false;
+        let clock_h = Handle::NONE;
+        let banner_h = Handle::NONE;
+        let meter_hs = vec![Handle::NONE, Handle::NONE];
+        let meter_charges = vec![None, None];
 
         Self {
             bar_h,
             score_hs,
             goal_h,
             goal_displayed,
+            clock_h,
+            banner_h,
+            clock_text: None,
+            banner_text: None,
+            meter_hs,
+            meter_charges,
         }
     }
 
     pub fn display(&mut self, media: &Media, user_interface: &mut UserInterface) {
-        self.bar_h = add_widget_node(HALF_WINDOW_W - 176., 0., user_interface);
+        self.bar_h = add_widget_node(HALF_WINDOW_W - BAR_W / 2., 0., user_interface);
         update_widget_texture(self.bar_h, media, BAR_IMG_BASE, &[], user_interface);
 
         for (i, score_h) in self.score_hs.iter_mut().enumerate() {
@@ -40,13 +69,49 @@ impl GameHud {
         update_widget_texture(self.goal_h, media, GOAL_IMG_BASE, &[], user_interface);
         disable_widget_node(self.goal_h, user_interface);
 
-        self.update(&[0, 0], false, media, user_interface);
+        self.clock_h = add_text_widget_node(
+            HALF_WINDOW_W - BAR_W / 2.,
+            40.,
+            BAR_W,
+            CLOCK_FONT_SIZE,
+            user_interface,
+        );
+        self.banner_h = add_text_widget_node(0., 80., WIDTH, BANNER_FONT_SIZE, user_interface);
+        self.clock_text = None;
+        self.banner_text = None;
+
+        for (i, meter_h) in self.meter_hs.iter_mut().enumerate() {
+            let x = METER_MARGIN + (WIDTH - METER_W - 2. * METER_MARGIN) * (i as f32);
+            *meter_h = add_bar_widget_node(
+                x,
+                HEIGHT - METER_MARGIN - METER_H,
+                METER_W,
+                METER_H,
+                METER_COLOR,
+                user_interface,
+            );
+            disable_widget_node(*meter_h, user_interface);
+        }
+        self.meter_charges = vec![None, None];
+
+        self.update(
+            &[0, 0],
+            false,
+            None,
+            None,
+            &[None, None],
+            media,
+            user_interface,
+        );
     }
 
     pub fn update(
         &mut self,
         team_scores: &[u8],
         display_goal: bool,
+        clock_text: Option<String>,
+        banner_text: Option<&'static str>,
+        kick_charges: &[Option<f32>],
         media: &Media,
         user_interface: &mut UserInterface,
     ) {
@@ -70,6 +135,42 @@ impl GameHud {
             }
             self.goal_displayed = display_goal;
         }
+
+        if clock_text != self.clock_text {
+            update_widget_text(
+                self.clock_h,
+                clock_text.as_deref().unwrap_or_default(),
+                user_interface,
+            );
+            self.clock_text = clock_text;
+        }
+
+        if banner_text != self.banner_text {
+            update_widget_text(
+                self.banner_h,
+                banner_text.unwrap_or_default(),
+                user_interface,
+            );
+            self.banner_text = banner_text;
+        }
+
+        for (i, meter_h) in self.meter_hs.iter().enumerate() {
+            let kick_charge = kick_charges[i];
+
+            if kick_charge != self.meter_charges[i] {
+                match kick_charge {
+                    Some(ratio) => {
+                        update_widget_width(*meter_h, METER_W * ratio, user_interface);
+
+                        if self.meter_charges[i].is_none() {
+                            enable_widget_node(*meter_h, user_interface);
+                        }
+                    }
+                    None => disable_widget_node(*meter_h, user_interface),
+                }
+                self.meter_charges[i] = kick_charge;
+            }
+        }
     }
 
     pub fn clear(&mut self, user_interface: &mut UserInterface) {
@@ -80,5 +181,11 @@ impl GameHud {
         }
 
         self.goal_h = remove_widget_node(self.goal_h, user_interface);
+        self.clock_h = remove_widget_node(self.clock_h, user_interface);
+        self.banner_h = remove_widget_node(self.banner_h, user_interface);
+
+        for meter_h in &mut self.meter_hs {
+            *meter_h = remove_widget_node(*meter_h, user_interface);
+        }
     }
 }
@@ -0,0 +1,138 @@
+use crate::prelude::*;
+
+// The presentation of a Game: the pitch, the actor sprites and the arrows over the players under
+// control. In the source project, this is part of Game; here, it's separate, so that the match can
+// be simulated without the engine.
+//
+pub struct GameView {
+    sprites: Sprites,
+}
+
+impl GameView {
+    // The pitch size doesn't change during the session.
+    //
+    pub fn new(scene: &mut Scene, media: &Media, pitch: &Pitch) -> Self {
+        // The pitch is always present, so we draw it only once.
+        // Rust: Each pitch size has its own image.
+        add_image_node(
+            media,
+            scene,
+            pitch.img_base,
+            &[],
+            0.,
+            0.,
+            DRAW_PITCH_Z,
+            Anchor::TopLeft,
+        );
+
+        Self {
+            sprites: Sprites::new(),
+        }
+    }
+
+    // Returns the camera offset; hopefully, it can be removed if Image widgets support transparency.
+    //
+    // Rust: The players and the ball are drawn from a snapshot; during the replays, the recorded one.
+    //
+    pub fn prepare_draw(
+        &mut self,
+        game: &Game,
+        scene: &mut Scene,
+        camera_h: Handle<Node>,
+        media: &Media,
+    ) -> Vector2<f32> {
+        let live_snapshot;
+        let snapshot = match game.replay.current() {
+            Some(snapshot) => snapshot,
+            None => {
+                live_snapshot = Snapshot::capture(game);
+                &live_snapshot
+            }
+        };
+
+        let cam_offset = -game.pitch.camera_offset(snapshot.camera_focus);
+
+        let camera = scene.graph[camera_h].as_camera_mut();
+        camera.set_local_transform(
+            TransformBuilder::new()
+                .with_local_position(Vector3::new(cam_offset.x, cam_offset.y, 0.))
+                .build(),
+        );
+
+        //# Prepare to draw all objects
+        //# 1. Create a list of all players and the ball, sorted based on their Y positions
+        //# 2. Add object shadows to the list
+        //# 3. Add the two goals at each end of the list
+        //# (note - technically we're not adding items to the list in steps two and three, we're creating a new list
+        //# which consists of the old list plus the new items)
+
+        // We deviate from the source project here, by taking advantage of the z-depth, which considerably
+        // simplifies the port.
+
+        self.sprites.begin();
+
+        // Min/max also include the ball; its shadow is used, since it's on the ground.
+        let ball_y = snapshot.ball_shadow.vpos.y;
+        let min_player_y = snapshot
+            .players
+            .iter()
+            .map(|p| p.vpos.y)
+            .min_by(|y1, y2| y1.partial_cmp(y2).unwrap())
+            .unwrap()
+            .min(ball_y);
+        let max_player_y = snapshot
+            .players
+            .iter()
+            .map(|p| p.vpos.y)
+            .max_by(|y1, y2| y1.partial_cmp(y2).unwrap())
+            .unwrap()
+            .max(ball_y);
+
+        // This crashes if all the players, and the ball, are on the exact same y coordinate :)
+        let players_z_unit = (DRAW_PLAYERS_Z.1 - DRAW_PLAYERS_Z.0) / (max_player_y - min_player_y);
+
+        for (player, shadow) in snapshot.players.iter().zip(&snapshot.shadows) {
+            let player_z = DRAW_PLAYERS_Z.0 + (player.vpos.y - min_player_y) * players_z_unit;
+            self.sprites.draw(player, scene, media, player_z);
+
+            let player_shadow_z =
+                DRAW_SHADOWS_Z.0 + (shadow.vpos.y - min_player_y) * players_z_unit;
+            self.sprites.draw(shadow, scene, media, player_shadow_z);
+        }
+
+        let ball_z = DRAW_PLAYERS_Z.0 + (ball_y - min_player_y) * players_z_unit;
+        self.sprites.draw(&snapshot.ball, scene, media, ball_z);
+
+        let ball_shadow_z =
+            DRAW_PLAYERS_Z.0 + (snapshot.ball_shadow.vpos.y - min_player_y) * players_z_unit;
+        self.sprites
+            .draw(&snapshot.ball_shadow, scene, media, ball_shadow_z);
+
+        // TODO: Goals don't need a draw prepare, as the textures and their positions are fixed; they
+        // are better prepared at the beginning of the Play game state.
+        let goal_0 = game.pools.goals.borrow(game.goals[0]);
+        self.sprites.draw(goal_0, scene, media, DRAW_GOAL_0_Z);
+        let goal_1 = game.pools.goals.borrow(game.goals[1]);
+        self.sprites.draw(goal_1, scene, media, DRAW_GOAL_1_Z);
+
+        //# Show active players
+        // Rust: Not during the replays.
+        let replaying = game.replay.playing();
+        for (i, team) in game.teams.iter().enumerate() {
+            //# Only show arrow for human teams
+            if let (true, Some(player_h)) = (team.human() && !replaying, team.active_control_player)
+            {
+                let arrow = Arrow::new(i as u8, game.pools.players.borrow(player_h).vpos);
+
+                self.sprites.draw_layered(&arrow, scene, media);
+            }
+        }
+
+        // The debug diagnostics are drawn separately; see DebugOverlay.
+
+        self.sprites.end(scene);
+
+        // By inverting it, we make it easier to use (it is added the objects coordinates).
+        cam_offset * -1.
+    }
+}
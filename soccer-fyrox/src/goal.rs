@@ -1,28 +1,26 @@
 use crate::prelude::*;
 
-#[my_actor_based]
+#[my_actor_based(img_base = "goal", anchor = Anchor::Center)]
 pub struct Goal {
-    team: u8,
+    // The team attacking the goal; changes when the teams switch ends.
+    pub team: u8,
 }
 
 impl Goal {
-    pub fn new(team: u8, graph: &mut Graph) -> Self {
-        let x = HALF_LEVEL_W;
-        let y = if team == 0 { 0. } else { LEVEL_H };
+    pub fn new(team: u8, pitch: &Pitch) -> Self {
+        let x = pitch.half_level_w;
+        let y = if team == 0 { 0. } else { pitch.level_h };
         let vpos = Vector2::new(x, y);
 
-        let img_base = "goal";
+        let img_base = Self::DEFAULT_IMG_BASE;
         let img_indexes = vec![team];
 
-        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
-
         Self {
             img_base,
             img_indexes,
             vpos,
             team,
-            anchor: Anchor::Center,
-            rectangle_h,
+            anchor: Self::DEFAULT_ANCHOR,
         }
     }
 }
@@ -1,8 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::prelude::*;
 
+// Below this, the stick is considered at rest.
+const STICK_DEAD_ZONE: f32 = 0.5;
+
+// Fyrox doesn't support gamepads, so they're read via gilrs. This is optional (the `gamepad` feature),
+// since on Linux it requires libudev.
+//
 #[derive(Default)]
+struct GamepadState {
+    stick: Vector2<f32>,
+    pressed_buttons: HashSet<GamepadButton>,
+    // Cleared on each tick, like `pressed_keys`.
+    just_pressed_buttons: Vec<GamepadButton>,
+}
+
 pub struct InputController {
     // The value is a tuple of previous and last state (true = pressed).
     // Once an entry is added, it's never removed - on key released, the value is set as (false, false).
@@ -13,6 +26,27 @@ pub struct InputController {
     // how to interpret the state in between - we do it through this variable; see `is_key_just_pressed()`.
     //
     event_received: bool,
+    // The keys pressed during the current tick, in order; used when binding the controls.
+    //
+    pressed_keys: Vec<VirtualKeyCode>,
+    // The key is the gilrs gamepad id, which corresponds to the player number.
+    //
+    gamepads: HashMap<usize, GamepadState>,
+    #[cfg(feature = "gamepad")]
+    gilrs: Option<gilrs::Gilrs>,
+}
+
+impl Default for InputController {
+    fn default() -> Self {
+        Self {
+            key_states: HashMap::new(),
+            event_received: false,
+            pressed_keys: vec![],
+            gamepads: HashMap::new(),
+            #[cfg(feature = "gamepad")]
+            gilrs: gilrs::Gilrs::new().ok(),
+        }
+    }
 }
 
 // WATCH OUT!!! It's **crucial** to invoke `flush_event_received_state()` at the end of `on_tick()`,
@@ -24,10 +58,81 @@ impl InputController {
     }
 
     pub fn flush_event_received_state(&mut self) {
-        self.event_received = false
+        self.event_received = false;
+        self.pressed_keys.clear();
+
+        for gamepad in self.gamepads.values_mut() {
+            gamepad.just_pressed_buttons.clear();
+        }
+    }
+
+    // Must be invoked at the beginning of `on_tick()`, since gilrs events are polled rather than pushed.
+    //
+    pub fn poll_gamepads(&mut self) {
+        #[cfg(feature = "gamepad")]
+        if let Some(gilrs) = self.gilrs.as_mut() {
+            use gilrs::{Axis, EventType};
+
+            while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+                let gamepad = self.gamepads.entry(usize::from(id)).or_default();
+
+                match event {
+                    EventType::ButtonPressed(button, _) => {
+                        if let Some(button) = Self::convert_button(button) {
+                            gamepad.pressed_buttons.insert(button);
+                            gamepad.just_pressed_buttons.push(button);
+                        }
+                    }
+                    EventType::ButtonReleased(button, _) => {
+                        if let Some(button) = Self::convert_button(button) {
+                            gamepad.pressed_buttons.remove(&button);
+                        }
+                    }
+                    EventType::AxisChanged(Axis::LeftStickX, value, _) => gamepad.stick.x = value,
+                    // The gilrs Y axis points up.
+                    EventType::AxisChanged(Axis::LeftStickY, value, _) => gamepad.stick.y = -value,
+                    EventType::Disconnected => {
+                        self.gamepads.remove(&usize::from(id));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    fn convert_button(button: gilrs::Button) -> Option<GamepadButton> {
+        use gilrs::Button;
+
+        match button {
+            Button::South => Some(GamepadButton::South),
+            Button::East => Some(GamepadButton::East),
+            Button::North => Some(GamepadButton::North),
+            Button::West => Some(GamepadButton::West),
+            Button::LeftTrigger => Some(GamepadButton::LeftTrigger),
+            Button::LeftTrigger2 => Some(GamepadButton::LeftTrigger2),
+            Button::RightTrigger => Some(GamepadButton::RightTrigger),
+            Button::RightTrigger2 => Some(GamepadButton::RightTrigger2),
+            Button::Select => Some(GamepadButton::Select),
+            Button::Start => Some(GamepadButton::Start),
+            Button::Mode => Some(GamepadButton::Mode),
+            Button::LeftThumb => Some(GamepadButton::LeftThumb),
+            Button::RightThumb => Some(GamepadButton::RightThumb),
+            Button::DPadUp => Some(GamepadButton::DPadUp),
+            Button::DPadDown => Some(GamepadButton::DPadDown),
+            Button::DPadLeft => Some(GamepadButton::DPadLeft),
+            Button::DPadRight => Some(GamepadButton::DPadRight),
+            Button::C | Button::Z | Button::Unknown => None,
+        }
     }
 
     pub fn key_down(&mut self, key: VirtualKeyCode) {
+        // Auto-repeated events are not presses.
+        //
+        if !self.is_key_pressed(key) {
+            self.pressed_keys.push(key);
+        }
+
         self.key_states
             .entry(key)
             .and_modify(|v| *v = (v.1, true))
@@ -75,4 +180,52 @@ impl InputController {
             }
         }
     }
+
+    // The first key pressed during the current tick, if any.
+    //
+    pub fn just_pressed_key(&self) -> Option<VirtualKeyCode> {
+        self.pressed_keys.first().copied()
+    }
+
+    pub fn is_button_pressed(&self, gamepad: usize, button: GamepadButton) -> bool {
+        self.gamepads
+            .get(&gamepad)
+            .map_or(false, |state| state.pressed_buttons.contains(&button))
+    }
+
+    // The first button pressed during the current tick on any gamepad, if any.
+    //
+    pub fn just_pressed_button(&self) -> Option<GamepadButton> {
+        self.gamepads
+            .values()
+            .find_map(|state| state.just_pressed_buttons.first().copied())
+    }
+
+    // The direction of the d-pad, or otherwise of the left stick, snapped to the 8 directions; each
+    // axis is -1, 0 or 1.
+    //
+    pub fn gamepad_direction(&self, gamepad: usize) -> Vector2<f32> {
+        use GamepadButton::*;
+
+        let state = match self.gamepads.get(&gamepad) {
+            Some(state) => state,
+            None => return Vector2::zero(),
+        };
+
+        let pressed = |button| state.pressed_buttons.contains(&button) as i8 as f32;
+        let dpad = Vector2::new(
+            pressed(DPadRight) - pressed(DPadLeft),
+            pressed(DPadDown) - pressed(DPadUp),
+        );
+
+        if dpad != Vector2::zero() || state.stick.norm() < STICK_DEAD_ZONE {
+            return dpad;
+        }
+
+        let angle = state.stick.y.atan2(state.stick.x);
+        let octant = (angle / std::f32::consts::FRAC_PI_4).round();
+        let snapped = octant * std::f32::consts::FRAC_PI_4;
+
+        Vector2::new(snapped.cos().round(), snapped.sin().round())
+    }
 }
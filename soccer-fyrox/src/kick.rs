@@ -0,0 +1,10 @@
+// Rust: In the source project, there is a single kick control, and the target decides whether
+// it's a pass or a shot.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Kick {
+    // Targets the goal only; straight ahead if the goal is not targetable.
+    Shoot,
+    // Targets teammates only; lobbed if an opponent is in the way.
+    Pass,
+}
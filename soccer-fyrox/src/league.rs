@@ -0,0 +1,309 @@
+use std::cmp::Reverse;
+
+use crate::prelude::*;
+use crate::storage;
+
+// Rust: The source project only has single matches. This is a double round-robin league of the
+// human team against CPU teams of increasing difficulty. In each round, the human plays one match,
+// and the other matches are simulated.
+
+const LEAGUE_FILE: &str = "league.txt";
+
+const HUMAN_TEAM: usize = 0;
+const TEAMS: [(&str, Option<u8>); 4] = [
+    ("PLAYER", None),
+    ("ROVERS", Some(0)),
+    ("ATHLETIC", Some(1)),
+    ("UNITED", Some(2)),
+];
+
+// Simulated matches are shorter than the default ones, so that the rounds are quick to complete.
+const CPU_MATCH_HALF_LENGTH: u32 = 60;
+
+pub struct League {
+    pub teams: Vec<LeagueTeam>,
+    // Sorted by round.
+    pub fixtures: Vec<Fixture>,
+}
+
+impl League {
+    pub fn new() -> Self {
+        let teams = TEAMS
+            .iter()
+            .map(|(name, difficulty)| LeagueTeam::new(name, *difficulty))
+            .collect::<Vec<_>>();
+
+        // Circle method: the first team (the human one) is fixed, and the others rotate around it on
+        // each round; they start in reverse order, so that the human team meets them by increasing
+        // difficulty. The home team alternates on each round, and is swapped in the return leg.
+        //
+        let teams_count = teams.len();
+        let rounds_per_leg = teams_count - 1;
+        let mut order = [HUMAN_TEAM]
+            .into_iter()
+            .chain((1..teams_count).rev())
+            .collect::<Vec<_>>();
+        let mut first_leg = vec![];
+
+        for round in 0..rounds_per_leg {
+            for i in 0..teams_count / 2 {
+                let (home, away) = (order[i], order[teams_count - 1 - i]);
+                let (home, away) = if round % 2 == 0 {
+                    (home, away)
+                } else {
+                    (away, home)
+                };
+
+                first_leg.push(Fixture::new(round as u8, home, away));
+            }
+
+            order[1..].rotate_right(1);
+        }
+
+        let return_leg = first_leg
+            .iter()
+            .map(|f| Fixture::new(f.round + rounds_per_leg as u8, f.away, f.home))
+            .collect::<Vec<_>>();
+
+        let fixtures = [first_leg, return_leg].concat();
+
+        Self { teams, fixtures }
+    }
+
+    // Returns None if there is no saved league, or it's invalid.
+    //
+    pub fn load() -> Option<Self> {
+        Self::from_config(&storage::read(LEAGUE_FILE)?)
+    }
+
+    pub fn save(&self) {
+        storage::write(LEAGUE_FILE, &self.to_config());
+    }
+
+    // The format is one line per team, `team = <name> ; <difficulty or ->`, followed by one line per
+    // fixture, `fixture = <round> <home> <away> [; <home score> <away score>]`.
+    //
+    fn from_config(config: &str) -> Option<Self> {
+        let mut teams = vec![];
+        let mut fixtures = vec![];
+
+        for line in config.lines() {
+            let (name, value) = line.split_once('=')?;
+            let (value, extra) = value.split_once(';').unwrap_or((value, ""));
+
+            match name.trim() {
+                "team" => {
+                    let difficulty = match extra.trim() {
+                        "-" => None,
+                        difficulty => Some(difficulty.parse().ok()?),
+                    };
+                    teams.push(LeagueTeam::new(value.trim(), difficulty));
+                }
+                "fixture" => {
+                    let mut fixture = match Self::parse_numbers(value)?[..] {
+                        [round, home, away] => Fixture::new(round as u8, home, away),
+                        _ => return None,
+                    };
+
+                    if !extra.trim().is_empty() {
+                        fixture.result = match Self::parse_numbers(extra)?[..] {
+                            [home_score, away_score] => Some((home_score as u8, away_score as u8)),
+                            _ => return None,
+                        };
+                    }
+
+                    fixtures.push(fixture);
+                }
+                _ => return None,
+            }
+        }
+
+        let valid = teams
+            .get(HUMAN_TEAM)
+            .map_or(false, |t| t.difficulty.is_none())
+            && fixtures
+                .iter()
+                .all(|f| f.home < teams.len() && f.away < teams.len());
+
+        valid.then_some(Self { teams, fixtures })
+    }
+
+    fn to_config(&self) -> String {
+        let mut config = String::new();
+
+        for team in &self.teams {
+            let difficulty = team
+                .difficulty
+                .map_or_else(|| String::from("-"), |d| d.to_string());
+
+            config.push_str(&format!("team = {} ; {}\n", team.name, difficulty));
+        }
+
+        for fixture in &self.fixtures {
+            config.push_str(&format!(
+                "fixture = {} {} {}",
+                fixture.round, fixture.home, fixture.away
+            ));
+            if let Some((home_score, away_score)) = fixture.result {
+                config.push_str(&format!(" ; {} {}", home_score, away_score));
+            }
+            config.push('\n');
+        }
+
+        config
+    }
+
+    pub fn rounds(&self) -> u8 {
+        self.fixtures.last().map_or(0, |f| f.round + 1)
+    }
+
+    pub fn next_fixture(&self) -> Option<&Fixture> {
+        self.fixtures
+            .iter()
+            .find(|f| f.result.is_none() && f.involves(HUMAN_TEAM))
+    }
+
+    pub fn finished(&self) -> bool {
+        self.next_fixture().is_none()
+    }
+
+    // The CPU team of the next human match.
+    //
+    pub fn next_opponent(&self) -> Option<&LeagueTeam> {
+        self.next_fixture().map(|f| {
+            let opponent = if f.home == HUMAN_TEAM { f.away } else { f.home };
+            &self.teams[opponent]
+        })
+    }
+
+    // Records the result of the next human match, then simulates the other matches of the round, on
+    // the same pitch.
+    //
+    pub fn record_result(&mut self, human_score: u8, opponent_score: u8, pitch: &Pitch) {
+        let fixture = match self
+            .fixtures
+            .iter_mut()
+            .find(|f| f.result.is_none() && f.involves(HUMAN_TEAM))
+        {
+            Some(fixture) => fixture,
+            None => return,
+        };
+
+        fixture.result = Some(if fixture.home == HUMAN_TEAM {
+            (human_score, opponent_score)
+        } else {
+            (opponent_score, human_score)
+        });
+
+        let round = fixture.round;
+
+        for fixture in &mut self.fixtures {
+            if fixture.round <= round && fixture.result.is_none() {
+                fixture.result = Some(Self::simulate(
+                    &self.teams[fixture.home],
+                    &self.teams[fixture.away],
+                    pitch,
+                ));
+            }
+        }
+    }
+
+    // Sorted by points, then goal difference, then goals scored.
+    //
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings = (0..self.teams.len()).map(Standing::new).collect::<Vec<_>>();
+
+        for fixture in &self.fixtures {
+            if let Some((home_score, away_score)) = fixture.result {
+                standings[fixture.home].add_result(home_score, away_score);
+                standings[fixture.away].add_result(away_score, home_score);
+            }
+        }
+
+        standings.sort_by_key(|s| Reverse((s.points(), s.goal_difference(), s.goals_for)));
+
+        standings
+    }
+
+    // The home team is the team 0 of the simulated game; each team plays at its own difficulty.
+    //
+    fn simulate(home: &LeagueTeam, away: &LeagueTeam, pitch: &Pitch) -> (u8, u8) {
+        let difficulties = [home, away].map(|team| team.difficulty.unwrap_or_default());
+
+        let game = Game::play_cpu_match(
+            difficulties,
+            MatchClock::new(CPU_MATCH_HALF_LENGTH, Overtime::None),
+            pitch.clone(),
+        );
+
+        (game.teams[0].score, game.teams[1].score)
+    }
+
+    fn parse_numbers(value: &str) -> Option<Vec<usize>> {
+        value
+            .split_whitespace()
+            .map(|number| number.parse().ok())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_pair_meets_twice_with_home_and_away_swapped() {
+        let league = League::new();
+        let teams_count = league.teams.len();
+
+        for home in 0..teams_count {
+            for away in (0..teams_count).filter(|&away| away != home) {
+                let meetings = league
+                    .fixtures
+                    .iter()
+                    .filter(|f| f.home == home && f.away == away)
+                    .count();
+
+                assert_eq!(meetings, 1, "{home} vs {away}");
+            }
+        }
+
+        assert_eq!(league.fixtures.len(), teams_count * (teams_count - 1));
+    }
+
+    #[test]
+    fn every_team_plays_once_per_round() {
+        let league = League::new();
+
+        for round in 0..league.rounds() {
+            let mut teams = league
+                .fixtures
+                .iter()
+                .filter(|f| f.round == round)
+                .flat_map(|f| [f.home, f.away])
+                .collect::<Vec<_>>();
+            teams.sort();
+
+            assert_eq!(teams, (0..league.teams.len()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut league = League::new();
+        league.fixtures[0].result = Some((2, 1));
+        league.fixtures[1].result = Some((0, 3));
+
+        let loaded = League::from_config(&league.to_config()).unwrap();
+
+        assert_eq!(loaded.teams, league.teams);
+        assert_eq!(loaded.fixtures, league.fixtures);
+    }
+
+    #[test]
+    fn load_rejects_a_cpu_human_team() {
+        let config = "team = ROVERS ; 0\nfixture = 0 0 1\n";
+
+        assert!(League::from_config(config).is_none());
+    }
+}
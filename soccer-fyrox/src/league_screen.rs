@@ -0,0 +1,127 @@
+use fyrox::{
+    core::color::Color,
+    gui::{border::BorderBuilder, brush::Brush, widget::WidgetBuilder},
+};
+
+use crate::prelude::*;
+
+// Rust: The standings and the next match of the league (see League).
+
+const BACKGROUND_COLOR: Color = Color::from_rgba(0, 0, 0, 200);
+const FONT_SIZE: f32 = 20.;
+const TEXT_Y: f32 = 40.;
+
+pub struct LeagueScreen {
+    background_h: Handle<UiNode>,
+    text_h: Handle<UiNode>,
+}
+
+impl LeagueScreen {
+    // Doesn't display the screen or perform any instantiation.
+    //
+    pub fn new() -> Self {
+        Self {
+            background_h: Handle::NONE,
+            text_h: Handle::NONE,
+        }
+    }
+
+    pub fn display(&mut self, league: &League, user_interface: &mut UserInterface) {
+        self.text_h = add_text_widget_node(0., TEXT_Y, WIDTH, FONT_SIZE, user_interface);
+
+        self.background_h = BorderBuilder::new(
+            WidgetBuilder::new()
+                .with_width(WIDTH)
+                .with_height(HEIGHT)
+                .with_background(Brush::Solid(BACKGROUND_COLOR))
+                .with_foreground(Brush::Solid(Color::TRANSPARENT))
+                .with_child(self.text_h),
+        )
+        .build(&mut user_interface.build_ctx());
+
+        self.refresh(league, user_interface);
+    }
+
+    // Returns Some(true) when the next match is started, and Some(false) when the player goes back.
+    // A new league is started (and saved) on request, or when playing after the end of the current
+    // one.
+    //
+    pub fn update(
+        &mut self,
+        input: &InputController,
+        league: &mut League,
+        user_interface: &mut UserInterface,
+    ) -> Option<bool> {
+        use VirtualKeyCode::*;
+
+        if input.is_key_just_pressed(Escape) {
+            Some(false)
+        } else if input.is_key_just_pressed(N)
+            || (league.finished() && input.is_key_just_pressed(Space))
+        {
+            *league = League::new();
+            league.save();
+            self.refresh(league, user_interface);
+
+            None
+        } else if input.is_key_just_pressed(Space) || input.is_key_just_pressed(Return) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    pub fn clear(&mut self, user_interface: &mut UserInterface) {
+        // The text is a child of the background, so it's removed with it.
+        //
+        self.background_h = remove_widget_node(self.background_h, user_interface);
+        self.text_h = Handle::NONE;
+    }
+
+    fn refresh(&self, league: &League, user_interface: &mut UserInterface) {
+        let played_rounds = league
+            .fixtures
+            .iter()
+            .filter(|f| f.result.is_some())
+            .map(|f| f.round + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut text = format!("LEAGUE - ROUND {}/{}\n\n", played_rounds, league.rounds());
+
+        text.push_str("TEAM - P W D L - GF GA - PTS\n");
+
+        for (position, standing) in league.standings().iter().enumerate() {
+            text.push_str(&format!(
+                "{}. {} - {} {} {} {} - {} {} - {}\n",
+                position + 1,
+                league.teams[standing.team].name,
+                standing.played,
+                standing.won,
+                standing.drawn,
+                standing.lost,
+                standing.goals_for,
+                standing.goals_against,
+                standing.points(),
+            ));
+        }
+
+        text.push('\n');
+
+        if let Some(fixture) = league.next_fixture() {
+            text.push_str(&format!(
+                "NEXT MATCH: {} - {}\n\nSPACE: PLAY - N: NEW LEAGUE - ESC: BACK",
+                league.teams[fixture.home].name, league.teams[fixture.away].name,
+            ));
+        } else {
+            let champions = league.standings()[0].team;
+
+            text.push_str(&format!(
+                "CHAMPIONS: {}\n\nSPACE: NEW LEAGUE - ESC: BACK",
+                league.teams[champions].name,
+            ));
+        }
+
+        update_widget_text(self.text_h, &text, user_interface);
+    }
+}
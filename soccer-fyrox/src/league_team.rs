@@ -0,0 +1,15 @@
+#[derive(Clone, PartialEq, Debug)]
+pub struct LeagueTeam {
+    pub name: String,
+    // None for the human team.
+    pub difficulty: Option<u8>,
+}
+
+impl LeagueTeam {
+    pub fn new(name: &str, difficulty: Option<u8>) -> Self {
+        Self {
+            name: name.to_string(),
+            difficulty,
+        }
+    }
+}
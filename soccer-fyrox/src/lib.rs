@@ -0,0 +1,171 @@
+#![allow(clippy::all)]
+#![deny(clippy::correctness)]
+
+pub mod anchor;
+pub mod arrow;
+pub mod audio_screen;
+pub mod audio_settings;
+pub mod ball;
+pub mod bare_actor;
+pub mod controls;
+pub mod controls_screen;
+pub mod data_file;
+pub mod debug_overlay;
+pub mod difficulty;
+pub mod draw_utils;
+pub mod fixture;
+pub mod formation;
+pub mod game;
+pub mod game_global;
+pub mod game_hud;
+pub mod game_over_screen;
+pub mod game_view;
+pub mod gamepad_button;
+pub mod goal;
+pub mod input_controller;
+pub mod kick;
+pub mod league;
+pub mod league_screen;
+pub mod league_team;
+pub mod match_clock;
+pub mod match_stats;
+pub mod math_utils;
+pub mod media;
+pub mod menu_screen;
+pub mod menu_state;
+pub mod my_actor;
+pub mod pitch;
+pub mod player;
+pub mod pools;
+pub mod position_utils;
+pub mod rect;
+pub mod replay;
+pub mod snapshot;
+pub mod sprites;
+pub mod standing;
+pub mod state;
+pub mod storage;
+pub mod tactic;
+pub mod tactics_screen;
+pub mod target;
+pub mod target_handle;
+pub mod team;
+pub mod team_input;
+pub mod team_tactics;
+
+pub mod prelude {
+    pub use fyrox::{
+        core::{
+            algebra::{Vector2, Vector3},
+            num_traits::Zero,
+            pool::{Handle, Pool},
+        },
+        event::VirtualKeyCode,
+        gui::{UiNode, UserInterface},
+        scene::{
+            base::BaseBuilder,
+            dim2::rectangle::{Rectangle, RectangleBuilder},
+            graph::Graph,
+            node::Node,
+            transform::TransformBuilder,
+            Scene,
+        },
+    };
+    pub use rand::{thread_rng, Rng};
+
+    pub use crate::anchor::Anchor;
+    pub use crate::arrow::Arrow;
+    pub use crate::audio_screen::AudioScreen;
+    pub use crate::audio_settings::{AudioSettings, SoundCategory, MAX_VOLUME};
+    pub use crate::ball::Ball;
+    pub use crate::bare_actor::BareActor;
+    pub use crate::controls::{ControlAction, Controls};
+    pub use crate::controls_screen::ControlsScreen;
+    pub use crate::debug_overlay::DebugOverlay;
+    pub use crate::difficulty::{Difficulty, DIFFICULTY};
+    pub use crate::draw_utils::*;
+    pub use crate::fixture::Fixture;
+    pub use crate::formation::Formation;
+    pub use crate::game::{
+        Game, DEFAULT_DIFFICULTY, LEAD_DISTANCE_1, LEAD_DISTANCE_2, PLAYER_START_POS,
+    };
+    pub use crate::game_hud::GameHud;
+    pub use crate::game_over_screen::GameOverScreen;
+    pub use crate::game_view::GameView;
+    pub use crate::gamepad_button::GamepadButton;
+    pub use crate::goal::Goal;
+    pub use crate::input_controller::InputController;
+    pub use crate::kick::Kick;
+    pub use crate::league::League;
+    pub use crate::league_screen::LeagueScreen;
+    pub use crate::league_team::LeagueTeam;
+    pub use crate::match_clock::{MatchClock, Overtime, Period};
+    pub use crate::match_stats::MatchStats;
+    pub use crate::math_utils::*;
+    pub use crate::media::{Media, BLANK_IMAGE};
+    pub use crate::menu_screen::MenuScreen;
+    pub use crate::menu_state::MenuState;
+    pub use crate::my_actor::MyActor;
+    pub use crate::pitch::Pitch;
+    pub use crate::player::Player;
+    pub use crate::pools::Pools;
+    pub use crate::position_utils::*;
+    pub use crate::rect::Rect;
+    pub use crate::replay::Replay;
+    pub use crate::snapshot::Snapshot;
+    pub use crate::sprites::Sprites;
+    pub use crate::standing::Standing;
+    pub use crate::state::State;
+    pub use crate::tactic::Tactic;
+    pub use crate::tactics_screen::TacticsScreen;
+    pub use crate::target::Target;
+    pub use crate::target_handle::TargetHandle;
+    pub use crate::team::Team;
+    pub use crate::team_input::TeamInput;
+    pub use crate::team_tactics::TeamTactics;
+    pub use soccer_macros_fyrox::my_actor_based;
+
+    pub const WIDTH: f32 = 800.;
+    pub const HEIGHT: f32 = 480.;
+
+    pub const HALF_WINDOW_W: f32 = WIDTH / 2.;
+
+    // The level and pitch sizes are match settings; see Pitch.
+
+    pub const GOAL_WIDTH: f32 = 186.;
+    pub const GOAL_DEPTH: f32 = 20.;
+    pub const HALF_GOAL_W: f32 = GOAL_WIDTH / 2.;
+
+    pub const DRIBBLE_DIST_X: f32 = 18.;
+    pub const DRIBBLE_DIST_Y: f32 = 16.;
+
+    pub const HUMAN_PLAYER_WITHOUT_BALL_SPEED: f32 = 3.3;
+
+    // The DEBUG_SHOW_* diagnostics are toggled at runtime; see DebugOverlay.
+
+    //# Ball physics model parameters
+    pub const KICK_STRENGTH: f32 = 11.5;
+    pub const DRAG: f32 = 0.98;
+
+    // The below are specific to the port; drawing sequence doesn't work in 3d-based engines; actually,
+    // using z-depth is more convenient, since draw calls can happen in any order.
+    // The priority on some sprites is based on their coordinates, so we use a min/max.
+
+    pub const CAMERA_NEAR_Z: f32 = -1.0;
+    pub const CAMERA_FAR_Z: f32 = 16.0;
+
+    pub const DRAW_MENU_Z: f32 = -1.0;
+
+    pub const DRAW_GAME_HUD_Z: f32 = 0.0;
+    pub const DRAW_GAME_SCORES_Z: f32 = -1.0; // need to override the top bar
+    pub const DRAW_PITCH_Z: f32 = 16.0;
+    pub const DRAW_GOAL_0_Z: f32 = 15.0;
+    pub const DRAW_PLAYERS_Z: (f32, f32) = (14.0, 13.0); // includes the ball
+    pub const DRAW_SHADOWS_Z: (f32, f32) = (12.0, 11.0); // includes the ball (shadow)
+    pub const DRAW_GOAL_1_Z: f32 = 10.0;
+    pub const DRAW_ARROWS_Z: f32 = 9.0;
+    pub const DRAW_DEBUG_Z: f32 = 8.0;
+
+    pub const DRAW_GAME_OVER_BACKGROUND_Z: f32 = 0.0;
+    pub const DRAW_GAME_OVER_SCORES_Z: f32 = -1.0;
+}
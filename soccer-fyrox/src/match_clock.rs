@@ -0,0 +1,174 @@
+// Rust: In the source project, the only end condition is the win score.
+
+// The game runs at a fixed rate.
+const FRAMES_PER_SECOND: u32 = 60;
+const HALF_TIME_BREAK: u32 = 3 * FRAMES_PER_SECOND;
+// As in real matches, where each half of extra time is 15', against halves of 45'.
+const EXTRA_TIME_RATIO: u32 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Overtime {
+    None,
+    ExtraTime,
+    GoldenGoal,
+}
+
+impl Overtime {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(Self::None),
+            "extra_time" => Some(Self::ExtraTime),
+            "golden_goal" => Some(Self::GoldenGoal),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Period {
+    FirstHalf,
+    HalfTime,
+    SecondHalf,
+    // The teams switch ends between the two halves of extra time, without a break.
+    ExtraTimeFirstHalf,
+    ExtraTimeSecondHalf,
+    // Open ended; the first goal wins.
+    GoldenGoal,
+    FullTime,
+}
+
+pub struct MatchClock {
+    // In frames; zero for untimed matches.
+    half_length: u32,
+    overtime: Overtime,
+    timed: bool,
+    period: Period,
+    frames_left: u32,
+}
+
+impl MatchClock {
+    // A half length of zero disables the clock, so that only the win score ends the match.
+    //
+    pub fn new(half_length_secs: u32, overtime: Overtime) -> Self {
+        let mut instance = Self {
+            half_length: half_length_secs * FRAMES_PER_SECOND,
+            overtime,
+            timed: false,
+            period: Period::FirstHalf,
+            frames_left: 0,
+        };
+
+        instance.reset(true);
+
+        instance
+    }
+
+    // The clock doesn't run when there are no human players (the menu background match).
+    //
+    pub fn reset(&mut self, timed: bool) {
+        self.timed = timed && self.half_length > 0;
+        self.period = Period::FirstHalf;
+        self.frames_left = self.half_length;
+    }
+
+    // During breaks, and after the final whistle, the players stand still.
+    //
+    pub fn stopped(&self) -> bool {
+        matches!(self.period, Period::HalfTime | Period::FullTime)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.period == Period::FullTime
+    }
+
+    // Advances the clock by a frame; `running` tells if the ball is in play (the clock stops
+    // during goal celebrations and before kickoffs), but doesn't affect breaks.
+    // Returns the new period, if there has been a change.
+    //
+    pub fn tick(&mut self, running: bool, scores_level: bool) -> Option<Period> {
+        use Period::*;
+
+        if !self.timed || self.period == GoldenGoal || self.period == FullTime {
+            return None;
+        }
+        if !running && self.period != HalfTime {
+            return None;
+        }
+
+        self.frames_left = self.frames_left.saturating_sub(1);
+
+        if self.frames_left > 0 {
+            return None;
+        }
+
+        self.period = match self.period {
+            FirstHalf => {
+                self.frames_left = HALF_TIME_BREAK;
+                HalfTime
+            }
+            HalfTime => {
+                self.frames_left = self.half_length;
+                SecondHalf
+            }
+            SecondHalf if scores_level => match self.overtime {
+                Overtime::None => FullTime,
+                Overtime::ExtraTime => {
+                    self.frames_left = self.extra_time_half_length();
+                    ExtraTimeFirstHalf
+                }
+                Overtime::GoldenGoal => GoldenGoal,
+            },
+            ExtraTimeFirstHalf => {
+                self.frames_left = self.extra_time_half_length();
+                ExtraTimeSecondHalf
+            }
+            SecondHalf | ExtraTimeSecondHalf => FullTime,
+            GoldenGoal | FullTime => unreachable!(),
+        };
+
+        Some(self.period)
+    }
+
+    fn extra_time_half_length(&self) -> u32 {
+        (self.half_length / EXTRA_TIME_RATIO).max(1)
+    }
+
+    pub fn goal_scored(&mut self) {
+        if self.period == Period::GoldenGoal {
+            self.period = Period::FullTime;
+        }
+    }
+
+    // Time left in the period, as MM:SS; None if there is nothing to display.
+    //
+    pub fn text(&self) -> Option<String> {
+        use Period::*;
+
+        if !self.timed {
+            return None;
+        }
+
+        match self.period {
+            FirstHalf | SecondHalf | ExtraTimeFirstHalf | ExtraTimeSecondHalf => {
+                let secs = (self.frames_left + FRAMES_PER_SECOND - 1) / FRAMES_PER_SECOND;
+                Some(format!("{:02}:{:02}", secs / 60, secs % 60))
+            }
+            GoldenGoal | HalfTime | FullTime => None,
+        }
+    }
+
+    pub fn banner(&self) -> Option<&'static str> {
+        use Period::*;
+
+        if !self.timed {
+            return None;
+        }
+
+        match self.period {
+            HalfTime => Some("HALF TIME"),
+            GoldenGoal => Some("GOLDEN GOAL"),
+            FullTime => Some("FULL TIME"),
+            FirstHalf | SecondHalf | ExtraTimeFirstHalf | ExtraTimeSecondHalf => None,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// Rust: Collected for the batch simulations (see the `soccer-sim` binary), which the source project
+// doesn't have.
+
+#[derive(Default)]
+pub struct MatchStats {
+    // Frames in which each team owned the ball.
+    pub possession: [u32; 2],
+    // Kicks towards a teammate, and how many of them were received by the same team.
+    pub passes: [u32; 2],
+    pub completed_passes: [u32; 2],
+    // Team of the pass in progress, until the ball is acquired by somebody.
+    pending_pass: Option<u8>,
+}
+
+impl MatchStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn owned(&mut self, team: u8) {
+        self.possession[team as usize] += 1;
+    }
+
+    pub fn passed(&mut self, team: u8) {
+        self.passes[team as usize] += 1;
+        self.pending_pass = Some(team);
+    }
+
+    pub fn acquired(&mut self, team: u8) {
+        if self.pending_pass.take() == Some(team) {
+            self.completed_passes[team as usize] += 1;
+        }
+    }
+
+    // A pass in progress when the play stops (e.g. a goal) is not completed.
+    //
+    pub fn play_stopped(&mut self) {
+        self.pending_pass = None;
+    }
+
+    // Adds the stats of another match; used for the totals.
+    //
+    pub fn accumulate(&mut self, other: &MatchStats) {
+        for team in 0..2 {
+            self.possession[team] += other.possession[team];
+            self.passes[team] += other.passes[team];
+            self.completed_passes[team] += other.completed_passes[team];
+        }
+    }
+
+    // Over the frames where the ball had an owner.
+    //
+    pub fn possession_ratio(&self, team: u8) -> f32 {
+        ratio(
+            self.possession[team as usize],
+            self.possession[0] + self.possession[1],
+        )
+    }
+
+    pub fn pass_completion(&self, team: u8) -> f32 {
+        ratio(
+            self.completed_passes[team as usize],
+            self.passes[team as usize],
+        )
+    }
+}
+
+fn ratio(count: u32, total: u32) -> f32 {
+    if total == 0 {
+        0.
+    } else {
+        count as f32 / total as f32
+    }
+}
@@ -26,17 +26,43 @@ const SOUNDS_PATH: &str = "sounds";
 const SUPPORTED_IMAGE_EXTENSIONS: &[&str] = &[".gif", ".png"];
 const SUPPORTED_SOUND_EXTENSIONS: &[&str] = &[".ogg"];
 
+// The crowd gain, as ratio of the crowd volume, when the ball is at the halfway line; it swells up to
+// the full volume as the ball nears a goal.
+const CROWD_CALM_GAIN: f32 = 0.5;
+// Maximum change of the crowd excitement per frame, so that the crowd doesn't jump with the ball
+// (e.g. on kickoffs).
+const CROWD_EXCITEMENT_STEP: f32 = 0.01;
+
+// A looping sound; the fades are in frames (the game runs at a fixed rate), and are applied on
+// top of the volume of the category.
+//
+struct LoopingSound {
+    node: Handle<Node>,
+    category: SoundCategory,
+    // From 0 (silent) to 1.
+    level: f32,
+    // Change of the level per frame; zero when not fading.
+    fade_step: f32,
+}
+
 // It's not easy to make the overall design of the program simple, since Fyrox requires several elements
 // to be carried around (scene, handles, resources...).
 // For a simple game like this, a simple type like this will do, and it will take care of everything.
 pub struct Media {
     image_textures: HashMap<String, Texture>,
     sound_resources: HashMap<String, SoundBufferResource>,
-    looping_sounds: HashMap<String, Handle<Node>>,
+    looping_sounds: HashMap<String, LoopingSound>,
+    // Removed from the looping sounds when the fade out starts, so that they can be restarted in the
+    // meantime; they're stopped when silent, unless faded in again.
+    fading_out_sounds: Vec<(String, LoopingSound)>,
+    audio_settings: AudioSettings,
+    // From 0 (ball at the halfway line) to 1 (ball at a goal line); see set_crowd_excitement().
+    crowd_excitement: f32,
+    crowd_target_excitement: f32,
 }
 
 impl Media {
-    pub fn new(resource_manager: &ResourceManager) -> Self {
+    pub fn new(resource_manager: &ResourceManager, audio_settings: AudioSettings) -> Self {
         let images_path = Self::resource_path(&[IMAGES_PATH]);
 
         let image_paths = read_dir(images_path)
@@ -113,7 +139,57 @@ impl Media {
             image_textures,
             sound_resources,
             looping_sounds,
+            fading_out_sounds: vec![],
+            audio_settings,
+            crowd_excitement: 0.,
+            crowd_target_excitement: 0.,
+        }
+    }
+
+    // Advances the fades and the crowd excitement, and applies the gains; must be called on each
+    // frame.
+    //
+    pub fn update(&mut self, scene: &mut Scene) {
+        self.crowd_excitement += (self.crowd_target_excitement - self.crowd_excitement)
+            .clamp(-CROWD_EXCITEMENT_STEP, CROWD_EXCITEMENT_STEP);
+
+        for sound in self
+            .looping_sounds
+            .values_mut()
+            .chain(self.fading_out_sounds.iter_mut().map(|(_, sound)| sound))
+        {
+            sound.level = (sound.level + sound.fade_step).clamp(0., 1.);
+
+            if sound.level == 0. || sound.level == 1. {
+                sound.fade_step = 0.;
+            }
+        }
+
+        for (_, sound) in self.fading_out_sounds.iter().filter(|(_, s)| s.level == 0.) {
+            scene.remove_node(sound.node);
         }
+        self.fading_out_sounds.retain(|(_, s)| s.level > 0.);
+
+        for sound in self
+            .looping_sounds
+            .values()
+            .chain(self.fading_out_sounds.iter().map(|(_, sound)| sound))
+        {
+            let gain = self.looping_sound_gain(sound);
+            scene.graph[sound.node].as_sound_mut().set_gain(gain);
+        }
+    }
+
+    // Applied from the next update().
+    //
+    pub fn set_audio_settings(&mut self, audio_settings: AudioSettings) {
+        self.audio_settings = audio_settings;
+    }
+
+    // From 0 (calm) to 1 (the ball is at a goal line); the crowd volume follows gradually.
+    //
+    pub fn set_crowd_excitement(&mut self, excitement: f32) {
+        self.crowd_target_excitement = excitement.clamp(0., 1.);
     }
 
     pub fn play_sound(&self, scene: &mut Scene, base: &str, indexes: &[u8]) {
@@ -123,6 +199,7 @@ impl Media {
             .with_buffer(Some(sound))
             .with_status(Status::Playing)
             .with_play_once(true)
+            .with_gain(self.audio_settings.gain(SoundCategory::Effects))
             .build(&mut scene.graph);
     }
 
@@ -135,15 +212,53 @@ impl Media {
     // advantage of the two points above.
     //
     pub fn play_looping_sound(&mut self, scene: &mut Scene, name: &str) {
-        let sound = self.sound(name, &[]);
+        self.start_looping_sound(scene, name, 1., 0.);
+    }
 
-        let node = SoundBuilder::new(BaseBuilder::new())
-            .with_buffer(Some(sound))
-            .with_looping(true)
-            .with_status(Status::Playing)
-            .build(&mut scene.graph);
+    // If the sound is already playing (or fading out), it's faded in from its current level.
+    //
+    pub fn fade_in_looping_sound(&mut self, scene: &mut Scene, name: &str, frames: u32) {
+        let fade_step = 1. / frames.max(1) as f32;
 
-        self.looping_sounds.insert(name.to_string(), node);
+        // A sound fading out is moved back to the looping ones, otherwise a second instance would be
+        // started (e.g. on a quick crossfade back and forth); if it has been restarted in the
+        // meantime, the fading out one just completes.
+        //
+        let fading_out = (!self.looping_sounds.contains_key(name))
+            .then(|| self.fading_out_sounds.iter().position(|(n, _)| n == name))
+            .flatten();
+
+        if let Some(i) = fading_out {
+            let (key, sound) = self.fading_out_sounds.remove(i);
+            self.looping_sounds.insert(key, sound);
+        }
+
+        if let Some(sound) = self.looping_sounds.get_mut(name) {
+            sound.fade_step = fade_step;
+        } else {
+            self.start_looping_sound(scene, name, 0., fade_step);
+        }
+    }
+
+    // Like stop_looping_sound(), but the sound is stopped once faded out; it can be restarted in the
+    // meantime.
+    //
+    pub fn fade_out_looping_sound(&mut self, base: &str, frames: u32) {
+        if let Some((name, mut sound)) = self.looping_sounds.remove_entry(base) {
+            sound.fade_step = -1. / frames.max(1) as f32;
+            self.fading_out_sounds.push((name, sound));
+        }
+    }
+
+    pub fn crossfade_looping_sounds(
+        &mut self,
+        scene: &mut Scene,
+        from: &str,
+        to: &str,
+        frames: u32,
+    ) {
+        self.fade_out_looping_sound(from, frames);
+        self.fade_in_looping_sound(scene, to, frames);
     }
 
     // The source project allows attempting to stop a sound that hasn't been started.
@@ -151,7 +266,8 @@ impl Media {
     // Looping sounds don't have an index (see play_sound()).
     //
     pub fn stop_looping_sound(&mut self, scene: &mut Scene, base: &str) {
-        if let Some(sound_h) = self.looping_sounds.remove(base) {
+        if let Some(sound) = self.looping_sounds.remove(base) {
+            let sound_h = sound.node;
             let sound = &mut scene.graph[sound_h];
 
             // Removing the node also stops the sound, so this is technically redundant.
@@ -162,6 +278,36 @@ impl Media {
         }
     }
 
+    fn start_looping_sound(&mut self, scene: &mut Scene, name: &str, level: f32, fade_step: f32) {
+        let sound = self.sound(name, &[]);
+
+        let mut looping_sound = LoopingSound {
+            node: Handle::NONE,
+            category: SoundCategory::of_looping_sound(name),
+            level,
+            fade_step,
+        };
+
+        looping_sound.node = SoundBuilder::new(BaseBuilder::new())
+            .with_buffer(Some(sound))
+            .with_looping(true)
+            .with_status(Status::Playing)
+            .with_gain(self.looping_sound_gain(&looping_sound))
+            .build(&mut scene.graph);
+
+        self.looping_sounds.insert(name.to_string(), looping_sound);
+    }
+
+    fn looping_sound_gain(&self, sound: &LoopingSound) -> f32 {
+        let crowd_gain = if sound.category == SoundCategory::Crowd {
+            CROWD_CALM_GAIN + (1. - CROWD_CALM_GAIN) * self.crowd_excitement
+        } else {
+            1.
+        };
+
+        self.audio_settings.gain(sound.category) * sound.level * crowd_gain
+    }
+
     pub fn image<S: AsRef<str> + Display>(&self, base: S, indexes: &[u8]) -> Texture {
         if indexes.len() > 3 {
             panic!();
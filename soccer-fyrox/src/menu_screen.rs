@@ -2,8 +2,14 @@ use crate::prelude::*;
 
 const IMG_BASE: &str = "menu";
 
+const HINT_Y: f32 = HEIGHT - 30.;
+const HINT_FONT_SIZE: f32 = 16.;
+const HINT_TEXT: &str = "C: CONTROLS - L: LEAGUE - A: AUDIO";
+
 pub struct MenuScreen {
     widget_h: Handle<UiNode>,
+    // Rust: The hint for the port-specific screens (controls, league and audio).
+    hint_h: Handle<UiNode>,
 }
 
 impl MenuScreen {
@@ -11,8 +17,9 @@ impl MenuScreen {
     //
     pub fn new(user_interface: &mut UserInterface, media: &Media) -> Self {
         let widget_h = Handle::NONE;
+        let hint_h = Handle::NONE;
 
-        let mut instance = Self { widget_h };
+        let mut instance = Self { widget_h, hint_h };
 
         instance.display(media, user_interface);
 
@@ -21,6 +28,7 @@ impl MenuScreen {
 
     pub fn display(&mut self, media: &Media, user_interface: &mut UserInterface) {
         self.widget_h = add_widget_node(0., 0., user_interface);
+        self.hint_h = add_text_widget_node(0., HINT_Y, WIDTH, HINT_FONT_SIZE, user_interface);
         self.update_selection(MenuState::NumPlayers, 1, 1, media, user_interface);
     }
 
@@ -37,6 +45,10 @@ impl MenuScreen {
         let image_indexes = match menu_state {
             NumPlayers => [0, menu_num_players],
             Difficulty => [1, menu_difficulty],
+            Controls => panic!("The controls are displayed by ControlsScreen"),
+            Tactics => panic!("The tactics are displayed by TacticsScreen"),
+            League => panic!("The league is displayed by LeagueScreen"),
+            Audio => panic!("The audio settings are displayed by AudioScreen"),
         };
 
         update_widget_texture(
@@ -46,9 +58,17 @@ impl MenuScreen {
             &image_indexes,
             user_interface,
         );
+
+        let hint = if menu_state == NumPlayers {
+            HINT_TEXT
+        } else {
+            ""
+        };
+        update_widget_text(self.hint_h, hint, user_interface);
     }
 
     pub fn clear(&mut self, user_interface: &mut UserInterface) {
         self.widget_h = remove_widget_node(self.widget_h, user_interface);
+        self.hint_h = remove_widget_node(self.hint_h, user_interface);
     }
 }
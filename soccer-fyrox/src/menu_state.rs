@@ -2,4 +2,12 @@
 pub enum MenuState {
     NumPlayers,
     Difficulty,
+    // Rust: Rebinding the controls; entered from the number of players selection.
+    Controls,
+    // Rust: Choosing the tactic; entered when starting a match.
+    Tactics,
+    // Rust: The league standings; entered from the number of players selection.
+    League,
+    // Rust: The audio levels; entered from the number of players selection.
+    Audio,
 }
@@ -0,0 +1,215 @@
+use crate::prelude::*;
+
+// Rust: In the source project, the pitch geometry and the team size are constants; the standard
+// pitch has the source values.
+
+// Size of the boundary surrounding the pitch (at each side); it's the same for all the pitch sizes.
+const BOUNDARY_W: f32 = 58.;
+const BOUNDARY_H: f32 = 78.;
+
+// How far inside the level the computer players chasing the ball owner stay.
+const AI_MARGIN_X: f32 = 78.;
+const AI_MARGIN_Y: f32 = 98.;
+
+const STANDARD_HALF_PITCH_W: f32 = 442.;
+const STANDARD_HALF_PITCH_H: f32 = 622.;
+const STANDARD_PITCH_IMG: &str = "pitch";
+
+// Five-a-side; the level is as wide as the window. The image is the standard one, with bands of
+// grass cut out between the lines, so that the boundary and the goal mouths keep their size.
+const SMALL_PITCH_IMG: &str = "pitch_small";
+const SMALL_HALF_PITCH_W: f32 = 342.;
+const SMALL_HALF_PITCH_H: f32 = 472.;
+const SMALL_PLAYERS_PER_TEAM: usize = 5;
+
+#[derive(Clone)]
+pub struct Pitch {
+    //# Size of level, including both the pitch and the boundary surrounding it
+    pub level_w: f32,
+    pub level_h: f32,
+    pub half_level_w: f32,
+    pub half_level_h: f32,
+
+    pub half_pitch_w: f32,
+    pub half_pitch_h: f32,
+
+    pub players_per_team: usize,
+
+    // Drawn at the level size, unscaled.
+    pub img_base: &'static str,
+}
+
+impl Pitch {
+    pub fn new(
+        half_pitch_w: f32,
+        half_pitch_h: f32,
+        players_per_team: usize,
+        img_base: &'static str,
+    ) -> Self {
+        let level_w = (half_pitch_w + BOUNDARY_W) * 2.;
+        let level_h = (half_pitch_h + BOUNDARY_H) * 2.;
+
+        // The camera scrolls inside the level, so it can't be smaller than the window.
+        //
+        assert!(
+            level_w >= WIDTH && level_h >= HEIGHT,
+            "The level ({level_w}x{level_h}) must be at least as large as the window"
+        );
+        assert!(
+            half_pitch_w > HALF_GOAL_W,
+            "The pitch must be wider than the goals"
+        );
+        assert!(
+            Formation::supported(players_per_team),
+            "Unsupported number of players per team: {players_per_team}"
+        );
+
+        Self {
+            level_w,
+            level_h,
+            half_level_w: level_w / 2.,
+            half_level_h: level_h / 2.,
+            half_pitch_w,
+            half_pitch_h,
+            players_per_team,
+            img_base,
+        }
+    }
+
+    // `standard` (the source project pitch, seven-a-side) or `small` (five-a-side).
+    //
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "standard" => Some(Self::default()),
+            "small" => Some(Self::new(
+                SMALL_HALF_PITCH_W,
+                SMALL_HALF_PITCH_H,
+                SMALL_PLAYERS_PER_TEAM,
+                SMALL_PITCH_IMG,
+            )),
+            _ => None,
+        }
+    }
+
+    // Same geometry, with a different team size.
+    //
+    pub fn with_players_per_team(&self, players_per_team: usize) -> Self {
+        Self::new(
+            self.half_pitch_w,
+            self.half_pitch_h,
+            players_per_team,
+            self.img_base,
+        )
+    }
+
+    pub fn bounds_x(&self) -> (f32, f32) {
+        (
+            self.half_level_w - self.half_pitch_w,
+            self.half_level_w + self.half_pitch_w,
+        )
+    }
+
+    pub fn bounds_y(&self) -> (f32, f32) {
+        (
+            self.half_level_h - self.half_pitch_h,
+            self.half_level_h + self.half_pitch_h,
+        )
+    }
+
+    pub fn goal_bounds_x(&self) -> (f32, f32) {
+        (
+            self.half_level_w - HALF_GOAL_W,
+            self.half_level_w + HALF_GOAL_W,
+        )
+    }
+
+    pub fn goal_bounds_y(&self) -> (f32, f32) {
+        (
+            self.half_level_h - self.half_pitch_h - GOAL_DEPTH,
+            self.half_level_h + self.half_pitch_h + GOAL_DEPTH,
+        )
+    }
+
+    pub fn rect(&self) -> Rect {
+        Rect::new(
+            self.bounds_x().0,
+            self.bounds_y().0,
+            self.half_pitch_w * 2.,
+            self.half_pitch_h * 2.,
+        )
+    }
+
+    pub fn goal_rects(&self) -> [Rect; 2] {
+        let (goal_bounds_x, goal_bounds_y) = (self.goal_bounds_x(), self.goal_bounds_y());
+
+        [
+            Rect::new(goal_bounds_x.0, goal_bounds_y.0, GOAL_WIDTH, GOAL_DEPTH),
+            Rect::new(
+                goal_bounds_x.0,
+                goal_bounds_y.1 - GOAL_DEPTH,
+                GOAL_WIDTH,
+                GOAL_DEPTH,
+            ),
+        ]
+    }
+
+    // Where the computer players chasing the ball owner can go, as (min, max) for each axis.
+    //
+    pub fn ai_bounds(&self) -> ((f32, f32), (f32, f32)) {
+        (
+            (AI_MARGIN_X, self.level_w - AI_MARGIN_X),
+            (AI_MARGIN_Y, self.level_h - AI_MARGIN_Y),
+        )
+    }
+
+    // The formations are defined for the standard pitch; this maps a position to this pitch,
+    // proportionally to the distance from the centre spot.
+    //
+    pub fn scale_position(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (standard_half_level_w, standard_half_level_h) = (
+            STANDARD_HALF_PITCH_W + BOUNDARY_W,
+            STANDARD_HALF_PITCH_H + BOUNDARY_H,
+        );
+
+        (
+            self.half_level_w + (x - standard_half_level_w) * self.scale_x(),
+            self.half_level_h + (y - standard_half_level_h) * self.scale_y(),
+        )
+    }
+
+    pub fn scale_x(&self) -> f32 {
+        self.half_pitch_w / STANDARD_HALF_PITCH_W
+    }
+
+    pub fn scale_y(&self) -> f32 {
+        self.half_pitch_h / STANDARD_HALF_PITCH_H
+    }
+
+    // How near the position is to the nearest goal line, from 0 (halfway line) to 1 (goal line, or
+    // beyond it).
+    //
+    pub fn goal_proximity(&self, pos: Vector2<f32>) -> f32 {
+        ((pos.y - self.half_level_h).abs() / self.half_pitch_h).min(1.)
+    }
+
+    // Top left of the visible area, for the camera centered on the focus, without showing anything
+    // outside the level.
+    //
+    pub fn camera_offset(&self, focus: Vector2<f32>) -> Vector2<f32> {
+        Vector2::new(
+            (focus.x - WIDTH / 2.).clamp(0., self.level_w - WIDTH),
+            (focus.y - HEIGHT / 2.).clamp(0., self.level_h - HEIGHT),
+        )
+    }
+}
+
+impl Default for Pitch {
+    fn default() -> Self {
+        Self::new(
+            STANDARD_HALF_PITCH_W,
+            STANDARD_HALF_PITCH_H,
+            PLAYER_START_POS.len(),
+            STANDARD_PITCH_IMG,
+        )
+    }
+}
@@ -2,10 +2,11 @@ use crate::prelude::*;
 
 const ANCHOR: Vector2<f32> = Vector2::new(25., 37.);
 
-pub const AI_MIN_X: f32 = 78.;
-pub const AI_MAX_X: f32 = LEVEL_W - 78.;
-pub const AI_MIN_Y: f32 = 98.;
-pub const AI_MAX_Y: f32 = LEVEL_H - 98.;
+// Rust: The bounds of the computer players chasing the ball owner (AI_MIN_X etc. in the source
+// project) depend on the pitch; see Pitch::ai_bounds().
+
+// Distance of the kickoff positions from the halfway line, on the standard pitch.
+const KICKOFF_OFFSET_Y: f32 = 200.;
 
 //# Speeds for players in various situations. Speeds including 'BASE' can be boosted by the speed_boost difficulty
 //# setting (only for players on a computer-controlled team)
@@ -17,22 +18,22 @@ pub const HUMAN_PLAYER_WITH_BALL_SPEED: f32 = 3.0;
 
 //# Return True if the given position is inside the level area, otherwise False
 //# Takes the goals into account so you can't run through them
-fn allow_movement(x: f32, y: f32) -> bool {
-    if (x - HALF_LEVEL_W).abs() > HALF_LEVEL_W {
+fn allow_movement(x: f32, y: f32, pitch: &Pitch) -> bool {
+    if (x - pitch.half_level_w).abs() > pitch.half_level_w {
         //# Trying to walk off the left or right side of the level
         false
-    } else if (x - HALF_LEVEL_W).abs() < HALF_GOAL_W + 20. {
+    } else if (x - pitch.half_level_w).abs() < HALF_GOAL_W + 20. {
         //# Player is within the bounds of the goals on the X axis, don't let them walk into, through or behind the goal
         //# +20 takes with of player sprite into account
-        (y - HALF_LEVEL_H).abs() < HALF_PITCH_H
+        (y - pitch.half_level_h).abs() < pitch.half_pitch_h
     } else {
         //# Player is outside the bounds of the goals on the X axis, so they can walk off the pitch and to the edge
         //# of the level
-        (y - HALF_LEVEL_H).abs() < HALF_LEVEL_H
+        (y - pitch.half_level_h).abs() < pitch.half_level_h
     }
 }
 
-#[my_actor_based]
+#[my_actor_based(img_base = BLANK_IMAGE, anchor = Anchor::Custom(ANCHOR))]
 pub struct Player {
     pub peer: Handle<Player>,
     pub mark: TargetHandle,
@@ -46,17 +47,19 @@ pub struct Player {
     anim_frame: i8,
     pub timer: i32,
     pub shadow: BareActor,
+    //# Used when DEBUG_SHOW_TARGETS is on
+    pub debug_target: Vector2<f32>,
 }
 
 impl Player {
-    pub fn new(x: f32, y: f32, team: u8, graph: &mut Graph) -> Self {
+    pub fn new(x: f32, y: f32, team: u8, pitch: &Pitch) -> Self {
         //# Player objects are reset each time there is a kickoff
 
-        let img_base = BLANK_IMAGE;
+        let img_base = Self::DEFAULT_IMG_BASE;
         let img_indexes = vec![];
 
         //# Used when DEBUG_SHOW_TARGETS is on
-        //self.debug_target = Vector2(0, 0)
+        let debug_target = Vector2::zero();
 
         // This is set by Game() at the beginning, and never reset.
         //
@@ -71,15 +74,13 @@ impl Player {
         let dir = 0;
         let anim_frame = 0;
         let timer = 0;
-        let shadow = BareActor::new(BLANK_IMAGE, None, Anchor::Custom(ANCHOR), graph);
-
-        let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(graph);
+        let shadow = BareActor::new(BLANK_IMAGE, None, Self::DEFAULT_ANCHOR);
 
         let mut instance = Self {
             vpos,
             img_base,
             img_indexes,
-            anchor: Anchor::Custom(ANCHOR),
+            anchor: Self::DEFAULT_ANCHOR,
             peer,
             mark,
             lead,
@@ -89,16 +90,17 @@ impl Player {
             anim_frame,
             timer,
             shadow,
-            rectangle_h,
+            debug_target,
         };
 
-        instance.reset(x, y, team, graph);
+        instance.reset(x, y, team, pitch);
 
         instance
     }
 
-    pub fn reset(&mut self, x: f32, y: f32, team: u8, graph: &mut Graph) {
+    pub fn reset(&mut self, x: f32, y: f32, end: u8, pitch: &Pitch) {
         //# Team will be 0 or 1
+        // Rust: Since the teams switch ends, this is the end (see Team) rather than the team.
         //# The x and y values supplied represent our 'home' position - the place we'll return to by default when not near
         //# the ball. However, on creation, we want players to be in their kickoff positions, which means all players from
         //# team 0 will be below the halfway line, and players from team 1 above. The player chosen to actually do the
@@ -106,7 +108,9 @@ impl Player {
 
         //# Calculate our initial position for kickoff by halving y, adding 550 and then subtracting either 400 for
         //# team 1, or nothing for team 0
-        let kickoff_y = (y / 2.) + 550. - (team as f32 * 400.);
+        // Rust: The formula is generalized to the pitch size; on the standard pitch, it's the same.
+        let kickoff_offset_y = KICKOFF_OFFSET_Y * pitch.scale_y() * (1. - 2. * end as f32);
+        let kickoff_y = pitch.half_level_h + (y - pitch.half_level_h) / 2. + kickoff_offset_y;
 
         self.vpos = Vector2::new(x, kickoff_y);
         self.mark = TargetHandle::None;
@@ -115,14 +119,14 @@ impl Player {
         self.dir = 0;
         self.anim_frame = -1;
         self.timer = 0;
-        self.shadow = BareActor::new(BLANK_IMAGE, None, Anchor::Custom(ANCHOR), graph);
+        self.shadow = BareActor::new(BLANK_IMAGE, None, Self::DEFAULT_ANCHOR);
     }
 
     // An option is to pass all the Game fields individually, but this is simpler.
     //
     // this implementation is the simplest (no tickets passed around), but 1. is also a simple alternative.
     //
-    pub fn update(player_h: Handle<Player>, game: &mut Game, input: &InputController) {
+    pub fn update(player_h: Handle<Player>, game: &mut Game) {
         // Can't keep mutably borrowed over the whole function; mutably reborrowed at the end.
         game.pools.players.borrow_mut(player_h).timer -= 1;
 
@@ -155,7 +159,7 @@ impl Player {
             };
 
             //# Find target by calling the controller for the player's team todo comment
-            target = player.vpos + my_team.controls.as_ref().unwrap().move_player(speed, input);
+            target = player.vpos + my_team.input.movement * speed;
         } else if let Some(ball_owner_h) = game.ball.owner {
             let ball_owner = game.pools.players.borrow(ball_owner_h);
 
@@ -184,8 +188,10 @@ impl Player {
                     cost(
                         player.vpos + angle_to_vec(angle) * 3.,
                         player.team,
+                        my_team.end,
                         d.unsigned_abs(),
                         &game.pools.players,
+                        &game.pitch,
                     )
                 });
 
@@ -206,7 +212,7 @@ impl Player {
                     .unwrap();
 
                 //# speed depends on difficulty
-                speed = CPU_PLAYER_WITH_BALL_BASE_SPEED + game.difficulty.speed_boost
+                speed = CPU_PLAYER_WITH_BALL_BASE_SPEED + my_team.difficulty.speed_boost
             } else if ball_owner.team == player.team {
                 //# Ball is owned by another player on our team
                 if player.active(&game.ball) {
@@ -214,9 +220,11 @@ impl Player {
                     //# don't want all players running to the same place. Target is halfway between home and a point
                     //# 400 pixels ahead of the ball. Team 0 are trying to score in the goal at the top of the
                     //# pitch, team 1 the goal at the bottom
-                    let direction = if player.team == 0 { -1. } else { 1. };
+                    // Rust: The distance ahead of the ball depends on the tactic.
+                    let direction = if my_team.end == 0 { -1. } else { 1. };
+                    let run_distance = my_team.tactics.tactic.support_run_distance;
                     target.x = (game.ball.vpos.x + target.x) / 2.;
-                    target.y = (game.ball.vpos.y + 400. * direction + target.y) / 2.;
+                    target.y = (game.ball.vpos.y + run_distance * direction + target.y) / 2.;
                 }
                 //# If we're not active, we'll do the default action of moving towards our home position
             } else {
@@ -232,14 +240,15 @@ impl Player {
                     target = ball_owner.vpos + angle_to_vec(ball_owner.dir) * player.lead.unwrap();
 
                     //# Stay on the pitch
-                    target.x = target.x.clamp(AI_MIN_X, AI_MAX_X);
-                    target.y = target.y.clamp(AI_MIN_Y, AI_MAX_Y);
+                    let (ai_bounds_x, ai_bounds_y) = game.pitch.ai_bounds();
+                    target.x = target.x.clamp(ai_bounds_x.0, ai_bounds_x.1);
+                    target.y = target.y.clamp(ai_bounds_y.0, ai_bounds_y.1);
 
                     // Bug here, fixed (was: `other_team = 1 if player.team == 0 else 1`)
                     let other_team = if player.team == 0 { 1 } else { 0 };
                     speed = LEAD_PLAYER_BASE_SPEED;
                     if game.teams[other_team].human() {
-                        speed += game.difficulty.speed_boost;
+                        speed += my_team.difficulty.speed_boost;
                     }
                 } else if mark_active {
                     //# The player or goal we've been chosen to mark is active
@@ -256,13 +265,15 @@ impl Player {
 
                         //# Alter length to choose a position in between the ball and whatever we're marking
                         //# We don't apply this behaviour for human teams - in that case we just run straight at the ball
+                        // Rust: The distances depend on the tactic; the source values are the default.
+                        let tactic = &my_team.tactics.tactic;
                         if player.mark.is_goal() {
                             //# If I'm currently the goalie, get in between the ball and goal, and don't get too far
                             //# from the goal
-                            length = 150_f32.min(length);
+                            length = tactic.goalie_distance.min(length);
                         } else {
                             //# Otherwise, just get halfway between the ball and whoever I'm marking
-                            length /= 2.;
+                            length *= tactic.mark_ratio;
                         }
 
                         target = mark_vpos + nvec * length
@@ -315,12 +326,12 @@ impl Player {
         //# vec[0] and vec[1] will be the x and y components of the vector
         let (vek, mut distance) = safe_normalise(&(target - player.vpos));
 
-        //self.debug_target = Vector2(target)
-
         let target_dir;
 
         let player = game.pools.players.borrow_mut(player_h);
 
+        player.debug_target = target;
+
         //# Check to see if we're already at the target position
         if distance > 0. {
             //# Limit movement to our max speed
@@ -332,10 +343,10 @@ impl Player {
             //# Update the x and y components of the player's position - but don't allow them to go off the edge of the
             //# level. Processing the x and y components separately allows the player to slide along the edge when trying
             //# to move diagonally off the edge of the level.
-            if allow_movement(player.vpos.x + vek.x * distance, player.vpos.y) {
+            if allow_movement(player.vpos.x + vek.x * distance, player.vpos.y, &game.pitch) {
                 player.vpos.x += vek.x * distance;
             }
-            if allow_movement(player.vpos.x, player.vpos.y + vek.y * distance) {
+            if allow_movement(player.vpos.x, player.vpos.y + vek.y * distance, &game.pitch) {
                 player.vpos.y += vek.y * distance;
             }
 
@@ -8,16 +8,30 @@ use crate::prelude::*;
 //# - the proximity of players on the other team – we want to get the ball away from them as much as possible
 //# - a quadratic equation (don’t panic too much!) causing the player to favour the centre of the pitch and their opponents goal
 //# - an optional handicap value which can bias the result towards or away from a particular position
+//
+// Rust: The direction is based on the end the team attacks, since the teams switch ends at half time.
+// The goal positions depend on the pitch size.
+//
 pub fn cost(
     pos: Vector2<f32>,
     team: u8,
+    end: u8,
     handicap: u8,
     players_pool: &Pool<Player>,
+    pitch: &Pitch,
 ) -> (f32, Vector2<f32>) {
     //# Get pos of our own goal. We do it this way rather than getting the pos of the actual goal object
     //# because this way gives us the pos of the goal's entrance, whereas the actual goal sprites are not anchored based
     //# on the entrances.
-    let own_goal_pos = Vector2::new(HALF_LEVEL_W, if team == 1 { 78. } else { LEVEL_H - 78. });
+    let (goal_line_top, goal_line_bottom) = pitch.bounds_y();
+    let own_goal_pos = Vector2::new(
+        pitch.half_level_w,
+        if end == 1 {
+            goal_line_top
+        } else {
+            goal_line_bottom
+        },
+    );
     let inverse_own_goal_distance = 3500. / (pos - own_goal_pos).norm();
 
     let result = inverse_own_goal_distance
@@ -26,7 +40,7 @@ pub fn cost(
             .filter(|p| p.team != team)
             .map(|p| 4000. / 24_f32.max((p.vpos - pos).norm()))
             .sum::<f32>()
-        + ((pos.x - HALF_LEVEL_W).powi(2) / 200. - pos.y * (4. * team as f32 - 2.))
+        + ((pos.x - pitch.half_level_w).powi(2) / 200. - pos.y * (4. * end as f32 - 2.))
         + handicap as f32;
 
     (result, pos)
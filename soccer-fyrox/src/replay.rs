@@ -0,0 +1,76 @@
+use std::collections::VecDeque;
+
+use crate::prelude::*;
+
+// Rust: The last seconds of play before a goal, recorded in a ring buffer, and played back in slow
+// motion before the kickoff; the source project has no replays.
+
+const REPLAY_FRAMES: usize = 150;
+// Frames each recorded frame is displayed for.
+const SLOWDOWN: usize = 2;
+
+pub struct Replay {
+    frames: VecDeque<Snapshot>,
+    // Playback frames elapsed, while playing.
+    playback: Option<usize>,
+}
+
+impl Replay {
+    pub fn new() -> Self {
+        Self {
+            frames: VecDeque::with_capacity(REPLAY_FRAMES),
+            playback: None,
+        }
+    }
+
+    // Once full, the oldest frame is dropped.
+    //
+    pub fn record(&mut self, snapshot: Snapshot) {
+        if self.frames.len() == REPLAY_FRAMES {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(snapshot);
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.playback = None;
+    }
+
+    // Returns false if there is nothing to play.
+    //
+    pub fn start(&mut self) -> bool {
+        if self.frames.is_empty() {
+            return false;
+        }
+
+        self.playback = Some(0);
+
+        true
+    }
+
+    pub fn playing(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    // Returns false once the playback is over.
+    //
+    pub fn advance(&mut self) -> bool {
+        if let Some(playback) = &mut self.playback {
+            *playback += 1;
+
+            if *playback / SLOWDOWN < self.frames.len() {
+                return true;
+            }
+        }
+
+        self.playback = None;
+
+        false
+    }
+
+    pub fn current(&self) -> Option<&Snapshot> {
+        self.playback
+            .and_then(|playback| self.frames.get(playback / SLOWDOWN))
+    }
+}
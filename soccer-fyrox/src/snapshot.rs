@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+// Rust: The drawable state of a frame: the sprites of the players and the ball, and the camera
+// focus. GameView draws a snapshot of the current frame, or, during the replays, a recorded one
+// (see Replay). The source project draws the actors directly.
+//
+pub struct Snapshot {
+    pub players: Vec<BareActor>,
+    pub shadows: Vec<BareActor>,
+    // Raised by the ball height, while the shadow is on the ground.
+    pub ball: BareActor,
+    pub ball_shadow: BareActor,
+    pub camera_focus: Vector2<f32>,
+}
+
+impl Snapshot {
+    pub fn capture(game: &Game) -> Self {
+        let players = game
+            .pools
+            .players
+            .iter()
+            .map(|player| Self::sprite(player))
+            .collect();
+        let shadows = game
+            .pools
+            .players
+            .iter()
+            .map(|player| Self::sprite(&player.shadow))
+            .collect();
+
+        let mut ball = Self::sprite(&game.ball);
+        ball.vpos.y -= game.ball.height;
+
+        Self {
+            players,
+            shadows,
+            ball,
+            ball_shadow: Self::sprite(&game.ball.shadow),
+            camera_focus: game.camera_focus,
+        }
+    }
+
+    fn sprite(actor: &dyn MyActor) -> BareActor {
+        let mut sprite = BareActor::new(actor.img_base(), None, actor.anchor());
+        sprite.img_indexes = actor.img_indexes().to_vec();
+        sprite.vpos = actor.vpos();
+        sprite
+    }
+}
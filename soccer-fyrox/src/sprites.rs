@@ -0,0 +1,68 @@
+use crate::prelude::*;
+
+// Draws the actors, via a pool of rectangle nodes.
+//
+// The actors don't own scene nodes (see MyActor); on each frame, the nodes are assigned to the actors
+// in drawing order, and the nodes left over are hidden. Since the order is the same on each frame,
+// in practice, each actor keeps using the same node.
+//
+pub struct Sprites {
+    nodes: Vec<Handle<Node>>,
+    used: usize,
+}
+
+impl Sprites {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![],
+            used: 0,
+        }
+    }
+
+    pub fn begin(&mut self) {
+        self.used = 0;
+    }
+
+    // Draws an actor that has a fixed drawing depth (see MyActor::z_layer()).
+    //
+    pub fn draw_layered(&mut self, actor: &dyn MyActor, scene: &mut Scene, media: &Media) {
+        let z = actor
+            .z_layer()
+            .expect("The actor doesn't have a fixed drawing depth");
+
+        self.draw(actor, scene, media, z);
+    }
+
+    pub fn draw(&mut self, actor: &dyn MyActor, scene: &mut Scene, media: &Media, z: f32) {
+        let rectangle_h = match self.nodes.get(self.used) {
+            Some(rectangle_h) => *rectangle_h,
+            None => {
+                let rectangle_h = RectangleBuilder::new(BaseBuilder::new()).build(&mut scene.graph);
+                self.nodes.push(rectangle_h);
+                rectangle_h
+            }
+        };
+        self.used += 1;
+
+        let texture = media.image(actor.img_base(), actor.img_indexes());
+        let (fyrox_coords, texture_dims) =
+            to_fyrox_coordinates(actor.vpos().x, actor.vpos().y, z, actor.anchor(), &texture);
+
+        let frame = scene.graph[rectangle_h].as_rectangle_mut();
+
+        frame.set_texture(Some(texture));
+        frame.set_local_transform(
+            TransformBuilder::new()
+                .with_local_position(Vector3::new(fyrox_coords.x, fyrox_coords.y, z))
+                .with_local_scale(Vector3::new(texture_dims.x, texture_dims.y, f32::EPSILON))
+                .build(),
+        );
+        frame.set_visibility(true);
+    }
+
+    pub fn end(&mut self, scene: &mut Scene) {
+        for rectangle_h in &self.nodes[self.used..] {
+            scene.graph[*rectangle_h].set_visibility(false);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+const WIN_POINTS: u32 = 3;
+const DRAW_POINTS: u32 = 1;
+
+// A row of the league table.
+//
+#[derive(Clone, Copy, Default)]
+pub struct Standing {
+    pub team: usize,
+    pub played: u32,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub goals_for: u32,
+    pub goals_against: u32,
+}
+
+impl Standing {
+    pub fn new(team: usize) -> Self {
+        Self {
+            team,
+            ..Self::default()
+        }
+    }
+
+    pub fn add_result(&mut self, scored: u8, conceded: u8) {
+        self.played += 1;
+        self.goals_for += scored as u32;
+        self.goals_against += conceded as u32;
+
+        if scored > conceded {
+            self.won += 1;
+        } else if scored == conceded {
+            self.drawn += 1;
+        } else {
+            self.lost += 1;
+        }
+    }
+
+    pub fn points(&self) -> u32 {
+        self.won * WIN_POINTS + self.drawn * DRAW_POINTS
+    }
+
+    pub fn goal_difference(&self) -> i32 {
+        self.goals_for as i32 - self.goals_against as i32
+    }
+}
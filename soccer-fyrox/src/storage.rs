@@ -0,0 +1,35 @@
+use std::{fs, path::PathBuf};
+
+// Rust: The source project doesn't persist anything. The controls (controls.txt), the audio levels
+// (audio.txt) and the league in progress (league.txt) are stored in the `soccer-fyrox` directory
+// of the user data directory; the resources are read-only, so they can't go in `resources/`.
+
+const DATA_DIR: &str = "soccer-fyrox";
+
+// If the platform doesn't define a user data directory, the files go in the working directory.
+//
+fn path(name: &str) -> PathBuf {
+    dirs::data_dir()
+        .map(|data_dir| data_dir.join(DATA_DIR))
+        .unwrap_or_default()
+        .join(name)
+}
+
+// None if the file has never been written (first run), or can't be read; the callers then use
+// their defaults.
+//
+pub fn read(name: &str) -> Option<String> {
+    fs::read_to_string(path(name)).ok()
+}
+
+// Errors are ignored: the settings and the league then just don't survive the session.
+//
+pub fn write(name: &str, data: &str) {
+    let path = path(name);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).ok();
+    }
+
+    fs::write(path, data).ok();
+}
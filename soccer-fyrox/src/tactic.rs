@@ -0,0 +1,76 @@
+use crate::data_file;
+use crate::prelude::*;
+
+// Rust: In the source project, the AI parameters are fixed; the defaults are the source values.
+
+const TACTICS_FILE: &[&str] = &["tactics", "tactics.txt"];
+
+#[derive(Clone)]
+pub struct Tactic {
+    pub name: String,
+    // How far ahead of the opposing ball owner the first and second lead players run.
+    pub lead_distances: [f32; 2],
+    // Where the markers stand, from the marked player (0) to the ball (1).
+    pub mark_ratio: f32,
+    // Maximum distance of the goalie from the goal.
+    pub goalie_distance: f32,
+    // How far ahead of the ball the teammates of the ball owner run.
+    pub support_run_distance: f32,
+}
+
+impl Tactic {
+    // The tactics in the data file; if it's missing, only the default one.
+    //
+    pub fn load_all() -> Vec<Self> {
+        let tactics = data_file::read_sections(TACTICS_FILE)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, lines)| Self::parse(name, &lines))
+            .collect::<Vec<_>>();
+
+        if tactics.is_empty() {
+            vec![Self::default()]
+        } else {
+            tactics
+        }
+    }
+
+    // Missing parameters keep the default value.
+    //
+    fn parse(name: String, lines: &[String]) -> Self {
+        let mut tactic = Self {
+            name,
+            ..Self::default()
+        };
+
+        for line in lines {
+            let (key, value) = line
+                .split_once('=')
+                .and_then(|(key, value)| Some((key.trim(), value.trim().parse().ok()?)))
+                .unwrap_or_else(|| panic!("Invalid line in tactic {}: {line}", tactic.name));
+
+            match key {
+                "lead_distance_1" => tactic.lead_distances[0] = value,
+                "lead_distance_2" => tactic.lead_distances[1] = value,
+                "mark_ratio" => tactic.mark_ratio = value,
+                "goalie_distance" => tactic.goalie_distance = value,
+                "support_run_distance" => tactic.support_run_distance = value,
+                _ => panic!("Unknown parameter in tactic {}: {key}", tactic.name),
+            }
+        }
+
+        tactic
+    }
+}
+
+impl Default for Tactic {
+    fn default() -> Self {
+        Self {
+            name: String::from("balanced"),
+            lead_distances: [LEAD_DISTANCE_1, LEAD_DISTANCE_2],
+            mark_ratio: 0.5,
+            goalie_distance: 150.,
+            support_run_distance: 400.,
+        }
+    }
+}
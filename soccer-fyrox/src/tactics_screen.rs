@@ -0,0 +1,147 @@
+use fyrox::{
+    core::color::Color,
+    gui::{border::BorderBuilder, brush::Brush, widget::WidgetBuilder},
+};
+
+use crate::prelude::*;
+
+// Rust: The tactics selection, displayed before kickoff; the source project has a single tactic.
+
+const BACKGROUND_COLOR: Color = Color::from_rgba(0, 0, 0, 200);
+const FONT_SIZE: f32 = 20.;
+const TEXT_Y: f32 = 80.;
+
+// Formation and tactic, for each team.
+const ENTRIES_COUNT: usize = 4;
+
+pub struct TacticsScreen {
+    background_h: Handle<UiNode>,
+    text_h: Handle<UiNode>,
+    formations: Vec<Formation>,
+    tactics: Vec<Tactic>,
+    // Indexes of the formation and tactic of each team; kept between matches.
+    selections: [[usize; 2]; 2],
+    // Index of the team * 2, plus 0 for the formation or 1 for the tactic.
+    selected: usize,
+    human_teams: [bool; 2],
+}
+
+impl TacticsScreen {
+    // Loads the formations (for the team size) and tactics, but doesn't display the screen.
+    //
+    pub fn new(players_per_team: usize) -> Self {
+        Self {
+            background_h: Handle::NONE,
+            text_h: Handle::NONE,
+            formations: Formation::load_all(players_per_team),
+            tactics: Tactic::load_all(),
+            selections: [[0, 0], [0, 0]],
+            selected: 0,
+            human_teams: [true, false],
+        }
+    }
+
+    pub fn display(&mut self, human_teams: [bool; 2], user_interface: &mut UserInterface) {
+        self.selected = 0;
+        self.human_teams = human_teams;
+
+        self.text_h = add_text_widget_node(0., TEXT_Y, WIDTH, FONT_SIZE, user_interface);
+
+        self.background_h = BorderBuilder::new(
+            WidgetBuilder::new()
+                .with_width(WIDTH)
+                .with_height(HEIGHT)
+                .with_background(Brush::Solid(BACKGROUND_COLOR))
+                .with_foreground(Brush::Solid(Color::TRANSPARENT))
+                .with_child(self.text_h),
+        )
+        .build(&mut user_interface.build_ctx());
+
+        self.refresh(user_interface);
+    }
+
+    // Returns Some(true) when the match is started, and Some(false) when the player goes back.
+    //
+    pub fn update(
+        &mut self,
+        input: &InputController,
+        user_interface: &mut UserInterface,
+    ) -> Option<bool> {
+        use VirtualKeyCode::*;
+
+        let (team, entry) = (self.selected / 2, self.selected % 2);
+        let options_count = if entry == 0 {
+            self.formations.len()
+        } else {
+            self.tactics.len()
+        };
+        let selection = &mut self.selections[team][entry];
+
+        if input.is_key_just_pressed(Space) || input.is_key_just_pressed(Return) {
+            return Some(true);
+        } else if input.is_key_just_pressed(Escape) {
+            return Some(false);
+        } else if input.is_key_just_pressed(Right) {
+            *selection = (*selection + 1) % options_count;
+        } else if input.is_key_just_pressed(Left) {
+            *selection = (*selection + options_count - 1) % options_count;
+        } else if input.is_key_just_pressed(Down) {
+            self.selected = (self.selected + 1) % ENTRIES_COUNT;
+        } else if input.is_key_just_pressed(Up) {
+            self.selected = (self.selected + ENTRIES_COUNT - 1) % ENTRIES_COUNT;
+        } else {
+            return None;
+        }
+
+        self.refresh(user_interface);
+
+        None
+    }
+
+    pub fn team_tactics(&self) -> [TeamTactics; 2] {
+        self.selections.map(|[formation_i, tactic_i]| TeamTactics {
+            formation: self.formations[formation_i].clone(),
+            tactic: self.tactics[tactic_i].clone(),
+        })
+    }
+
+    pub fn clear(&mut self, user_interface: &mut UserInterface) {
+        // The text is a child of the background, so it's removed with it.
+        //
+        self.background_h = remove_widget_node(self.background_h, user_interface);
+        self.text_h = Handle::NONE;
+    }
+
+    fn refresh(&self, user_interface: &mut UserInterface) {
+        let mut text = String::from("TACTICS\n\n");
+
+        for (team, [formation_i, tactic_i]) in self.selections.iter().enumerate() {
+            let team_name = if self.human_teams[team] {
+                format!("P{}", team + 1)
+            } else {
+                String::from("CPU")
+            };
+
+            let entries = [
+                ("FORMATION", &self.formations[*formation_i].name),
+                ("TACTIC", &self.tactics[*tactic_i].name),
+            ];
+
+            for (entry, (entry_name, value)) in entries.into_iter().enumerate() {
+                let line = format!("{team_name} {entry_name}: {}", value.to_uppercase());
+
+                if self.selected == team * 2 + entry {
+                    text.push_str(&format!("> {line} <\n"));
+                } else {
+                    text.push_str(&format!("{line}\n"));
+                }
+            }
+
+            text.push('\n');
+        }
+
+        text.push_str("UP/DOWN: SELECT - LEFT/RIGHT: CHANGE - SPACE: START - ESC: BACK");
+
+        update_widget_text(self.text_h, &text, user_interface);
+    }
+}
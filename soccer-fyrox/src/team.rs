@@ -1,24 +1,94 @@
 use crate::prelude::*;
 
+// Frames required to charge a kick to the full power.
+const KICK_CHARGE_FRAMES: u32 = 45;
+// Power range of the human kicks, as ratio of KICK_STRENGTH; computer kicks always have power 1.
+const MIN_KICK_POWER: f32 = 0.6;
+const MAX_KICK_POWER: f32 = 1.25;
+
 pub struct Team {
-    pub controls: Option<Controls>,
+    human: bool,
+    // Set on each frame; always the default for computer teams.
+    pub input: TeamInput,
+    pub previous_input: TeamInput,
+    // Frames a kick control has been held, while owning the ball.
+    pub kick_charge: u32,
     pub score: u8,
     pub active_control_player: Option<Handle<Player>>,
+    // The goal attacked: 0 = top, 1 = bottom. Initially the same as the team number, but the teams
+    // switch ends at half time.
+    pub end: u8,
+    pub tactics: TeamTactics,
+    // Rust: In the source project, the difficulty is a game setting. Here, each team has its own, so
+    // that CPU teams of different strengths can play each other (see League); in the other matches,
+    // both the teams have the same.
+    pub difficulty: Difficulty,
 }
 
 impl Team {
-    pub fn new(controls: Option<Controls>) -> Self {
+    // Rust: In the source project, the team stores its controls, and the presence of the controls tells
+    // if the team is human; here, the controls are owned by GameGlobal (see TeamInput).
+    //
+    pub fn new(human: bool, end: u8, tactics: TeamTactics, difficulty: Difficulty) -> Self {
+        let input = TeamInput::default();
+        let previous_input = TeamInput::default();
+        let kick_charge = 0;
         let score = 0;
         let active_control_player = None;
 
         Self {
-            controls,
+            human,
+            input,
+            previous_input,
+            kick_charge,
             score,
             active_control_player,
+            end,
+            tactics,
+            difficulty,
         }
     }
 
     pub fn human(&self) -> bool {
-        self.controls.is_some()
+        self.human
+    }
+
+    // True on the frame either kick control is pressed.
+    //
+    pub fn kick_pressed(&self) -> bool {
+        (self.input.shoot && !self.previous_input.shoot)
+            || (self.input.pass && !self.previous_input.pass)
+    }
+
+    // The kick whose control has been released on this frame, if any.
+    //
+    pub fn kick_released(&self) -> Option<Kick> {
+        if self.previous_input.shoot && !self.input.shoot {
+            Some(Kick::Shoot)
+        } else if self.previous_input.pass && !self.input.pass {
+            Some(Kick::Pass)
+        } else {
+            None
+        }
+    }
+
+    pub fn kick_charging(&self) -> bool {
+        self.input.shoot || self.input.pass
+    }
+
+    // From 0 to 1.
+    //
+    pub fn kick_charge_ratio(&self) -> f32 {
+        self.kick_charge.min(KICK_CHARGE_FRAMES) as f32 / KICK_CHARGE_FRAMES as f32
+    }
+
+    // Multiplier of KICK_STRENGTH.
+    //
+    pub fn kick_power(&self) -> f32 {
+        if self.human {
+            MIN_KICK_POWER + (MAX_KICK_POWER - MIN_KICK_POWER) * self.kick_charge_ratio()
+        } else {
+            1.
+        }
     }
 }
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+
+// The commands given to a human team on the current frame. The simulation only sees these, so that
+// it's independent from the input devices; see Controls.
+//
+#[derive(Clone, Copy, Default)]
+pub struct TeamInput {
+    // Each axis is -1, 0 or 1.
+    pub movement: Vector2<f32>,
+    // Held state of the kick controls; kicks are charged while the control is held, and happen on
+    // release (see Team).
+    pub shoot: bool,
+    pub pass: bool,
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+// The formation and tactic of a team, chosen before the match.
+//
+#[derive(Clone)]
+pub struct TeamTactics {
+    pub formation: Formation,
+    pub tactic: Tactic,
+}
+
+impl TeamTactics {
+    // The formation depends on the team size.
+    //
+    pub fn default_for(players_per_team: usize) -> Self {
+        Self {
+            formation: Formation::default_for(players_per_team),
+            tactic: Tactic::default(),
+        }
+    }
+}